@@ -0,0 +1,37 @@
+// Conversions between entropy and word indices, no word list involved.
+// Plain timing loops, run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use mnemonic_external::WordSet;
+
+const ROUNDS: u32 = 20000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // warm-up
+    for _ in 0..ROUNDS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let per_round = start.elapsed() / ROUNDS;
+    println!("{name:<40} {per_round:>12?}");
+}
+
+fn main() {
+    for len in [16, 32] {
+        let entropy: Vec<u8> = (0..len as u8).map(|i| i.wrapping_mul(37)).collect();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        let words = word_set.len();
+
+        bench(&format!("from_entropy, {words} words"), || {
+            black_box(WordSet::from_entropy(black_box(&entropy)).unwrap());
+        });
+        bench(&format!("to_entropy, {words} words"), || {
+            black_box(black_box(&word_set).to_entropy().unwrap());
+        });
+    }
+}
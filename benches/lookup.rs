@@ -0,0 +1,62 @@
+// Word lookups over the English list: `InternalWordList` binary searches the
+// sorted list, `SliceWordList` over the same words scans it. Plain timing
+// loops, run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use mnemonic_external::custom::SliceWordList;
+use mnemonic_external::regular::InternalWordList;
+use mnemonic_external::wordlist::WORDLIST_ENGLISH;
+use mnemonic_external::{AsWordList, WordSet};
+
+const PHRASE: &str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+
+const ROUNDS: u32 = 2000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // warm-up
+    for _ in 0..ROUNDS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let per_round = start.elapsed() / ROUNDS;
+    println!("{name:<40} {per_round:>12?}");
+}
+
+fn main() {
+    let slice_word_list = SliceWordList::new(&WORDLIST_ENGLISH).unwrap();
+    let words: Vec<&str> = PHRASE.split(' ').collect();
+
+    bench("bits11_for_word x24, binary search", || {
+        for word in words.iter() {
+            black_box(InternalWordList.bits11_for_word(black_box(word)).unwrap());
+        }
+    });
+    bench("bits11_for_word x24, scan", || {
+        for word in words.iter() {
+            black_box(slice_word_list.bits11_for_word(black_box(word)).unwrap());
+        }
+    });
+    bench("get_words_by_prefix(\"s\"), binary search", || {
+        black_box(
+            InternalWordList
+                .get_words_by_prefix(black_box("s"))
+                .unwrap(),
+        );
+    });
+    bench("get_words_by_prefix(\"s\"), scan", || {
+        black_box(slice_word_list.get_words_by_prefix(black_box("s")).unwrap());
+    });
+    bench("from_phrase + validate, binary search", || {
+        let word_set = WordSet::from_phrase(black_box(PHRASE), &InternalWordList).unwrap();
+        word_set.validate().unwrap();
+    });
+    bench("from_phrase + validate, scan", || {
+        let word_set = WordSet::from_phrase(black_box(PHRASE), &slice_word_list).unwrap();
+        word_set.validate().unwrap();
+    });
+}
@@ -0,0 +1,128 @@
+#ifndef MNEMONIC_EXTERNAL_H
+#define MNEMONIC_EXTERNAL_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of every fallible call. The values are stable.
+ */
+typedef enum MnemonicStatus {
+  MNEMONIC_STATUS_OK = 0,
+  MNEMONIC_STATUS_NULL_POINTER = 1,
+  MNEMONIC_STATUS_INVALID_UTF8 = 2,
+  MNEMONIC_STATUS_NO_WORD = 3,
+  MNEMONIC_STATUS_TOO_MANY_WORDS = 4,
+  MNEMONIC_STATUS_WORDS_NUMBER = 5,
+  MNEMONIC_STATUS_INVALID_CHECKSUM = 6,
+  MNEMONIC_STATUS_BUFFER_TOO_SMALL = 7,
+  MNEMONIC_STATUS_OTHER = 255,
+} MnemonicStatus;
+
+typedef struct MnemonicWordSet MnemonicWordSet;
+
+/**
+ * Called for each match of [`mnemonic_words_by_prefix`] with the word index
+ * and the word, not NUL-terminated. Return `false` to stop.
+ */
+typedef bool (*MnemonicWordCallback)(void *user_data,
+                                     uint16_t index,
+                                     const uint8_t *word,
+                                     size_t word_len);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * New empty set, released with [`mnemonic_wordset_free`].
+ */
+struct MnemonicWordSet *mnemonic_wordset_new(void);
+
+/**
+ * Zeroizes and releases a set. Null is ignored.
+ *
+ * # Safety
+ *
+ * `set` is null or came from [`mnemonic_wordset_new`] and is not used
+ * afterwards.
+ */
+void mnemonic_wordset_free(struct MnemonicWordSet *set);
+
+/**
+ * # Safety
+ *
+ * `set` is null or a live set; `word` is null or a NUL-terminated string.
+ */
+enum MnemonicStatus mnemonic_wordset_add_word(struct MnemonicWordSet *set, const char *word);
+
+/**
+ * Removes the last word; `false` if the set was empty or null.
+ *
+ * # Safety
+ *
+ * `set` is null or a live set.
+ */
+bool mnemonic_wordset_remove_last_word(struct MnemonicWordSet *set);
+
+/**
+ * Number of words entered, 0 for null.
+ *
+ * # Safety
+ *
+ * `set` is null or a live set.
+ */
+size_t mnemonic_wordset_len(const struct MnemonicWordSet *set);
+
+/**
+ * Whether the word count is a valid length; the checksum is not looked at.
+ *
+ * # Safety
+ *
+ * `set` is null or a live set.
+ */
+bool mnemonic_wordset_is_finalizable(const struct MnemonicWordSet *set);
+
+/**
+ * Length and checksum check.
+ *
+ * # Safety
+ *
+ * `set` is null or a live set.
+ */
+enum MnemonicStatus mnemonic_wordset_validate(const struct MnemonicWordSet *set);
+
+/**
+ * Entropy into `out`, its length into `written`. `BufferTooSmall` below 32
+ * bytes for a 24 word set; nothing is written on error.
+ *
+ * # Safety
+ *
+ * `set` is null or a live set, `out` is null or valid for `out_len` bytes,
+ * `written` is null or valid for a write.
+ */
+enum MnemonicStatus mnemonic_wordset_to_entropy(const struct MnemonicWordSet *set,
+                                                uint8_t *out,
+                                                size_t out_len,
+                                                size_t *written);
+
+/**
+ * Runs `callback` for each word starting with `prefix`, in list order.
+ *
+ * # Safety
+ *
+ * `prefix` is null or a NUL-terminated string; `callback` is safe to call
+ * with `user_data`.
+ */
+enum MnemonicStatus mnemonic_words_by_prefix(const char *prefix,
+                                             MnemonicWordCallback callback,
+                                             void *user_data);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MNEMONIC_EXTERNAL_H */
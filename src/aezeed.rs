@@ -0,0 +1,282 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use scrypt::{scrypt, Params};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[cfg(feature = "generate")]
+use rand_core::CryptoRngCore;
+
+use crate::error::ErrorMnemonic;
+use crate::regular::InternalWordList;
+use crate::{Bits11, WordSet, BITS_IN_BYTE, BITS_IN_U11, MAX_SEED_LEN};
+
+pub(crate) mod aez;
+
+use aez::Aez;
+
+/// The only cipher seed version lnd defines.
+pub const CIPHER_SEED_VERSION: u8 = 0;
+
+pub const AEZEED_ENTROPY_LEN: usize = 16;
+pub const AEZEED_SALT_LEN: usize = 5;
+
+/// Version, ciphertext, salt and checksum: 24 words of the English list.
+pub const ENCIPHERED_SEED_LEN: usize = 33;
+
+/// Unix time of the Bitcoin genesis block, day zero of the birthday.
+pub const BITCOIN_GENESIS_TIME: u64 = 1231006505;
+
+// version, birthday and entropy
+const DECIPHERED_SEED_LEN: usize = 1 + 2 + AEZEED_ENTROPY_LEN;
+// zero bytes appended before encryption, checked on decryption
+const CIPHERTEXT_EXPANSION: usize = 4;
+const CIPHERTEXT_LEN: usize = DECIPHERED_SEED_LEN + CIPHERTEXT_EXPANSION;
+const SALT_START: usize = 1 + CIPHERTEXT_LEN;
+const CHECKSUM_START: usize = SALT_START + AEZEED_SALT_LEN;
+
+const DEFAULT_PASSPHRASE: &str = "aezeed";
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const KEY_LEN: usize = 32;
+
+const SECONDS_IN_DAY: u64 = 86400;
+
+/// lnd cipher seed: entropy and the wallet birthday, encrypted with AEZ under
+/// a scrypt-stretched passphrase and written as 24 BIP-39 English words.
+/// Without a passphrase lnd uses `"aezeed"`, and so does this.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct CipherSeed {
+    birthday: u16,
+    entropy: [u8; AEZEED_ENTROPY_LEN],
+    salt: [u8; AEZEED_SALT_LEN],
+}
+
+impl CipherSeed {
+    /// `birthday` is in days since the genesis block, see
+    /// [`birthday_from_unix_time`](Self::birthday_from_unix_time).
+    pub fn new(
+        birthday: u16,
+        entropy: &[u8; AEZEED_ENTROPY_LEN],
+        salt: &[u8; AEZEED_SALT_LEN],
+    ) -> Self {
+        Self {
+            birthday,
+            entropy: *entropy,
+            salt: *salt,
+        }
+    }
+
+    /// Fresh entropy and salt from a cryptographic RNG.
+    #[cfg(feature = "generate")]
+    pub fn generate<R: CryptoRngCore + ?Sized>(birthday: u16, rng: &mut R) -> Self {
+        let mut cipher_seed = Self::new(birthday, &[0; AEZEED_ENTROPY_LEN], &[0; AEZEED_SALT_LEN]);
+        rng.fill_bytes(&mut cipher_seed.entropy);
+        rng.fill_bytes(&mut cipher_seed.salt);
+        cipher_seed
+    }
+
+    /// Whole days since the genesis block, saturating at the ends.
+    pub fn birthday_from_unix_time(unix_time: u64) -> u16 {
+        let days = unix_time.saturating_sub(BITCOIN_GENESIS_TIME) / SECONDS_IN_DAY;
+        days.min(u16::MAX as u64) as u16
+    }
+
+    pub fn birthday(&self) -> u16 {
+        self.birthday
+    }
+
+    pub fn entropy(&self) -> &[u8; AEZEED_ENTROPY_LEN] {
+        &self.entropy
+    }
+
+    pub fn salt(&self) -> &[u8; AEZEED_SALT_LEN] {
+        &self.salt
+    }
+
+    pub fn encipher(
+        &self,
+        passphrase: &str,
+    ) -> Result<Zeroizing<[u8; ENCIPHERED_SEED_LEN]>, ErrorMnemonic> {
+        self.encipher_with_cost(passphrase, SCRYPT_LOG_N)
+    }
+
+    // lnd publishes its test vectors with a scrypt N of 16, not 32768.
+    pub(crate) fn encipher_with_cost(
+        &self,
+        passphrase: &str,
+        scrypt_log_n: u8,
+    ) -> Result<Zeroizing<[u8; ENCIPHERED_SEED_LEN]>, ErrorMnemonic> {
+        let mut enciphered = Zeroizing::new([0u8; ENCIPHERED_SEED_LEN]);
+        enciphered[0] = CIPHER_SEED_VERSION;
+        let ciphertext = &mut enciphered[1..SALT_START];
+        ciphertext[0] = CIPHER_SEED_VERSION;
+        ciphertext[1..3].copy_from_slice(&self.birthday.to_be_bytes());
+        ciphertext[3..DECIPHERED_SEED_LEN].copy_from_slice(&self.entropy);
+
+        let aez = Aez::new(&derive_key(passphrase, &self.salt, scrypt_log_n)?[..]);
+        let ad = associated_data(&self.salt);
+        aez.encrypt(&[], &[&ad[..]], CIPHERTEXT_EXPANSION, ciphertext);
+
+        enciphered[SALT_START..CHECKSUM_START].copy_from_slice(&self.salt);
+        let checksum = crc32c(&enciphered[..CHECKSUM_START]);
+        enciphered[CHECKSUM_START..].copy_from_slice(&checksum.to_be_bytes());
+        Ok(enciphered)
+    }
+
+    /// Checksum and version are checked before the passphrase is stretched, so
+    /// a mistyped phrase fails fast. A wrong passphrase is `AezeedDecryption`.
+    pub fn decipher(
+        enciphered: &[u8; ENCIPHERED_SEED_LEN],
+        passphrase: &str,
+    ) -> Result<Self, ErrorMnemonic> {
+        Self::decipher_with_cost(enciphered, passphrase, SCRYPT_LOG_N)
+    }
+
+    pub(crate) fn decipher_with_cost(
+        enciphered: &[u8; ENCIPHERED_SEED_LEN],
+        passphrase: &str,
+        scrypt_log_n: u8,
+    ) -> Result<Self, ErrorMnemonic> {
+        if enciphered[0] != CIPHER_SEED_VERSION {
+            return Err(ErrorMnemonic::AezeedVersion);
+        }
+        let checksum = crc32c(&enciphered[..CHECKSUM_START]);
+        if enciphered[CHECKSUM_START..] != checksum.to_be_bytes() {
+            return Err(ErrorMnemonic::InvalidChecksum);
+        }
+
+        let mut salt = [0u8; AEZEED_SALT_LEN];
+        salt.copy_from_slice(&enciphered[SALT_START..CHECKSUM_START]);
+        let mut plaintext = Zeroizing::new([0u8; CIPHERTEXT_LEN]);
+        plaintext.copy_from_slice(&enciphered[1..SALT_START]);
+
+        let aez = Aez::new(&derive_key(passphrase, &salt, scrypt_log_n)?[..]);
+        let ad = associated_data(&salt);
+        if !aez.decrypt(&[], &[&ad[..]], CIPHERTEXT_EXPANSION, &mut plaintext[..]) {
+            return Err(ErrorMnemonic::AezeedDecryption);
+        }
+        if plaintext[0] != CIPHER_SEED_VERSION {
+            return Err(ErrorMnemonic::AezeedVersion);
+        }
+
+        let mut entropy = Zeroizing::new([0u8; AEZEED_ENTROPY_LEN]);
+        entropy.copy_from_slice(&plaintext[3..DECIPHERED_SEED_LEN]);
+        Ok(Self::new(
+            u16::from_be_bytes([plaintext[1], plaintext[2]]),
+            &entropy,
+            &salt,
+        ))
+    }
+
+    /// The enciphered seed, 11 bits per word, most significant first.
+    pub fn to_word_set(&self, passphrase: &str) -> Result<WordSet, ErrorMnemonic> {
+        word_set_from_enciphered(&*self.encipher(passphrase)?)
+    }
+
+    pub fn to_phrase(&self, passphrase: &str) -> Result<String, ErrorMnemonic> {
+        self.to_word_set(passphrase)?.to_phrase(&InternalWordList)
+    }
+
+    pub fn from_word_set(word_set: &WordSet, passphrase: &str) -> Result<Self, ErrorMnemonic> {
+        Self::decipher(&*enciphered_from_word_set(word_set)?, passphrase)
+    }
+
+    pub fn from_phrase(phrase: &str, passphrase: &str) -> Result<Self, ErrorMnemonic> {
+        let word_set = WordSet::from_phrase(phrase, &InternalWordList)?;
+        Self::from_word_set(&word_set, passphrase)
+    }
+}
+
+impl Debug for CipherSeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "CipherSeed(..)")
+    }
+}
+
+pub(crate) fn word_set_from_enciphered(
+    enciphered: &[u8; ENCIPHERED_SEED_LEN],
+) -> Result<WordSet, ErrorMnemonic> {
+    let mut word_set = WordSet::new();
+    let mut acc: u32 = 0;
+    let mut acc_bits = 0;
+    for byte in enciphered.iter() {
+        acc = (acc << BITS_IN_BYTE) | *byte as u32;
+        acc_bits += BITS_IN_BYTE;
+        if acc_bits >= BITS_IN_U11 {
+            acc_bits -= BITS_IN_U11;
+            word_set
+                .bits11_set
+                .push(Bits11::from(((acc >> acc_bits) & 0x7FF) as u16)?);
+        }
+    }
+    acc.zeroize();
+    Ok(word_set)
+}
+
+pub(crate) fn enciphered_from_word_set(
+    word_set: &WordSet,
+) -> Result<Zeroizing<[u8; ENCIPHERED_SEED_LEN]>, ErrorMnemonic> {
+    if word_set.bits11_set.len() != MAX_SEED_LEN {
+        return Err(ErrorMnemonic::WordsNumber);
+    }
+    let mut enciphered = Zeroizing::new([0u8; ENCIPHERED_SEED_LEN]);
+    let mut acc: u32 = 0;
+    let mut acc_bits = 0;
+    let mut position = 0;
+    for bits11 in word_set.bits11_set.iter() {
+        acc = (acc << BITS_IN_U11) | bits11.bits() as u32;
+        acc_bits += BITS_IN_U11;
+        while acc_bits >= BITS_IN_BYTE {
+            acc_bits -= BITS_IN_BYTE;
+            enciphered[position] = (acc >> acc_bits) as u8;
+            position += 1;
+        }
+    }
+    acc.zeroize();
+    Ok(enciphered)
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8; AEZEED_SALT_LEN],
+    scrypt_log_n: u8,
+) -> Result<Zeroizing<[u8; KEY_LEN]>, ErrorMnemonic> {
+    let passphrase = if passphrase.is_empty() {
+        DEFAULT_PASSPHRASE
+    } else {
+        passphrase
+    };
+    let params = Params::new(scrypt_log_n, SCRYPT_R, SCRYPT_P, KEY_LEN)
+        .map_err(|_| ErrorMnemonic::SeedDerivation)?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    scrypt(passphrase.as_bytes(), salt, &params, &mut key[..])
+        .map_err(|_| ErrorMnemonic::SeedDerivation)?;
+    Ok(key)
+}
+
+fn associated_data(salt: &[u8; AEZEED_SALT_LEN]) -> [u8; 1 + AEZEED_SALT_LEN] {
+    let mut ad = [0u8; 1 + AEZEED_SALT_LEN];
+    ad[0] = CIPHER_SEED_VERSION;
+    ad[1..].copy_from_slice(salt);
+    ad
+}
+
+// CRC-32C (Castagnoli), bit by bit: it only ever runs over 29 bytes.
+fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFFFFFF;
+    for byte in bytes.iter() {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = 0u32.wrapping_sub(crc & 1);
+            crc = (crc >> 1) ^ (0x82F63B78 & mask);
+        }
+    }
+    !crc
+}
@@ -0,0 +1,212 @@
+use aes::hazmat::cipher_round;
+use aes::Block;
+use blake2::digest::consts::U48;
+use blake2::{Blake2b, Digest};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+// The part of AEZ v5 aezeed needs: key extraction, AEZ-hash and the tiny
+// Feistel cipher for 16 to 31 byte inputs. AEZ-core, used for 32 bytes and
+// up, is not here.
+
+const BLOCK_LEN: usize = 16;
+const EXTRACTED_KEY_LEN: usize = 3 * BLOCK_LEN;
+
+/// Range of `message + tau` lengths the tiny cipher is run for here.
+pub(crate) const MIN_TINY_LEN: usize = 16;
+pub(crate) const MAX_TINY_LEN: usize = 2 * BLOCK_LEN - 1;
+
+const TINY_ROUNDS: usize = 8;
+// `i` of the tweakable block cipher calls for inputs of 16 bytes and up
+const TINY_I: usize = 6;
+
+type Bytes16 = [u8; BLOCK_LEN];
+
+#[derive(ZeroizeOnDrop)]
+pub(crate) struct Aez {
+    i: Bytes16,
+    j: Bytes16,
+    l: Bytes16,
+}
+
+impl Aez {
+    /// 48-byte keys are used as is, others go through BLAKE2b.
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut extracted = [0u8; EXTRACTED_KEY_LEN];
+        if key.len() == EXTRACTED_KEY_LEN {
+            extracted.copy_from_slice(key);
+        } else {
+            let mut digest = Blake2b::<U48>::digest(key);
+            extracted.copy_from_slice(&digest);
+            digest.zeroize();
+        }
+        let mut aez = Self {
+            i: [0; BLOCK_LEN],
+            j: [0; BLOCK_LEN],
+            l: [0; BLOCK_LEN],
+        };
+        aez.i.copy_from_slice(&extracted[..BLOCK_LEN]);
+        aez.j.copy_from_slice(&extracted[BLOCK_LEN..2 * BLOCK_LEN]);
+        aez.l.copy_from_slice(&extracted[2 * BLOCK_LEN..]);
+        extracted.zeroize();
+        aez
+    }
+
+    /// Enciphers `data`, the message followed by `tau` zero bytes, in place.
+    pub(crate) fn encrypt(&self, nonce: &[u8], ad: &[&[u8]], tau: usize, data: &mut [u8]) {
+        let mut delta = self.hash(nonce, ad, tau);
+        self.tiny(&delta, data, false);
+        delta.zeroize();
+    }
+
+    /// Deciphers `data` in place, `true` if the last `tau` bytes come out
+    /// zero, i.e. the ciphertext is authentic.
+    pub(crate) fn decrypt(&self, nonce: &[u8], ad: &[&[u8]], tau: usize, data: &mut [u8]) -> bool {
+        let mut delta = self.hash(nonce, ad, tau);
+        self.tiny(&delta, data, true);
+        delta.zeroize();
+        let expansion = data[data.len() - tau..]
+            .iter()
+            .fold(0u8, |acc, byte| acc | *byte);
+        expansion == 0
+    }
+
+    // E^{j,i} for j >= 0: four AES rounds keyed J, I, L and zero, over the
+    // input offset by jJ, 2^ceil(i/8) I and (i mod 8) L.
+    fn e(&self, j: usize, i: usize, input: &Bytes16) -> Bytes16 {
+        let mut offset = multiply(j, &self.j);
+        let mut i_power = self.i;
+        for _ in 0..i.div_ceil(8) {
+            double(&mut i_power);
+        }
+        let mut l_multiple = multiply(i % 8, &self.l);
+        xor_into(&mut offset, &i_power);
+        xor_into(&mut offset, &l_multiple);
+        i_power.zeroize();
+        l_multiple.zeroize();
+
+        let mut block = Block::from(*input);
+        xor_into(block.as_mut(), &offset);
+        offset.zeroize();
+        for key in [&self.j, &self.i, &self.l, &[0; BLOCK_LEN]] {
+            cipher_round(&mut block, Block::from_slice(key));
+        }
+        let out = block.into();
+        block.zeroize();
+        out
+    }
+
+    fn hash(&self, nonce: &[u8], ad: &[&[u8]], tau: usize) -> Bytes16 {
+        let mut tau_block = [0u8; BLOCK_LEN];
+        tau_block[BLOCK_LEN - 4..].copy_from_slice(&((tau * 8) as u32).to_be_bytes());
+        let mut sum = self.e(3, 1, &tau_block);
+        self.hash_vector(4, nonce, &mut sum);
+        for (k, element) in ad.iter().enumerate() {
+            self.hash_vector(5 + k, element, &mut sum);
+        }
+        sum
+    }
+
+    fn hash_vector(&self, j: usize, input: &[u8], sum: &mut Bytes16) {
+        let mut chunks = input.chunks_exact(BLOCK_LEN);
+        for (i, chunk) in (1..).zip(chunks.by_ref()) {
+            let mut block = [0u8; BLOCK_LEN];
+            block.copy_from_slice(chunk);
+            xor_into(sum, &self.e(j, i, &block));
+        }
+        let rest = chunks.remainder();
+        if !rest.is_empty() || input.is_empty() {
+            let mut block = [0u8; BLOCK_LEN];
+            block[..rest.len()].copy_from_slice(rest);
+            block[rest.len()] = 0x80;
+            xor_into(sum, &self.e(j, 0, &block));
+        }
+    }
+
+    // Balanced Feistel over the two halves of `data`; for odd lengths the
+    // halves split a byte, so the right one is kept shifted by a nibble.
+    fn tiny(&self, delta: &Bytes16, data: &mut [u8], decrypt: bool) {
+        let len = data.len();
+        assert!((MIN_TINY_LEN..=MAX_TINY_LEN).contains(&len));
+        let half = len.div_ceil(2);
+        let (mask, pad) = if len % 2 == 1 {
+            (0xF0, 0x08)
+        } else {
+            (0x00, 0x80)
+        };
+
+        let mut left = [0u8; BLOCK_LEN];
+        let mut right = [0u8; BLOCK_LEN];
+        left[..half].copy_from_slice(&data[..half]);
+        right[..half].copy_from_slice(&data[len / 2..len / 2 + half]);
+        if len % 2 == 1 {
+            for k in 0..len / 2 {
+                right[k] = (right[k] << 4) | (right[k + 1] >> 4);
+            }
+            right[len / 2] <<= 4;
+        }
+
+        let round = |source: &Bytes16, target: &mut Bytes16, counter: usize| {
+            let mut block = [0u8; BLOCK_LEN];
+            block[..half].copy_from_slice(&source[..half]);
+            block[len / 2] = (block[len / 2] & mask) | pad;
+            xor_into(&mut block, delta);
+            block[BLOCK_LEN - 1] ^= counter as u8;
+            let mut out = self.e(0, TINY_I, &block);
+            xor_into(target, &out);
+            block.zeroize();
+            out.zeroize();
+        };
+        for pair in 0..TINY_ROUNDS / 2 {
+            let (first, second) = if decrypt {
+                (TINY_ROUNDS - 1 - 2 * pair, TINY_ROUNDS - 2 - 2 * pair)
+            } else {
+                (2 * pair, 2 * pair + 1)
+            };
+            round(&right, &mut left, first);
+            round(&left, &mut right, second);
+        }
+
+        let mut out = [0u8; 2 * BLOCK_LEN];
+        out[..len / 2].copy_from_slice(&right[..len / 2]);
+        out[len / 2..len / 2 + half].copy_from_slice(&left[..half]);
+        if len % 2 == 1 {
+            for k in (len / 2 + 1..len).rev() {
+                out[k] = (out[k] >> 4) | (out[k - 1] << 4);
+            }
+            out[len / 2] = (left[0] >> 4) | (right[len / 2] & 0xF0);
+        }
+        data.copy_from_slice(&out[..len]);
+        left.zeroize();
+        right.zeroize();
+        out.zeroize();
+    }
+}
+
+fn xor_into(target: &mut [u8], source: &[u8]) {
+    for (t, s) in target.iter_mut().zip(source.iter()) {
+        *t ^= *s;
+    }
+}
+
+// Doubling in GF(2^128), blocks read as big-endian polynomials.
+fn double(block: &mut Bytes16) {
+    let carry = block[0] >> 7;
+    for k in 0..BLOCK_LEN - 1 {
+        block[k] = (block[k] << 1) | (block[k + 1] >> 7);
+    }
+    block[BLOCK_LEN - 1] = (block[BLOCK_LEN - 1] << 1) ^ (0x87 * carry);
+}
+
+fn multiply(mut factor: usize, block: &Bytes16) -> Bytes16 {
+    let mut out = [0u8; BLOCK_LEN];
+    let mut power = *block;
+    while factor != 0 {
+        if factor & 1 == 1 {
+            xor_into(&mut out, &power);
+        }
+        double(&mut power);
+        factor >>= 1;
+    }
+    power.zeroize();
+    out
+}
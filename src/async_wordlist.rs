@@ -0,0 +1,107 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use zeroize::Zeroize;
+
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, Bits11, WordSet, MAX_SEED_LEN, WORD_MAX_LEN};
+
+/// [`AsWordList`] for lists behind an async driver, e.g. on SPI flash, so
+/// reads do not block the executor. Every synchronous list is one too.
+///
+/// The futures are not required to be `Send`, as single-threaded embedded
+/// executors have no use for it.
+#[allow(async_fn_in_trait)]
+pub trait AsWordListAsync {
+    type Word: AsRef<str>;
+    /// As [`AsWordList::get_word`], must not panic on a damaged list.
+    async fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic>;
+    async fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElementAsync<Self>>, ErrorMnemonic>;
+    async fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic>;
+    /// Character joining the words of a phrase.
+    fn separator(&self) -> char {
+        ' '
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WordListElementAsync<L: AsWordListAsync + ?Sized> {
+    pub word: L::Word,
+    pub bits11: Bits11,
+}
+
+impl<L: AsWordList> AsWordListAsync for L {
+    type Word = L::Word;
+
+    async fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        AsWordList::get_word(self, bits)
+    }
+
+    async fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElementAsync<Self>>, ErrorMnemonic> {
+        Ok(AsWordList::get_words_by_prefix(self, prefix)?
+            .into_iter()
+            .map(|element| WordListElementAsync {
+                word: element.word,
+                bits11: element.bits11,
+            })
+            .collect())
+    }
+
+    async fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        AsWordList::bits11_for_word(self, word)
+    }
+
+    fn separator(&self) -> char {
+        AsWordList::separator(self)
+    }
+}
+
+impl WordSet {
+    /// [`add_word`](Self::add_word) over an async list.
+    pub async fn add_word_async<L: AsWordListAsync>(
+        &mut self,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let bits11 = wordlist.bits11_for_word(word).await?;
+        if self.bits11_set.len() == MAX_SEED_LEN {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.bits11_set.push(bits11);
+        Ok(())
+    }
+
+    /// [`to_phrase`](Self::to_phrase) over an async list.
+    pub async fn to_phrase_async<L: AsWordListAsync>(
+        &self,
+        wordlist: &L,
+    ) -> Result<String, ErrorMnemonic> {
+        let separator = wordlist.separator();
+        let separator_len = separator.len_utf8();
+        let mut phrase = String::with_capacity(
+            (self.bits11_set.len() * (WORD_MAX_LEN + separator_len)).saturating_sub(separator_len),
+        );
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            if position != 0 {
+                phrase.push(separator);
+            }
+            match wordlist.get_word(*bits11).await {
+                Ok(word) => phrase.push_str(word.as_ref()),
+                Err(error) => {
+                    phrase.zeroize();
+                    return Err(error.at(position));
+                }
+            }
+        }
+        Ok(phrase)
+    }
+}
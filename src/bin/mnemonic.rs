@@ -0,0 +1,137 @@
+// Offline command line tool over the English list, built on the public API
+// only, so that it runs the same code as embedded builds.
+//
+// Phrases and entropy are secrets: when left out of the command line they
+// are read from standard input, which keeps them out of the shell history.
+
+use std::env;
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+use zeroize::Zeroizing;
+
+use mnemonic_external::entropy::HexCase;
+use mnemonic_external::error::ErrorMnemonic;
+use mnemonic_external::regular::InternalWordList;
+use mnemonic_external::{AsWordList, MnemonicType, WordSet};
+
+const USAGE: &str = "\
+usage: mnemonic <command> [argument]
+
+  generate [WORDS]     new phrase from the system random generator, 12 words
+                       unless WORDS is 15, 18, 21 or 24
+  validate [PHRASE]    check the words and the checksum
+  entropy [PHRASE]     entropy of a phrase, as hex
+  phrase [HEX]         phrase for hex entropy
+  last-word [PHRASE]   words that complete a phrase one word short
+  prefix PREFIX        words starting with PREFIX
+
+PHRASE and HEX are read from standard input when not given.";
+
+enum Failure {
+    Usage,
+    Mnemonic(ErrorMnemonic),
+    Input(io::Error),
+}
+
+impl From<ErrorMnemonic> for Failure {
+    fn from(error: ErrorMnemonic) -> Self {
+        Failure::Mnemonic(error)
+    }
+}
+
+// the argument, or else the first line of standard input
+fn secret_input(argument: Option<String>) -> Result<Zeroizing<String>, Failure> {
+    if let Some(argument) = argument {
+        return Ok(Zeroizing::new(argument));
+    }
+    let mut line = Zeroizing::new(String::new());
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(Failure::Input)?;
+    Ok(line)
+}
+
+// words as typed, without the length check of `from_phrase`
+fn partial_word_set(phrase: &str) -> Result<WordSet, ErrorMnemonic> {
+    let mut word_set = WordSet::new();
+    for word in phrase.split_whitespace() {
+        word_set.add_word(word, &InternalWordList)?;
+    }
+    Ok(word_set)
+}
+
+fn run(command: &str, argument: Option<String>) -> Result<(), Failure> {
+    match command {
+        "generate" => {
+            let word_count = match argument {
+                Some(word_count) => word_count.parse().map_err(|_| Failure::Usage)?,
+                None => 12,
+            };
+            let mnemonic_type = MnemonicType::try_from(word_count)?;
+            let word_set = WordSet::generate_os(mnemonic_type)?;
+            println!("{}", *word_set.to_phrase_secret(&InternalWordList)?);
+        }
+        "validate" => {
+            let phrase = secret_input(argument)?;
+            WordSet::from_phrase(&phrase, &InternalWordList)?.validate()?;
+            println!("valid");
+        }
+        "entropy" => {
+            let phrase = secret_input(argument)?;
+            let word_set = WordSet::from_phrase(&phrase, &InternalWordList)?;
+            println!("{}", *word_set.entropy_hex(HexCase::Lower)?);
+        }
+        "phrase" => {
+            let hex = secret_input(argument)?;
+            let word_set = WordSet::from_hex_entropy(hex.trim())?;
+            println!("{}", *word_set.to_phrase_secret(&InternalWordList)?);
+        }
+        "last-word" => {
+            let phrase = secret_input(argument)?;
+            let word_set = partial_word_set(&phrase)?;
+            let words: Vec<String> = word_set
+                .valid_last_words(&InternalWordList)?
+                .into_iter()
+                .map(|element| String::from(element.word))
+                .collect();
+            println!("{}", words.join(" "));
+        }
+        "prefix" => {
+            let prefix = argument.ok_or(Failure::Usage)?;
+            for element in InternalWordList.get_words_by_prefix(&prefix)? {
+                println!("{:>4} {}", element.bits11.bits(), element.word);
+            }
+        }
+        _ => return Err(Failure::Usage),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let (Some(command), argument) = (args.next(), args.next()) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    if args.next().is_some() {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    }
+    match run(&command, argument) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Usage) => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(Failure::Mnemonic(error)) => {
+            eprintln!("error {}: {error}", error.code());
+            ExitCode::FAILURE
+        }
+        Err(Failure::Input(error)) => {
+            eprintln!("error reading input: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
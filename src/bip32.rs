@@ -0,0 +1,286 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::{ff::PrimeField, group::GroupEncoding};
+use k256::{FieldBytes, ProjectivePoint, Scalar, SecretKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, WordSet};
+
+pub const FINGERPRINT_LEN: usize = 4;
+
+/// Offset of hardened child indices.
+pub const HARDENED: u32 = 0x80000000;
+
+const MASTER_KEY_DOMAIN: &[u8] = b"Bitcoin seed";
+
+const KEY_LEN: usize = 32;
+const SERIALIZED_KEY_LEN: usize = 78;
+const CHECKSUM_LEN: usize = 4;
+// mainnet `xprv` and testnet `tprv`
+const PRIVATE_VERSIONS: [[u8; 4]; 2] = [[0x04, 0x88, 0xAD, 0xE4], [0x04, 0x35, 0x83, 0x94]];
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Network of a serialized key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Network {
+    /// `xprv`
+    #[default]
+    Mainnet,
+    /// `tprv`
+    Testnet,
+}
+
+/// BIP-32 extended private key: the secret key, its chain code, and where
+/// it sits in the tree for serialization.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct ExtendedPrivateKey {
+    secret: [u8; KEY_LEN],
+    chain_code: [u8; KEY_LEN],
+    depth: u8,
+    parent_fingerprint: [u8; FINGERPRINT_LEN],
+    child_number: u32,
+}
+
+impl ExtendedPrivateKey {
+    /// Master key of a seed, e.g. [`Seed::as_bytes`](crate::seed::Seed::as_bytes).
+    pub fn from_seed(seed: &[u8]) -> Result<Self, ErrorMnemonic> {
+        let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(MASTER_KEY_DOMAIN)
+            .map_err(|_| ErrorMnemonic::InvalidMasterKey)?;
+        mac.update(seed);
+        let master = Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()));
+        SecretKey::from_slice(&master[..KEY_LEN]).map_err(|_| ErrorMnemonic::InvalidMasterKey)?;
+        Ok(Self::from_halves(&master))
+    }
+
+    /// Key from its Base58Check serialization, `xprv` or `tprv`.
+    pub fn from_xprv(xprv: &str) -> Result<Self, ErrorMnemonic> {
+        let decoded = base58_decode(xprv)?;
+        if decoded.len() != SERIALIZED_KEY_LEN + CHECKSUM_LEN {
+            return Err(ErrorMnemonic::InvalidExtendedKey);
+        }
+        let (payload, checksum) = decoded.split_at(SERIALIZED_KEY_LEN);
+        if Sha256::digest(Sha256::digest(payload))[..CHECKSUM_LEN] != *checksum
+            || !PRIVATE_VERSIONS
+                .iter()
+                .any(|version| payload[..4] == *version)
+            || payload[45] != 0
+        {
+            return Err(ErrorMnemonic::InvalidExtendedKey);
+        }
+        SecretKey::from_slice(&payload[46..]).map_err(|_| ErrorMnemonic::InvalidExtendedKey)?;
+        let mut key = Self::empty();
+        key.depth = payload[4];
+        key.parent_fingerprint.copy_from_slice(&payload[5..9]);
+        let mut child_number = [0u8; 4];
+        child_number.copy_from_slice(&payload[9..13]);
+        key.child_number = u32::from_be_bytes(child_number);
+        key.chain_code.copy_from_slice(&payload[13..45]);
+        key.secret.copy_from_slice(&payload[46..]);
+        Ok(key)
+    }
+
+    /// Base58Check serialization, `xprv` or `tprv`, wiped when dropped.
+    pub fn to_xprv(&self, network: Network) -> Zeroizing<String> {
+        let mut payload = Zeroizing::new([0u8; SERIALIZED_KEY_LEN + CHECKSUM_LEN]);
+        payload[..4].copy_from_slice(match network {
+            Network::Mainnet => &PRIVATE_VERSIONS[0],
+            Network::Testnet => &PRIVATE_VERSIONS[1],
+        });
+        payload[4] = self.depth;
+        payload[5..9].copy_from_slice(&self.parent_fingerprint);
+        payload[9..13].copy_from_slice(&self.child_number.to_be_bytes());
+        payload[13..45].copy_from_slice(&self.chain_code);
+        payload[46..SERIALIZED_KEY_LEN].copy_from_slice(&self.secret);
+        let checksum = Sha256::digest(Sha256::digest(&payload[..SERIALIZED_KEY_LEN]));
+        payload[SERIALIZED_KEY_LEN..].copy_from_slice(&checksum[..CHECKSUM_LEN]);
+        base58_encode(&payload[..])
+    }
+
+    pub fn secret_bytes(&self) -> &[u8; KEY_LEN] {
+        &self.secret
+    }
+
+    pub fn chain_code(&self) -> &[u8; KEY_LEN] {
+        &self.chain_code
+    }
+
+    /// 0 for a master key.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// First 4 bytes of HASH160 of the public key; children carry it as
+    /// their parent fingerprint.
+    pub fn fingerprint(&self) -> Result<[u8; FINGERPRINT_LEN], ErrorMnemonic> {
+        let secret_key =
+            SecretKey::from_slice(&self.secret).map_err(|_| ErrorMnemonic::InvalidExtendedKey)?;
+        let scalar: Zeroizing<Scalar> = Zeroizing::new(*secret_key.to_nonzero_scalar());
+        let public_key = (ProjectivePoint::GENERATOR * *scalar)
+            .to_affine()
+            .to_bytes();
+
+        let hash160 = Ripemd160::digest(Sha256::digest(public_key));
+        let mut fingerprint = [0u8; FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(&hash160[..FINGERPRINT_LEN]);
+        Ok(fingerprint)
+    }
+
+    /// Hardened child `index'`; `index` itself must be below [`HARDENED`].
+    pub fn derive_hardened(&self, index: u32) -> Result<Self, ErrorMnemonic> {
+        if index >= HARDENED || self.depth == u8::MAX {
+            return Err(ErrorMnemonic::InvalidExtendedKey);
+        }
+        let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(&self.chain_code)
+            .map_err(|_| ErrorMnemonic::InvalidExtendedKey)?;
+        mac.update(&[0]);
+        mac.update(&self.secret);
+        mac.update(&(index | HARDENED).to_be_bytes());
+        let tweak = Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()));
+
+        let tweak_scalar: Option<Scalar> =
+            Scalar::from_repr(*FieldBytes::from_slice(&tweak[..KEY_LEN])).into();
+        let parent: Option<Scalar> =
+            Scalar::from_repr(*FieldBytes::from_slice(&self.secret)).into();
+        let (Some(tweak_scalar), Some(parent)) = (tweak_scalar, parent) else {
+            return Err(ErrorMnemonic::InvalidExtendedKey);
+        };
+        let child = Zeroizing::new(tweak_scalar + parent);
+        if bool::from(child.is_zero()) {
+            return Err(ErrorMnemonic::InvalidExtendedKey);
+        }
+
+        let mut key = Self::from_halves(&tweak);
+        key.secret.copy_from_slice(&child.to_bytes());
+        key.depth = self.depth + 1;
+        key.parent_fingerprint = self.fingerprint()?;
+        key.child_number = index | HARDENED;
+        Ok(key)
+    }
+
+    /// Walks hardened indices down from this key.
+    pub fn derive_hardened_path(&self, path: &[u32]) -> Result<Self, ErrorMnemonic> {
+        let mut key = self.clone();
+        for index in path.iter() {
+            key = key.derive_hardened(*index)?;
+        }
+        Ok(key)
+    }
+
+    fn empty() -> Self {
+        Self {
+            secret: [0; KEY_LEN],
+            chain_code: [0; KEY_LEN],
+            depth: 0,
+            parent_fingerprint: [0; FINGERPRINT_LEN],
+            child_number: 0,
+        }
+    }
+
+    fn from_halves(bytes: &[u8; 64]) -> Self {
+        let mut key = Self::empty();
+        key.secret.copy_from_slice(&bytes[..KEY_LEN]);
+        key.chain_code.copy_from_slice(&bytes[KEY_LEN..]);
+        key
+    }
+}
+
+impl Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "ExtendedPrivateKey(..)")
+    }
+}
+
+/// First 4 bytes of HASH160 of the BIP-32 master public key derived from
+/// `seed`.
+pub fn master_fingerprint(seed: &[u8]) -> Result<[u8; FINGERPRINT_LEN], ErrorMnemonic> {
+    ExtendedPrivateKey::from_seed(seed)?.fingerprint()
+}
+
+impl WordSet {
+    /// BIP-32 master key of the BIP-39 seed, from entered words straight to
+    /// a signing key root.
+    pub fn to_master_key<L: AsWordList>(
+        &self,
+        wordlist: &L,
+        passphrase: &str,
+    ) -> Result<ExtendedPrivateKey, ErrorMnemonic> {
+        let seed = self.to_seed(wordlist, passphrase)?;
+        ExtendedPrivateKey::from_seed(seed.as_bytes())
+    }
+
+    pub fn matches_fingerprint<L: AsWordList>(
+        &self,
+        wordlist: &L,
+        passphrase: &str,
+        expected_fp: [u8; FINGERPRINT_LEN],
+    ) -> Result<bool, ErrorMnemonic> {
+        let seed = self.to_seed(wordlist, passphrase)?;
+        Ok(master_fingerprint(seed.as_bytes())? == expected_fp)
+    }
+}
+
+// Bytes to Base58, leading zero bytes giving leading `1`s.
+fn base58_encode(input: &[u8]) -> Zeroizing<String> {
+    // little-endian base 58 digits
+    let mut digits: Zeroizing<Vec<u8>> =
+        Zeroizing::new(Vec::with_capacity(input.len() * 138 / 100 + 1));
+    for byte in input.iter() {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry != 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+        carry.zeroize();
+    }
+    let zeros = input.iter().take_while(|byte| **byte == 0).count();
+    let mut out = Zeroizing::new(String::with_capacity(zeros + digits.len()));
+    for _ in 0..zeros {
+        out.push('1');
+    }
+    for digit in digits.iter().rev() {
+        out.push(BASE58_ALPHABET[*digit as usize] as char);
+    }
+    out
+}
+
+// Base58 to bytes, leading `1`s giving leading zero bytes.
+fn base58_decode(input: &str) -> Result<Zeroizing<Vec<u8>>, ErrorMnemonic> {
+    let mut bytes: Zeroizing<Vec<u8>> = Zeroizing::new(Vec::with_capacity(input.len()));
+    for c in input.bytes() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|symbol| *symbol == c)
+            .ok_or(ErrorMnemonic::InvalidExtendedKey)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry != 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+        carry.zeroize();
+    }
+    for _ in input.bytes().take_while(|c| *c == b'1') {
+        bytes.push(0);
+    }
+    bytes.reverse();
+    Ok(bytes)
+}
@@ -0,0 +1,80 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::bip32::ExtendedPrivateKey;
+use crate::error::ErrorMnemonic;
+use crate::regular::Language;
+use crate::{MnemonicType, WordSet};
+
+/// `83696968'`, "SEED" on a phone keypad.
+pub const BIP85_PURPOSE: u32 = 83696968;
+pub const BIP39_APPLICATION: u32 = 39;
+
+pub const BIP85_ENTROPY_LEN: usize = 64;
+
+const ENTROPY_KEY: &[u8] = b"bip-entropy-from-k";
+
+/// BIP-85 entropy at `m/83696968'/path'`, all indices hardened: HMAC-SHA512
+/// keyed `"bip-entropy-from-k"` over the derived secret key.
+pub fn derive_entropy(
+    root: &ExtendedPrivateKey,
+    path: &[u32],
+) -> Result<Zeroizing<[u8; BIP85_ENTROPY_LEN]>, ErrorMnemonic> {
+    let key = root
+        .derive_hardened(BIP85_PURPOSE)?
+        .derive_hardened_path(path)?;
+    let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(ENTROPY_KEY)
+        .map_err(|_| ErrorMnemonic::SeedDerivation)?;
+    mac.update(key.secret_bytes());
+    Ok(Zeroizing::new(mac.finalize().into_bytes().into()))
+}
+
+impl Language {
+    /// Language index of the BIP-85 BIP-39 application.
+    pub fn bip85_code(self) -> u32 {
+        match self {
+            Language::English => 0,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => 1,
+            #[cfg(feature = "korean")]
+            Language::Korean => 2,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => 3,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => 4,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => 5,
+            #[cfg(feature = "french")]
+            Language::French => 6,
+            #[cfg(feature = "italian")]
+            Language::Italian => 7,
+            #[cfg(feature = "czech")]
+            Language::Czech => 8,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => 9,
+        }
+    }
+}
+
+impl WordSet {
+    /// Child mnemonic of BIP-85 application 39', at
+    /// `m/83696968'/39'/language'/words'/index'`. The language only enters
+    /// the path: the set is to be shown on the list of `language`. BIP-85
+    /// lists 12, 18 and 24 words.
+    pub fn from_bip85(
+        root: &ExtendedPrivateKey,
+        language: Language,
+        mnemonic_type: MnemonicType,
+        index: u32,
+    ) -> Result<Self, ErrorMnemonic> {
+        let path = [
+            BIP39_APPLICATION,
+            language.bip85_code(),
+            mnemonic_type.word_count() as u32,
+            index,
+        ];
+        let entropy = derive_entropy(root, &path)?;
+        Self::from_entropy(&entropy[..mnemonic_type.entropy_bytes()])
+    }
+}
@@ -0,0 +1,205 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use core::cell::RefCell;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use zeroize::Zeroize;
+
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, Bits11, WordIndex, WordListElement};
+
+// Word lists on SPI flash or an SD card pay for every read, and entering,
+// validating and then showing a phrase looks up the same words several
+// times. `CachedWordList` keeps the last `N` words looked up, and evicts the
+// least recently used one when full.
+//
+// The cache holds words of the phrase being handled, so it is wiped on
+// `flush` and on drop. A cache hit is faster than a miss, which shows that a
+// word was looked up recently; `bits11_for_word_ct` bypasses the cache.
+
+struct CacheEntry<W, I> {
+    bits11: I,
+    word: W,
+    last_used: u64,
+}
+
+struct Cache<W, I, const N: usize> {
+    entries: [Option<CacheEntry<W, I>>; N],
+    clock: u64,
+}
+
+impl<W: AsRef<str> + Clone + Zeroize, I: WordIndex, const N: usize> Cache<W, I, N> {
+    fn touch(&mut self, found: impl Fn(&CacheEntry<W, I>) -> bool) -> Option<&CacheEntry<W, I>> {
+        self.clock += 1;
+        let clock = self.clock;
+        let entry = self
+            .entries
+            .iter_mut()
+            .flatten()
+            .find(|entry| found(entry))?;
+        entry.last_used = clock;
+        Some(entry)
+    }
+
+    fn insert(&mut self, bits11: I, word: &W) {
+        if self.touch(|entry| entry.bits11 == bits11).is_some() {
+            return;
+        }
+        // a free slot, or else the least recently used one
+        let Some(slot) = self.entries.iter_mut().min_by_key(|slot| match slot {
+            Some(entry) => entry.last_used,
+            None => 0,
+        }) else {
+            return;
+        };
+        if let Some(evicted) = slot.as_mut() {
+            evicted.word.zeroize();
+        }
+        *slot = Some(CacheEntry {
+            bits11,
+            word: word.clone(),
+            last_used: self.clock,
+        });
+    }
+
+    fn flush(&mut self) {
+        for slot in self.entries.iter_mut() {
+            if let Some(entry) = slot.as_mut() {
+                entry.bits11.zeroize();
+                entry.word.zeroize();
+            }
+            *slot = None;
+        }
+        self.clock = 0;
+    }
+}
+
+/// `inner` with the last `N` words looked up kept in memory, e.g.
+/// `CachedWordList<_, 24>` for a whole phrase. Lookups that miss read
+/// through to `inner`; prefix searches are not cached. `I` is the index of
+/// `inner`, e.g. `CachedWordList<_, 25, MoneroWordIndex>` for a Monero list.
+pub struct CachedWordList<L: AsWordList<I>, const N: usize, I: WordIndex = Bits11>
+where
+    L::Word: Clone + Zeroize,
+{
+    inner: L,
+    cache: RefCell<Cache<L::Word, I, N>>,
+}
+
+impl<L: AsWordList<I>, const N: usize, I: WordIndex> CachedWordList<L, N, I>
+where
+    L::Word: Clone + Zeroize,
+{
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(Cache {
+                entries: core::array::from_fn(|_| None),
+                clock: 0,
+            }),
+        }
+    }
+
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Number of words held.
+    pub fn cached(&self) -> usize {
+        self.cache
+            .try_borrow()
+            .map(|cache| cache.entries.iter().flatten().count())
+            .unwrap_or_default()
+    }
+
+    /// Zeroize and drop every cached word, e.g. once a phrase is done with.
+    pub fn flush(&mut self) {
+        self.cache.get_mut().flush();
+    }
+
+    fn cache(&self) -> Result<core::cell::RefMut<'_, Cache<L::Word, I, N>>, ErrorMnemonic> {
+        self.cache
+            .try_borrow_mut()
+            .map_err(|_| ErrorMnemonic::WordListBusy)
+    }
+}
+
+impl<L: AsWordList<I>, const N: usize, I: WordIndex> Drop for CachedWordList<L, N, I>
+where
+    L::Word: Clone + Zeroize,
+{
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl<L: AsWordList<I>, const N: usize, I: WordIndex> Debug for CachedWordList<L, N, I>
+where
+    L::Word: Clone + Zeroize,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "CachedWordList {{ capacity: {N} }}")
+    }
+}
+
+impl<L: AsWordList<I>, const N: usize, I: WordIndex> AsWordList<I> for CachedWordList<L, N, I>
+where
+    L::Word: Clone + Zeroize,
+{
+    type Word = L::Word;
+
+    fn get_word(&self, bits: I) -> Result<Self::Word, ErrorMnemonic> {
+        if let Some(entry) = self.cache()?.touch(|entry| entry.bits11 == bits) {
+            return Ok(entry.word.clone());
+        }
+        let word = self.inner.get_word(bits)?;
+        self.cache()?.insert(bits, &word);
+        Ok(word)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic> {
+        Ok(self
+            .inner
+            .get_words_by_prefix(prefix)?
+            .into_iter()
+            .map(|element| WordListElement {
+                word: element.word,
+                bits11: element.bits11,
+            })
+            .collect())
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        if let Some(entry) = self.cache()?.touch(|entry| entry.word.as_ref() == word) {
+            return Ok(entry.bits11);
+        }
+        let bits11 = self.inner.bits11_for_word(word)?;
+        // one more read, so that rendering the phrase afterwards hits
+        let found = self.inner.get_word(bits11)?;
+        self.cache()?.insert(bits11, &found);
+        Ok(bits11)
+    }
+
+    fn bits11_for_word_ct(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        self.inner.bits11_for_word_ct(word)
+    }
+
+    fn separator(&self) -> char {
+        self.inner.separator()
+    }
+
+    fn has_accents(&self) -> bool {
+        self.inner.has_accents()
+    }
+
+    fn count_by_prefix(&self, prefix: &str) -> Result<usize, ErrorMnemonic> {
+        self.inner.count_by_prefix(prefix)
+    }
+}
@@ -0,0 +1,65 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use zeroize::ZeroizeOnDrop;
+
+use crate::error::ErrorMnemonic;
+use crate::pbkdf2::pbkdf2_sha512;
+use crate::WordSet;
+
+pub const ICARUS_MASTER_KEY_LEN: usize = 96;
+pub const ICARUS_PBKDF2_ROUNDS: u32 = 4096;
+
+const EXTENDED_SECRET_LEN: usize = 64;
+
+/// Cardano root key in the Icarus scheme of CIP-3: the 64 byte extended
+/// ed25519 secret followed by the 32 byte chain code, as `ed25519-bip32`
+/// takes it.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct IcarusMasterKey([u8; ICARUS_MASTER_KEY_LEN]);
+
+impl IcarusMasterKey {
+    pub fn as_bytes(&self) -> &[u8; ICARUS_MASTER_KEY_LEN] {
+        &self.0
+    }
+
+    pub fn extended_secret(&self) -> &[u8] {
+        &self.0[..EXTENDED_SECRET_LEN]
+    }
+
+    pub fn chain_code(&self) -> &[u8] {
+        &self.0[EXTENDED_SECRET_LEN..]
+    }
+}
+
+impl AsRef<[u8]> for IcarusMasterKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for IcarusMasterKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "IcarusMasterKey(..)")
+    }
+}
+
+impl WordSet {
+    /// Icarus master key: PBKDF2-HMAC-SHA512 keyed with the passphrase over
+    /// the entropy as salt, 4096 rounds, then clamped for ed25519. Unlike
+    /// BIP-39 the phrase itself is not hashed, and the passphrase is used as
+    /// is, without NFKD.
+    pub fn to_icarus_master_key(&self, passphrase: &str) -> Result<IcarusMasterKey, ErrorMnemonic> {
+        let entropy = self.to_entropy_secret()?;
+        let mut key = IcarusMasterKey([0u8; ICARUS_MASTER_KEY_LEN]);
+        pbkdf2_sha512(
+            passphrase.as_bytes(),
+            &entropy,
+            ICARUS_PBKDF2_ROUNDS,
+            &mut key.0,
+        )?;
+        key.0[0] &= 0b1111_1000;
+        key.0[31] &= 0b0001_1111;
+        key.0[31] |= 0b0100_0000;
+        Ok(key)
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use zeroize::ZeroizeOnDrop;
+
+use crate::ct::ct_eq_u16;
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, WordSet};
+
+/// "Now type your phrase again" backup check. Words are re-entered one at a
+/// time and each is compared to the target without revealing through timing
+/// where they differ.
+#[derive(ZeroizeOnDrop)]
+pub struct ConfirmSession {
+    #[zeroize(skip)]
+    target: WordSet,
+    verified: Vec<bool>,
+}
+
+impl ConfirmSession {
+    pub fn new(target: WordSet) -> Self {
+        let verified = Vec::with_capacity(target.bits11_set.len());
+        Self { target, verified }
+    }
+
+    /// Checks the next re-typed word; `Ok(false)` on a mismatch. A word
+    /// missing from the list is an error and does not use up the position.
+    pub fn verify_next<L: AsWordList>(
+        &mut self,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<bool, ErrorMnemonic> {
+        let position = self.verified.len();
+        if position == self.target.bits11_set.len() {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let bits11 = wordlist.bits11_for_word(word)?;
+        let matched = ct_eq_u16(bits11.bits(), self.target.bits11_set[position].bits()) == 1;
+        self.verified.push(matched);
+        Ok(matched)
+    }
+
+    /// Number of words re-entered so far.
+    pub fn position(&self) -> usize {
+        self.verified.len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.verified.len() == self.target.bits11_set.len()
+    }
+
+    /// All words re-entered and all of them matched.
+    pub fn is_confirmed(&self) -> bool {
+        let all_matched = self
+            .verified
+            .iter()
+            .fold(1u8, |acc, matched| acc & u8::from(*matched));
+        self.is_complete() & (all_matched == 1)
+    }
+
+    pub fn failed_positions(&self) -> Vec<usize> {
+        self.verified
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !**matched)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
@@ -0,0 +1,19 @@
+// Branch-free comparisons for secret values. The result is only turned into
+// a `bool` once all inputs have been combined.
+
+/// `1` if `a == b`, `0` otherwise.
+pub(crate) fn ct_eq_u16(a: u16, b: u16) -> u8 {
+    let diff = (a ^ b) as u32;
+    // `diff - 1` underflows into the top bit only when `diff == 0`
+    (diff.wrapping_sub(1) >> 31) as u8
+}
+
+/// `1` if `a == b`, `0` otherwise. Runs over the longer of the two, so only
+/// the lengths show in the timing.
+pub(crate) fn ct_eq_bytes(a: &[u8], b: &[u8]) -> u8 {
+    let mut diff = u16::from(a.len() != b.len());
+    for i in 0..a.len().max(b.len()) {
+        diff |= (a.get(i).copied().unwrap_or(0) ^ b.get(i).copied().unwrap_or(0)) as u16;
+    }
+    ct_eq_u16(diff, 0)
+}
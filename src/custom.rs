@@ -0,0 +1,356 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{collections::HashMap, string::String, vec::Vec};
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::error::{ErrorMnemonic, WordlistAudit};
+use crate::{AsWordList, Bits11, WordIndex, WordListElement, TOTAL_WORDS};
+
+/// Characters that identify a word in lists built for entry by prefix, as
+/// the BIP-39 lists are.
+pub const UNIQUE_PREFIX_LEN: usize = 4;
+
+/// Checks a runtime-loaded word list before it is used as [`AsWordList`].
+pub fn audit_wordlist(words: &[&str]) -> Result<(), WordlistAudit> {
+    audit_wordlist_indexed::<Bits11, _>(words)
+}
+
+/// As [`audit_wordlist`], for a list indexed by `I`, e.g. a Monero list.
+pub fn audit_wordlist_indexed<I: WordIndex, S: AsRef<str>>(
+    words: &[S],
+) -> Result<(), WordlistAudit> {
+    if words.len() != I::TOTAL {
+        return Err(WordlistAudit::WrongLength { len: words.len() });
+    }
+    for (index, word) in words.iter().enumerate() {
+        // typically a trailing newline in the source file
+        if word.as_ref().trim().is_empty() {
+            return Err(WordlistAudit::EmptyWord { index });
+        }
+    }
+    Ok(())
+}
+
+// Lookups over the entries of a list kept as a slice, each word handed out as
+// a copy of its entry. Searches scan in list order, so the first of repeated
+// entries wins.
+pub(crate) fn slice_get_word<S: Clone, I: WordIndex>(
+    words: &[S],
+    bits: I,
+) -> Result<S, ErrorMnemonic> {
+    words
+        .get(bits.index() as usize)
+        .cloned()
+        .ok_or(ErrorMnemonic::DamagedWord)
+}
+
+pub(crate) fn slice_words_by_prefix<L, S, I>(
+    words: &[S],
+    prefix: &str,
+) -> Result<Vec<WordListElement<L, I>>, ErrorMnemonic>
+where
+    L: AsWordList<I, Word = S> + ?Sized,
+    S: AsRef<str> + Clone,
+    I: WordIndex,
+{
+    let mut out: Vec<WordListElement<L, I>> = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        if word.as_ref().starts_with(prefix) {
+            out.push(WordListElement {
+                word: word.clone(),
+                bits11: I::from_index(i as u16)?,
+            })
+        }
+    }
+    Ok(out)
+}
+
+pub(crate) fn slice_bits11_for_word<S: AsRef<str>, I: WordIndex>(
+    words: &[S],
+    word: &str,
+) -> Result<I, ErrorMnemonic> {
+    for (i, element) in words.iter().enumerate() {
+        if element.as_ref() == word {
+            return I::from_index(i as u16);
+        }
+    }
+    Err(ErrorMnemonic::NoWord)
+}
+
+/// Word list borrowed from a runtime-provided slice, e.g. loaded from a file.
+#[derive(Clone, Copy, Debug)]
+pub struct SliceWordList<'a> {
+    words: &'a [&'a str],
+}
+
+impl<'a> SliceWordList<'a> {
+    pub fn new(words: &'a [&'a str]) -> Result<Self, WordlistAudit> {
+        audit_wordlist(words)?;
+        Ok(Self { words })
+    }
+}
+
+impl<'a> AsWordList for SliceWordList<'a> {
+    type Word = &'a str;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        slice_get_word(self.words, bits)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        slice_words_by_prefix(self.words, prefix)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        slice_bits11_for_word(self.words, word)
+    }
+}
+
+/// Slice word list with a hash index built once at construction, for
+/// validating many phrases: `bits11_for_word` is a single lookup instead of a
+/// scan. Prefix queries still scan.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct PrecomputedWordList<'a> {
+    words: &'a [&'a str],
+    index: HashMap<&'a str, Bits11>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> PrecomputedWordList<'a> {
+    pub fn new(words: &'a [&'a str]) -> Result<Self, WordlistAudit> {
+        audit_wordlist(words)?;
+        let mut index = HashMap::with_capacity(TOTAL_WORDS);
+        for (i, word) in words.iter().enumerate() {
+            // the audit fixed the length at 2048, so `i` fits; first
+            // occurrence wins, as in a scan
+            index.entry(*word).or_insert(Bits11(i as u16));
+        }
+        Ok(Self { words, index })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> AsWordList for PrecomputedWordList<'a> {
+    type Word = &'a str;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        slice_get_word(self.words, bits)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        slice_words_by_prefix(self.words, prefix)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        self.index.get(word).copied().ok_or(ErrorMnemonic::NoWord)
+    }
+}
+
+/// Word list parsed at compile time from newline-separated text, typically
+/// `include_str!`-ed:
+///
+/// ```ignore
+/// static WORDS: StaticWordList = StaticWordList::from_newline_str(include_str!("words.txt"));
+/// ```
+///
+/// Malformed input (wrong number of lines, empty or whitespace-only lines)
+/// fails const evaluation.
+#[derive(Clone, Debug)]
+pub struct StaticWordList {
+    words: [&'static str; TOTAL_WORDS],
+}
+
+impl StaticWordList {
+    pub const fn from_newline_str(s: &'static str) -> Self {
+        let mut words: [&'static str; TOTAL_WORDS] = [""; TOTAL_WORDS];
+        let mut count = 0;
+        let mut rest = s.as_bytes();
+        while !rest.is_empty() {
+            let mut line_len = 0;
+            while line_len < rest.len() && rest[line_len] != b'\n' {
+                line_len += 1;
+            }
+            let (mut line, tail) = rest.split_at(line_len);
+            rest = if tail.is_empty() {
+                tail
+            } else {
+                tail.split_at(1).1
+            };
+            if let [head @ .., b'\r'] = line {
+                line = head;
+            }
+
+            let mut i = 0;
+            while i < line.len() && line[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if i == line.len() {
+                panic!("word list contains an empty or whitespace only line");
+            }
+            if count == TOTAL_WORDS {
+                panic!("word list contains more than 2048 lines");
+            }
+            words[count] = match core::str::from_utf8(line) {
+                Ok(word) => word,
+                Err(_) => panic!("word list is not valid utf8"),
+            };
+            count += 1;
+        }
+        if count != TOTAL_WORDS {
+            panic!("word list contains less than 2048 lines");
+        }
+        Self { words }
+    }
+
+    pub fn words(&self) -> &[&'static str] {
+        &self.words
+    }
+}
+
+impl AsWordList for StaticWordList {
+    type Word = &'static str;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        slice_get_word(&self.words, bits)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        slice_words_by_prefix(&self.words, prefix)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        slice_bits11_for_word(&self.words, word)
+    }
+}
+
+/// Owned word list loaded at runtime, e.g. a translated or branded list.
+///
+/// Construction checks the length, that no entry is empty, and that the
+/// entries are unique and sorted bytewise, which lets lookups use a binary
+/// search. The checks stop at the first bad entry.
+///
+/// Lists of another length are indexed by another [`WordIndex`], e.g.
+/// `CustomWordList::<MoneroWordIndex>::new_indexed(&words)` for Monero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomWordList<I: WordIndex = Bits11> {
+    words: Vec<String>,
+    index: PhantomData<I>,
+}
+
+impl CustomWordList {
+    pub fn new<S: AsRef<str>>(words: &[S]) -> Result<Self, WordlistAudit> {
+        Self::new_indexed(words)
+    }
+
+    /// As `new`, taking the strings without copying them.
+    pub fn from_vec(words: Vec<String>) -> Result<Self, WordlistAudit> {
+        Self::from_vec_indexed(words)
+    }
+}
+
+impl<I: WordIndex> CustomWordList<I> {
+    /// As `new`, for a list indexed by `I`.
+    pub fn new_indexed<S: AsRef<str>>(words: &[S]) -> Result<Self, WordlistAudit> {
+        Self::from_vec_indexed(
+            words
+                .iter()
+                .map(|word| String::from(word.as_ref()))
+                .collect(),
+        )
+    }
+
+    /// As `from_vec`, for a list indexed by `I`.
+    pub fn from_vec_indexed(words: Vec<String>) -> Result<Self, WordlistAudit> {
+        if words.len() != I::TOTAL {
+            return Err(WordlistAudit::WrongLength { len: words.len() });
+        }
+        for (index, word) in words.iter().enumerate() {
+            if word.trim().is_empty() {
+                return Err(WordlistAudit::EmptyWord { index });
+            }
+            if index > 0 {
+                match words[index - 1].cmp(word) {
+                    Ordering::Less => {}
+                    Ordering::Equal => return Err(WordlistAudit::DuplicateWord { index }),
+                    Ordering::Greater => return Err(WordlistAudit::NotSorted { index }),
+                }
+            }
+        }
+        Ok(Self {
+            words,
+            index: PhantomData,
+        })
+    }
+
+    /// Also checks that the first [`UNIQUE_PREFIX_LEN`] characters tell every
+    /// word apart, so that users may type only those.
+    pub fn with_unique_prefixes(self) -> Result<Self, WordlistAudit> {
+        let prefix = |word: &str| {
+            word.char_indices()
+                .nth(UNIQUE_PREFIX_LEN)
+                .map_or(word.len(), |(end, _)| end)
+        };
+        for index in 1..self.words.len() {
+            let (before, word) = (&self.words[index - 1], &self.words[index]);
+            // sorted, so words sharing a prefix are next to each other
+            if before[..prefix(before)] == word[..prefix(word)] {
+                return Err(WordlistAudit::SharedPrefix { index });
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl<I: WordIndex> AsWordList<I> for CustomWordList<I> {
+    type Word = String;
+
+    fn get_word(&self, bits: I) -> Result<Self::Word, ErrorMnemonic> {
+        slice_get_word(&self.words, bits)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic> {
+        let start = self.words.partition_point(|word| word.as_str() < prefix);
+        let mut out: Vec<WordListElement<Self, I>> = Vec::new();
+        for (i, word) in self.words[start..].iter().enumerate() {
+            if !word.starts_with(prefix) {
+                break;
+            }
+            out.push(WordListElement {
+                word: word.clone(),
+                bits11: I::from_index((start + i) as u16)?,
+            })
+        }
+        Ok(out)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        match self
+            .words
+            .binary_search_by(|element| element.as_str().cmp(word))
+        {
+            Ok(i) => I::from_index(i as u16),
+            Err(_) => Err(ErrorMnemonic::NoWord),
+        }
+    }
+}
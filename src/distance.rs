@@ -0,0 +1,60 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Edit distance between `a` and `b` if it does not exceed `max`, counting a
+/// swap of two adjacent characters as one edit (restricted Damerau, or
+/// optimal string alignment): hand-copied phrases are full of those.
+///
+/// Only the diagonal band of width `2 * max + 1` is computed, and the
+/// calculation stops as soon as a whole row exceeds `max`, so words far from
+/// `a` are abandoned after a few characters. A transposition never lowers a
+/// cell below its left neighbour's, so the early exit still holds.
+pub(crate) fn bounded_edit_distance(a: &[char], b: &str, max: usize) -> Option<usize> {
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    // cells outside the band are treated as `max + 1`
+    let over = max + 1;
+    let mut before_previous: Vec<usize> = Vec::with_capacity(b.len() + 1);
+    let mut previous: Vec<usize> = (0..=b.len()).map(|j| j.min(over)).collect();
+    let mut current: Vec<usize> = Vec::with_capacity(b.len() + 1);
+
+    for (i, a_char) in a.iter().enumerate() {
+        let row = i + 1;
+        let band_start = row.saturating_sub(max);
+        let band_end = (row + max).min(b.len());
+
+        current.clear();
+        current.resize(b.len() + 1, over);
+        if band_start == 0 {
+            current[0] = row.min(over);
+        }
+
+        let mut row_min = current[0];
+        for j in band_start.max(1)..=band_end {
+            let substitution = previous[j - 1] + usize::from(*a_char != b[j - 1]);
+            let deletion = previous[j] + 1;
+            let insertion = current[j - 1] + 1;
+            let mut cell = substitution.min(deletion).min(insertion);
+            if i > 0 && j > 1 && *a_char == b[j - 2] && a[i - 1] == b[j - 1] {
+                cell = cell.min(before_previous[j - 2] + 1);
+            }
+            let cell = cell.min(over);
+            current[j] = cell;
+            row_min = row_min.min(cell);
+        }
+        if row_min > max {
+            return None;
+        }
+        core::mem::swap(&mut before_previous, &mut previous);
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+    (distance <= max).then_some(distance)
+}
@@ -0,0 +1,319 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(all(feature = "seed", not(feature = "std")))]
+use alloc::string::String;
+
+#[cfg(all(feature = "seed", feature = "std"))]
+use std::string::String;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "generate")]
+use rand_core::CryptoRngCore;
+
+use crate::error::ErrorMnemonic;
+use crate::regular::InternalWordList;
+use crate::{AsWordList, Bits11, WordSet, BITS_IN_BYTE, BITS_IN_U11, MAX_SEED_LEN};
+
+#[cfg(feature = "seed")]
+use crate::seed::{pbkdf2_hmac_sha512, Seed, SEED_LEN};
+
+/// Entropy of freshly generated seeds: 12 words.
+pub const ELECTRUM_ENTROPY_BITS: usize = 132;
+
+#[cfg(feature = "generate")]
+const ELECTRUM_ENTROPY_LEN: usize = 17;
+
+const VERSION_KEY: &[u8] = b"Seed version";
+
+#[cfg(feature = "seed")]
+const ELECTRUM_SALT_PREFIX: &str = "electrum";
+
+/// Electrum seed versions. Instead of a checksum, the hex digest of
+/// HMAC-SHA512 keyed with `"Seed version"` over the phrase starts with the
+/// version prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElectrumSeedType {
+    Standard,
+    Segwit,
+    TwoFactor,
+    TwoFactorSegwit,
+}
+
+impl ElectrumSeedType {
+    pub const ALL: &'static [ElectrumSeedType] = &[
+        ElectrumSeedType::Standard,
+        ElectrumSeedType::Segwit,
+        ElectrumSeedType::TwoFactor,
+        ElectrumSeedType::TwoFactorSegwit,
+    ];
+
+    pub fn prefix(self) -> &'static str {
+        match self {
+            ElectrumSeedType::Standard => "01",
+            ElectrumSeedType::Segwit => "100",
+            ElectrumSeedType::TwoFactor => "101",
+            ElectrumSeedType::TwoFactorSegwit => "102",
+        }
+    }
+
+    fn matches(self, digest: &[u8]) -> bool {
+        self.prefix().chars().enumerate().all(|(i, c)| {
+            let nibble = (digest[i / 2] >> (4 * (1 - i % 2))) & 0xF;
+            c.to_digit(16) == Some(nibble as u32)
+        })
+    }
+}
+
+impl WordSet {
+    /// Electrum phrase on the English list. Any number of words up to
+    /// `MAX_SEED_LEN` is accepted, as Electrum does not fix the length; the
+    /// version prefix must match one of [`ElectrumSeedType::ALL`].
+    pub fn from_electrum_phrase(phrase: &str) -> Result<(Self, ElectrumSeedType), ErrorMnemonic> {
+        let mut word_set = Self::new();
+        for (position, word) in phrase.split_whitespace().enumerate() {
+            if word_set.bits11_set.len() == MAX_SEED_LEN {
+                return Err(ErrorMnemonic::WordsNumber);
+            }
+            let bits11 = InternalWordList
+                .bits11_for_word_lenient(word)
+                .map_err(|error| error.at(position))?;
+            word_set.bits11_set.push(bits11);
+        }
+        let seed_type = word_set
+            .electrum_seed_type()?
+            .ok_or(ErrorMnemonic::ElectrumVersion)?;
+        Ok((word_set, seed_type))
+    }
+
+    /// Version of the set read as an Electrum phrase on the English list,
+    /// `None` if no known prefix matches.
+    pub fn electrum_seed_type(&self) -> Result<Option<ElectrumSeedType>, ErrorMnemonic> {
+        if self.bits11_set.is_empty() {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(VERSION_KEY)
+            .map_err(|_| ErrorMnemonic::SeedDerivation)?;
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            if position != 0 {
+                mac.update(b" ");
+            }
+            let word = InternalWordList
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?;
+            mac.update(word.as_bytes());
+        }
+        let mut digest = <[u8; 64]>::from(mac.finalize().into_bytes());
+        let seed_type = ElectrumSeedType::ALL
+            .iter()
+            .copied()
+            .find(|seed_type| seed_type.matches(&digest));
+        digest.zeroize();
+        Ok(seed_type)
+    }
+
+    /// Set from Electrum entropy: a big-endian integer, written in base 2048
+    /// with the least significant word first. Leading zero bytes, and so
+    /// trailing zero words, do not show in the phrase.
+    pub fn from_electrum_entropy(entropy: &[u8]) -> Result<Self, ErrorMnemonic> {
+        let mut word_set = Self::new();
+        let mut acc: u32 = 0;
+        let mut acc_bits = 0;
+        for byte in entropy.iter().rev() {
+            acc |= (*byte as u32) << acc_bits;
+            acc_bits += BITS_IN_BYTE;
+            if acc_bits >= BITS_IN_U11 {
+                word_set.push_electrum_word(acc & 0x7FF)?;
+                acc >>= BITS_IN_U11;
+                acc_bits -= BITS_IN_U11;
+            }
+        }
+        if acc_bits != 0 {
+            word_set.push_electrum_word(acc)?;
+        }
+        acc.zeroize();
+
+        while let Some(0) = word_set.bits11_set.last().map(|bits11| bits11.bits()) {
+            word_set.bits11_set.pop();
+        }
+        if word_set.bits11_set.is_empty() {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        Ok(word_set)
+    }
+
+    // Zero words past `MAX_SEED_LEN` are fine, they are dropped afterwards.
+    fn push_electrum_word(&mut self, bits: u32) -> Result<(), ErrorMnemonic> {
+        if self.bits11_set.len() == MAX_SEED_LEN {
+            if bits == 0 {
+                return Ok(());
+            }
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.bits11_set.push(Bits11(bits as u16));
+        Ok(())
+    }
+
+    /// Electrum entropy of the set, big-endian, `ceil(11 * words / 8)`
+    /// bytes long.
+    pub fn to_electrum_entropy(&self) -> Zeroizing<Vec<u8>> {
+        let len = (self.bits11_set.len() * BITS_IN_U11).div_ceil(BITS_IN_BYTE);
+        let mut entropy = Zeroizing::new(Vec::with_capacity(len));
+        let mut acc: u32 = 0;
+        let mut acc_bits = 0;
+        for bits11 in self.bits11_set.iter() {
+            acc |= (bits11.bits() as u32) << acc_bits;
+            acc_bits += BITS_IN_U11;
+            while acc_bits >= BITS_IN_BYTE {
+                entropy.push(acc as u8);
+                acc >>= BITS_IN_BYTE;
+                acc_bits -= BITS_IN_BYTE;
+            }
+        }
+        if acc_bits != 0 {
+            entropy.push(acc as u8);
+        }
+        acc.zeroize();
+        entropy.reverse();
+        entropy
+    }
+
+    /// Fresh 12-word Electrum seed of the requested type. As Electrum does,
+    /// the random entropy is counted up until the version matches, skipping
+    /// phrases that would also pass as BIP-39.
+    #[cfg(feature = "generate")]
+    pub fn generate_electrum<R: CryptoRngCore + ?Sized>(
+        seed_type: ElectrumSeedType,
+        rng: &mut R,
+    ) -> Self {
+        let mut entropy = Zeroizing::new([0u8; ELECTRUM_ENTROPY_LEN]);
+        // the most significant word, the low 4 bits of the first byte and
+        // the high 7 bits of the second, must not be zero, or the phrase is
+        // short
+        loop {
+            rng.fill_bytes(&mut entropy[..]);
+            entropy[0] &= 0x0F;
+            if entropy[0] != 0 || entropy[1] >> 1 != 0 {
+                break;
+            }
+        }
+        loop {
+            for byte in entropy.iter_mut().rev() {
+                *byte = byte.wrapping_add(1);
+                if *byte != 0 {
+                    break;
+                }
+            }
+            let word_set = match Self::from_electrum_entropy(&entropy[..]) {
+                Ok(word_set) => word_set,
+                Err(_) => continue,
+            };
+            if word_set.validate().is_ok() {
+                continue;
+            }
+            if let Ok(Some(found)) = word_set.electrum_seed_type() {
+                if found == seed_type {
+                    return word_set;
+                }
+            }
+        }
+    }
+
+    /// Electrum wallet seed: PBKDF2-HMAC-SHA512 over the phrase, salted with
+    /// `"electrum"` and the passphrase, both normalized the Electrum way
+    /// (NFKD, lowercase, no combining marks, single spaces, no spaces between
+    /// CJK characters). The version is checked first.
+    #[cfg(feature = "seed")]
+    pub fn to_electrum_seed(&self, passphrase: &str) -> Result<Seed, ErrorMnemonic> {
+        self.electrum_seed_type()?
+            .ok_or(ErrorMnemonic::ElectrumVersion)?;
+
+        // English words joined by single spaces are already normalized
+        let phrase = self.to_phrase_secret(&InternalWordList)?;
+        let passphrase = normalize_electrum_text(passphrase);
+        let mut salt = Zeroizing::new(String::with_capacity(
+            ELECTRUM_SALT_PREFIX.len() + passphrase.len(),
+        ));
+        salt.push_str(ELECTRUM_SALT_PREFIX);
+        salt.push_str(&passphrase);
+
+        let mut seed = Seed([0u8; SEED_LEN]);
+        pbkdf2_hmac_sha512(phrase.as_bytes(), salt.as_bytes(), &mut seed.0)?;
+        Ok(seed)
+    }
+}
+
+/// Electrum text normalization, as applied to phrases and passphrases.
+#[cfg(feature = "seed")]
+pub fn normalize_electrum_text(text: &str) -> Zeroizing<String> {
+    use unicode_normalization::char::canonical_combining_class;
+    use unicode_normalization::UnicodeNormalization;
+
+    let lowercase: Zeroizing<String> =
+        Zeroizing::new(text.nfkd().collect::<String>().to_lowercase());
+    let mut out = Zeroizing::new(String::with_capacity(lowercase.len()));
+    let mut previous: Option<char> = None;
+    let mut pending_space = false;
+    for c in lowercase
+        .chars()
+        .filter(|c| canonical_combining_class(*c) == 0)
+    {
+        if c.is_whitespace() {
+            pending_space = previous.is_some();
+            continue;
+        }
+        if pending_space && !(previous.is_some_and(is_cjk) && is_cjk(c)) {
+            out.push(' ');
+        }
+        pending_space = false;
+        out.push(c);
+        previous = Some(c);
+    }
+    out
+}
+
+// Blocks Electrum treats as CJK when dropping whitespace.
+#[cfg(feature = "seed")]
+fn is_cjk(c: char) -> bool {
+    const CJK_INTERVALS: &[(u32, u32)] = &[
+        (0x4E00, 0x9FFF),
+        (0x3400, 0x4DBF),
+        (0x20000, 0x2A6DF),
+        (0x2A700, 0x2B73F),
+        (0x2B740, 0x2B81F),
+        (0xF900, 0xFAFF),
+        (0x2F800, 0x2FA1D),
+        (0x3190, 0x319F),
+        (0x2E80, 0x2EFF),
+        (0x2F00, 0x2FDF),
+        (0x31C0, 0x31EF),
+        (0x2FF0, 0x2FFF),
+        (0xE0100, 0xE01EF),
+        (0x3100, 0x312F),
+        (0x31A0, 0x31BF),
+        (0xFF00, 0xFFEF),
+        (0x3040, 0x309F),
+        (0x30A0, 0x30FF),
+        (0x31F0, 0x31FF),
+        (0x1B000, 0x1B0FF),
+        (0xAC00, 0xD7AF),
+        (0x1100, 0x11FF),
+        (0xA960, 0xA97F),
+        (0xD7B0, 0xD7FF),
+        (0x3130, 0x318F),
+        (0xA4D0, 0xA4FF),
+        (0x16F00, 0x16F9F),
+        (0xA000, 0xA48F),
+        (0xA490, 0xA4CF),
+    ];
+    let c = c as u32;
+    CJK_INTERVALS
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&c))
+}
@@ -0,0 +1,217 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use crate::error::ErrorMnemonic;
+use crate::{MnemonicType, WordSet, BITS_IN_BYTE, MAX_ENTROPY_LEN};
+
+// Entropy from physical sources, for air-gapped generation. Bits are packed
+// most significant first, in the order they were collected.
+
+#[derive(Clone, ZeroizeOnDrop)]
+struct BitCollector {
+    bytes: [u8; MAX_ENTROPY_LEN],
+    #[zeroize(skip)]
+    bits: usize,
+    #[zeroize(skip)]
+    target: usize,
+}
+
+impl BitCollector {
+    fn new(mnemonic_type: MnemonicType) -> Self {
+        Self {
+            bytes: [0; MAX_ENTROPY_LEN],
+            bits: 0,
+            target: mnemonic_type.entropy_bits(),
+        }
+    }
+
+    // bits past the target are dropped
+    fn push_bit(&mut self, bit: bool) {
+        if self.bits < self.target {
+            self.bytes[self.bits / BITS_IN_BYTE] |=
+                u8::from(bit) << (BITS_IN_BYTE - 1 - self.bits % BITS_IN_BYTE);
+            self.bits += 1;
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.target - self.bits
+    }
+
+    fn entropy(&self) -> Result<&[u8], ErrorMnemonic> {
+        match self.remaining() {
+            0 => Ok(&self.bytes[..self.target / BITS_IN_BYTE]),
+            missing_bits => Err(ErrorMnemonic::EntropyIncomplete { missing_bits }),
+        }
+    }
+}
+
+/// Entropy from rolls of a fair six-sided die.
+///
+/// Six outcomes do not split into whole bits, so each roll gives either two
+/// bits or one: 1 to 4 give `00`, `01`, `10`, `11`, and 5 and 6 give `0` and
+/// `1`. Within each group every outcome is equally likely, so the bits are
+/// unbiased for a fair die, nothing is hashed and no roll is wasted; on
+/// average a roll gives 5/3 bits, so 12 words take about 77 rolls and 24
+/// words about 154. A loaded die is not corrected for.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct DiceRolls {
+    collector: BitCollector,
+    #[zeroize(skip)]
+    rolls: usize,
+}
+
+impl DiceRolls {
+    pub fn new(mnemonic_type: MnemonicType) -> Self {
+        Self {
+            collector: BitCollector::new(mnemonic_type),
+            rolls: 0,
+        }
+    }
+
+    /// Adds a roll, 1 to 6. Rolls after [`is_complete`](Self::is_complete)
+    /// are ignored.
+    pub fn roll(&mut self, value: u8) -> Result<(), ErrorMnemonic> {
+        let outcome = match value {
+            1..=6 => value - 1,
+            _ => return Err(ErrorMnemonic::DiceRoll),
+        };
+        if self.collector.remaining() == 0 {
+            return Ok(());
+        }
+        if outcome < 4 {
+            self.collector.push_bit(outcome & 0b10 != 0);
+            self.collector.push_bit(outcome & 0b01 != 0);
+        } else {
+            self.collector.push_bit(outcome & 0b01 != 0);
+        }
+        self.rolls += 1;
+        Ok(())
+    }
+
+    /// Rolls that contributed bits.
+    pub fn rolls(&self) -> usize {
+        self.rolls
+    }
+
+    pub fn bits_collected(&self) -> usize {
+        self.collector.bits
+    }
+
+    pub fn bits_remaining(&self) -> usize {
+        self.collector.remaining()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.collector.remaining() == 0
+    }
+
+    /// `EntropyIncomplete` until enough rolls are in.
+    pub fn entropy(&self) -> Result<&[u8], ErrorMnemonic> {
+        self.collector.entropy()
+    }
+
+    pub fn to_word_set(&self) -> Result<WordSet, ErrorMnemonic> {
+        WordSet::from_entropy(self.entropy()?)
+    }
+}
+
+/// Entropy from coin flips, one bit each: heads is `1`, tails is `0`. 12
+/// words take 128 flips, 24 words 256.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct CoinFlips {
+    collector: BitCollector,
+}
+
+impl CoinFlips {
+    pub fn new(mnemonic_type: MnemonicType) -> Self {
+        Self {
+            collector: BitCollector::new(mnemonic_type),
+        }
+    }
+
+    /// Adds a flip. Flips after [`is_complete`](Self::is_complete) are
+    /// ignored.
+    pub fn flip(&mut self, heads: bool) {
+        self.collector.push_bit(heads)
+    }
+
+    pub fn flips(&self) -> usize {
+        self.collector.bits
+    }
+
+    pub fn flips_remaining(&self) -> usize {
+        self.collector.remaining()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.collector.remaining() == 0
+    }
+
+    /// `EntropyIncomplete` until enough flips are in.
+    pub fn entropy(&self) -> Result<&[u8], ErrorMnemonic> {
+        self.collector.entropy()
+    }
+
+    pub fn to_word_set(&self) -> Result<WordSet, ErrorMnemonic> {
+        WordSet::from_entropy(self.entropy()?)
+    }
+}
+
+/// Letter case of [`WordSet::entropy_hex`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HexCase {
+    /// As in the BIP-39 reference vectors.
+    #[default]
+    Lower,
+    Upper,
+}
+
+fn hex_value(digit: u8) -> Result<u8, ErrorMnemonic> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ErrorMnemonic::InvalidHex),
+    }
+}
+
+impl WordSet {
+    /// Set from entropy written as hex, either case, as in the BIP-39
+    /// reference vectors. The decoded bytes are zeroized.
+    pub fn from_hex_entropy(hex: &str) -> Result<Self, ErrorMnemonic> {
+        let hex = hex.as_bytes();
+        if hex.len() % 2 != 0 {
+            return Err(ErrorMnemonic::InvalidHex);
+        }
+        let len = hex.len() / 2;
+        if len > MAX_ENTROPY_LEN {
+            return Err(ErrorMnemonic::EntropyTooLong { len });
+        }
+        let mut entropy = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+        for (byte, pair) in entropy.iter_mut().zip(hex.chunks_exact(2)) {
+            *byte = hex_value(pair[0])? << 4 | hex_value(pair[1])?;
+        }
+        Self::from_entropy(&entropy[..len])
+    }
+
+    /// Entropy as hex, wiped when dropped.
+    pub fn entropy_hex(&self, case: HexCase) -> Result<Zeroizing<String>, ErrorMnemonic> {
+        let digits = match case {
+            HexCase::Lower => b"0123456789abcdef",
+            HexCase::Upper => b"0123456789ABCDEF",
+        };
+        let entropy = self.to_entropy_secret()?;
+        let mut hex = Zeroizing::new(String::with_capacity(entropy.len() * 2));
+        for byte in entropy.iter() {
+            hex.push(digits[(byte >> 4) as usize] as char);
+            hex.push(digits[(byte & 0xf) as usize] as char);
+        }
+        Ok(hex)
+    }
+}
@@ -1,43 +1,324 @@
-#[cfg(feature = "std")]
-use std::string::String;
-
-#[cfg(not(feature = "std"))]
-use alloc::string::String;
-
-#[cfg(feature = "std")]
-use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
-
-#[cfg(not(feature = "std"))]
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
+use crate::custom::UNIQUE_PREFIX_LEN;
+use crate::TOTAL_WORDS;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error), uniffi(flat_error))]
 pub enum ErrorMnemonic {
+    /// AEZ authentication failed: wrong passphrase, or a damaged seed that
+    /// still passes the checksum.
+    AezeedDecryption,
+    AezeedVersion,
+    /// More than one word starts with the given prefix.
+    AmbiguousPrefix,
     DamagedWord,
+    /// `DamagedWord` for the word at zero-based `position` of a set.
+    DamagedWordAt {
+        position: usize,
+    },
+    /// A die roll outside 1 to 6.
+    DiceRoll,
+    ElectrumVersion,
+    /// Entropy collection stopped `missing_bits` short of the target.
+    EntropyIncomplete {
+        missing_bits: usize,
+    },
+    EntropyNotMultipleOfFour {
+        len: usize,
+    },
+    EntropyTooLong {
+        len: usize,
+    },
+    EntropyTooShort {
+        len: usize,
+    },
     InvalidChecksum,
-    InvalidEntropy,
+    InvalidExtendedKey,
+    /// Entropy text that is not an even number of hex digits.
+    InvalidHex,
+    InvalidMasterKey,
     InvalidWordNumber,
+    /// Keypad input with a character other than `2` to `9`.
+    KeypadDigits,
     NoWord,
+    /// `NoWord` for the word at zero-based `position` of a phrase. The
+    /// rejected word itself is not kept: it is likely a mistyped secret.
+    NoWordAt {
+        position: usize,
+    },
+    PhraseWrite,
+    /// Zero-based `position` past the end of the set.
+    PositionOutOfRange {
+        position: usize,
+    },
+    RandomSource,
+    SeedDerivation,
+    SeedQrDigits,
+    TooManyWords,
+    TruncationTarget,
+    UnknownLanguage,
+    /// More than one unknown word in a phrase to recover.
+    UnknownWords,
+    /// A word list device already in use by another lookup.
+    WordListBusy,
+    /// A word list that hashes to something other than the expected digest.
+    WordListDigest,
+    /// A word list blob header that is not `RAW_MAGIC` and a supported
+    /// version and record length.
+    WordListFormat,
+    /// Three Monero words that do not encode a 32-bit value.
+    WordTriple,
     WordsNumber,
+    /// Fewer than two XOR shares, or shares of different lengths.
+    XorShares,
 }
 
 impl ErrorMnemonic {
-    fn error_text(&self) -> String {
-        match &self {
-            ErrorMnemonic::DamagedWord => String::from("Unable to extract a word from the word list."),
-            ErrorMnemonic::InvalidChecksum => String::from("Invalid text mnemonic: the checksum does not match."),
-            ErrorMnemonic::InvalidEntropy => String::from("Unable to calculate the mnemonic from entropy. Invalid entropy length."),
-            ErrorMnemonic::InvalidWordNumber => String::from("Ordinal number for word requested is higher than total number of words in the word list."),
-            ErrorMnemonic::NoWord => String::from("Requested word in not in the word list."),
-            ErrorMnemonic::WordsNumber => String::from("Invalid text mnemonic: unexpected number of words."),
+    /// Attach the position of the word a lookup error is about.
+    pub(crate) fn at(self, position: usize) -> Self {
+        match self {
+            ErrorMnemonic::DamagedWord => ErrorMnemonic::DamagedWordAt { position },
+            ErrorMnemonic::NoWord => ErrorMnemonic::NoWordAt { position },
+            error => error,
+        }
+    }
+
+    /// Stable numeric code, for FFI and for logs that should not carry text.
+    /// Codes are never reused or renumbered; those of [`ErrorShare`] start at
+    /// 101 and those of [`WordlistAudit`] at 201.
+    pub const fn code(&self) -> u16 {
+        match self {
+            ErrorMnemonic::AezeedDecryption => 1,
+            ErrorMnemonic::AezeedVersion => 2,
+            ErrorMnemonic::AmbiguousPrefix => 3,
+            ErrorMnemonic::DamagedWord => 4,
+            ErrorMnemonic::DamagedWordAt { .. } => 5,
+            ErrorMnemonic::DiceRoll => 6,
+            ErrorMnemonic::ElectrumVersion => 7,
+            ErrorMnemonic::EntropyIncomplete { .. } => 8,
+            ErrorMnemonic::EntropyNotMultipleOfFour { .. } => 9,
+            ErrorMnemonic::EntropyTooLong { .. } => 10,
+            ErrorMnemonic::EntropyTooShort { .. } => 11,
+            ErrorMnemonic::InvalidChecksum => 12,
+            ErrorMnemonic::InvalidExtendedKey => 13,
+            ErrorMnemonic::InvalidHex => 14,
+            ErrorMnemonic::InvalidMasterKey => 15,
+            ErrorMnemonic::InvalidWordNumber => 16,
+            ErrorMnemonic::NoWord => 17,
+            ErrorMnemonic::NoWordAt { .. } => 18,
+            ErrorMnemonic::PhraseWrite => 19,
+            ErrorMnemonic::PositionOutOfRange { .. } => 20,
+            ErrorMnemonic::RandomSource => 21,
+            ErrorMnemonic::SeedDerivation => 22,
+            ErrorMnemonic::SeedQrDigits => 23,
+            ErrorMnemonic::TooManyWords => 24,
+            ErrorMnemonic::TruncationTarget => 25,
+            ErrorMnemonic::UnknownLanguage => 26,
+            ErrorMnemonic::WordListBusy => 27,
+            ErrorMnemonic::WordListDigest => 28,
+            ErrorMnemonic::WordListFormat => 29,
+            ErrorMnemonic::WordTriple => 30,
+            ErrorMnemonic::WordsNumber => 31,
+            ErrorMnemonic::XorShares => 32,
+            ErrorMnemonic::KeypadDigits => 33,
+            ErrorMnemonic::UnknownWords => 34,
+        }
+    }
+
+    /// Message without the details held in the variant, e.g. the position of
+    /// the word; `Display` has them.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ErrorMnemonic::AezeedDecryption => "Unable to decrypt the aezeed cipher seed: wrong passphrase or damaged phrase.",
+            ErrorMnemonic::AezeedVersion => "Unsupported aezeed cipher seed version.",
+            ErrorMnemonic::AmbiguousPrefix => "More than one word in the word list starts with this prefix.",
+            ErrorMnemonic::DamagedWord => "Unable to extract a word from the word list.",
+            ErrorMnemonic::DamagedWordAt { .. } => "Unable to extract a word of the set from the word list.",
+            ErrorMnemonic::DiceRoll => "Die roll must be between 1 and 6.",
+            ErrorMnemonic::ElectrumVersion => "Not an Electrum seed: the version prefix matches no known seed type.",
+            ErrorMnemonic::EntropyIncomplete { .. } => "Not enough entropy collected.",
+            ErrorMnemonic::EntropyNotMultipleOfFour { .. } => "Unable to calculate the mnemonic from entropy: length is not a multiple of 4 bytes.",
+            ErrorMnemonic::EntropyTooLong { .. } => "Unable to calculate the mnemonic from entropy: length is above the maximum of 32 bytes.",
+            ErrorMnemonic::EntropyTooShort { .. } => "Unable to calculate the mnemonic from entropy: length is below the minimum of 16 bytes.",
+            ErrorMnemonic::InvalidChecksum => "Invalid text mnemonic: the checksum does not match.",
+            ErrorMnemonic::InvalidExtendedKey => "Invalid BIP-32 extended private key: malformed, or not a valid secp256k1 secret key.",
+            ErrorMnemonic::InvalidHex => "Invalid hex entropy: only an even number of hex digits is allowed.",
+            ErrorMnemonic::InvalidMasterKey => "Derived master key is not a valid secp256k1 secret key.",
+            ErrorMnemonic::InvalidWordNumber => "Ordinal number for word requested is higher than total number of words in the word list.",
+            ErrorMnemonic::KeypadDigits => "Keypad input may only hold the digits 2 to 9.",
+            ErrorMnemonic::NoWord => "Requested word in not in the word list.",
+            ErrorMnemonic::NoWordAt { .. } => "A word of the phrase is not in the word list.",
+            ErrorMnemonic::PhraseWrite => "Unable to write the phrase into the output.",
+            ErrorMnemonic::PositionOutOfRange { .. } => "The set has no word at this position.",
+            ErrorMnemonic::RandomSource => "Unable to get entropy from the random number generator.",
+            ErrorMnemonic::SeedDerivation => "Unable to derive the seed.",
+            ErrorMnemonic::SeedQrDigits => "Invalid SeedQR payload: only decimal digits are allowed.",
+            ErrorMnemonic::TooManyWords => "Unable to add the word: the word set already has the maximum number of words.",
+            ErrorMnemonic::TruncationTarget => "Unable to truncate entropy: target length exceeds the entropy length.",
+            ErrorMnemonic::UnknownLanguage => "No built-in word list contains every word of the phrase.",
+            ErrorMnemonic::UnknownWords => "Unable to recover the phrase: only one word may be unknown.",
+            ErrorMnemonic::WordListBusy => "Word list is in use by another lookup.",
+            ErrorMnemonic::WordListDigest => "Word list does not match the official one: corrupted or replaced.",
+            ErrorMnemonic::WordListFormat => "Word list blob header is not recognized.",
+            ErrorMnemonic::WordTriple => "Invalid Monero phrase: a word triple does not encode a 32-bit value.",
+            ErrorMnemonic::WordsNumber => "Invalid text mnemonic: unexpected number of words.",
+            ErrorMnemonic::XorShares => "XOR backup needs at least two shares, all of the same length.",
         }
     }
 }
 
+// Positions are zero-based in the variants and counted from one for people.
 impl Display for ErrorMnemonic {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}", self.error_text())
+        match self {
+            ErrorMnemonic::DamagedWordAt { position } => write!(f, "Unable to extract word {} of the set from the word list.", position + 1),
+            ErrorMnemonic::EntropyIncomplete { missing_bits } => write!(f, "Not enough entropy collected: {missing_bits} bits missing."),
+            ErrorMnemonic::EntropyNotMultipleOfFour { len } => write!(f, "Unable to calculate the mnemonic from entropy: length {len} bytes is not a multiple of 4."),
+            ErrorMnemonic::EntropyTooLong { len } => write!(f, "Unable to calculate the mnemonic from entropy: length {len} bytes is above the maximum of 32."),
+            ErrorMnemonic::EntropyTooShort { len } => write!(f, "Unable to calculate the mnemonic from entropy: length {len} bytes is below the minimum of 16."),
+            ErrorMnemonic::NoWordAt { position } => write!(f, "Word {} of the phrase is not in the word list.", position + 1),
+            ErrorMnemonic::PositionOutOfRange { position } => write!(f, "The set has no word {}.", position + 1),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl core::error::Error for ErrorMnemonic {}
+
+/// SLIP-0039 share errors.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ErrorShare {
+    Checksum,
+    Digest,
+    DuplicateIndex,
+    Encryption,
+    GroupThreshold,
+    IterationExponent,
+    Length,
+    MemberThreshold,
+    MismatchedShares,
+    NoShares,
+    Padding,
+    SecretLength,
+    ShareCount,
+    Word(ErrorMnemonic),
+}
+
+impl ErrorShare {
+    /// Stable numeric code, see [`ErrorMnemonic::code`]; a word error keeps
+    /// its own code.
+    pub const fn code(&self) -> u16 {
+        match self {
+            ErrorShare::Checksum => 101,
+            ErrorShare::Digest => 102,
+            ErrorShare::DuplicateIndex => 103,
+            ErrorShare::Encryption => 104,
+            ErrorShare::GroupThreshold => 105,
+            ErrorShare::IterationExponent => 106,
+            ErrorShare::Length => 107,
+            ErrorShare::MemberThreshold => 108,
+            ErrorShare::MismatchedShares => 109,
+            ErrorShare::NoShares => 110,
+            ErrorShare::Padding => 111,
+            ErrorShare::SecretLength => 112,
+            ErrorShare::ShareCount => 113,
+            ErrorShare::Word(error) => error.code(),
+        }
+    }
+
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ErrorShare::Checksum => "Invalid share: the RS1024 checksum does not match.",
+            ErrorShare::Digest => "Recovered secret does not match its digest: the shares are damaged or come from different splits.",
+            ErrorShare::DuplicateIndex => "Two different shares have the same index.",
+            ErrorShare::Encryption => "Unable to run the master secret encryption.",
+            ErrorShare::GroupThreshold => "Group threshold must be between 1 and the group count, at most 16 groups.",
+            ErrorShare::IterationExponent => "Iteration exponent is above the maximum of 15.",
+            ErrorShare::Length => "Invalid share: unexpected number of words.",
+            ErrorShare::MemberThreshold => "Member threshold must be between 1 and the member count, at most 16 members, and 1 only for a single member.",
+            ErrorShare::MismatchedShares => "Shares do not belong to the same split: identifier, iteration exponent, thresholds, counts or lengths differ.",
+            ErrorShare::NoShares => "No shares provided.",
+            ErrorShare::Padding => "Invalid share: non-zero padding bits.",
+            ErrorShare::SecretLength => "Master secret must be an even number of bytes, from 16 to 32.",
+            ErrorShare::ShareCount => "Wrong number of groups, or of shares in a group, for the thresholds.",
+            ErrorShare::Word(error) => error.message(),
+        }
+    }
+}
+
+impl Display for ErrorShare {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ErrorShare::Word(error) => Display::fmt(error, f),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl core::error::Error for ErrorShare {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ErrorShare::Word(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WordlistAudit {
+    DuplicateWord { index: usize },
+    EmptyWord { index: usize },
+    NotSorted { index: usize },
+    SharedPrefix { index: usize },
+    WordTooLong { index: usize },
+    WrongLength { len: usize },
+}
+
+impl WordlistAudit {
+    /// Stable numeric code, see [`ErrorMnemonic::code`].
+    pub const fn code(&self) -> u16 {
+        match self {
+            WordlistAudit::DuplicateWord { .. } => 201,
+            WordlistAudit::EmptyWord { .. } => 202,
+            WordlistAudit::NotSorted { .. } => 203,
+            WordlistAudit::SharedPrefix { .. } => 204,
+            WordlistAudit::WordTooLong { .. } => 205,
+            WordlistAudit::WrongLength { .. } => 206,
+        }
+    }
+
+    /// Message without the entry index or count; `Display` has them.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            WordlistAudit::DuplicateWord { .. } => "Word list entry repeats the entry before it.",
+            WordlistAudit::EmptyWord { .. } => "Word list entry is empty or whitespace only.",
+            WordlistAudit::NotSorted { .. } => "Word list entry sorts before the entry before it.",
+            WordlistAudit::SharedPrefix { .. } => {
+                "Word list entry starts with the same characters as the entry before it."
+            }
+            WordlistAudit::WordTooLong { .. } => {
+                "Word list entry is too long for a raw blob record."
+            }
+            WordlistAudit::WrongLength { .. } => "Word list has the wrong number of entries.",
+        }
+    }
+}
+
+impl Display for WordlistAudit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            WordlistAudit::DuplicateWord { index } => write!(f, "Word list entry {index} repeats the entry before it."),
+            WordlistAudit::EmptyWord { index } => write!(f, "Word list entry {index} is empty or whitespace only."),
+            WordlistAudit::NotSorted { index } => write!(f, "Word list entry {index} sorts before the entry before it."),
+            WordlistAudit::SharedPrefix { index } => write!(f, "Word list entry {index} starts with the same {UNIQUE_PREFIX_LEN} characters as the entry before it."),
+            WordlistAudit::WordTooLong { index } => write!(f, "Word list entry {index} is too long for a raw blob record."),
+            WordlistAudit::WrongLength { len } => write!(f, "Word list has {len} entries instead of {TOTAL_WORDS}."),
+        }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ErrorMnemonic {}
+impl core::error::Error for WordlistAudit {}
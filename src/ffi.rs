@@ -0,0 +1,225 @@
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use std::boxed::Box;
+
+use core::ffi::{c_char, c_void, CStr};
+use core::slice;
+
+use zeroize::Zeroize;
+
+use crate::error::ErrorMnemonic;
+use crate::regular::InternalWordList;
+use crate::{AsWordList, WordSet};
+
+// C interface over the English list. Sets are opaque heap objects owned by
+// the caller from `mnemonic_wordset_new` until `mnemonic_wordset_free`.
+// `include/mnemonic_external.h` is generated from this file with cbindgen,
+// and the library to link it with is built with the crate type given on the
+// command line:
+//
+//     cbindgen --config cbindgen.toml --output include/mnemonic_external.h
+//     cargo rustc --release --lib --crate-type staticlib --features ffi
+//
+// The system libraries it needs on top are listed by adding
+// `-- --print native-static-libs` to the second command.
+
+/// Result of every fallible call. The values are stable.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MnemonicStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    NoWord = 3,
+    TooManyWords = 4,
+    WordsNumber = 5,
+    InvalidChecksum = 6,
+    BufferTooSmall = 7,
+    Other = 255,
+}
+
+impl From<ErrorMnemonic> for MnemonicStatus {
+    fn from(error: ErrorMnemonic) -> Self {
+        match error {
+            ErrorMnemonic::NoWord | ErrorMnemonic::NoWordAt { .. } => MnemonicStatus::NoWord,
+            ErrorMnemonic::TooManyWords => MnemonicStatus::TooManyWords,
+            ErrorMnemonic::WordsNumber => MnemonicStatus::WordsNumber,
+            ErrorMnemonic::InvalidChecksum => MnemonicStatus::InvalidChecksum,
+            _ => MnemonicStatus::Other,
+        }
+    }
+}
+
+/// Called for each match of [`mnemonic_words_by_prefix`] with the word index
+/// and the word, not NUL-terminated. Return `false` to stop.
+pub type MnemonicWordCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut c_void,
+        index: u16,
+        word: *const u8,
+        word_len: usize,
+    ) -> bool,
+>;
+
+// `input` is null or NUL-terminated.
+unsafe fn with_str<T>(
+    input: *const c_char,
+    f: impl FnOnce(&str) -> Result<T, MnemonicStatus>,
+) -> Result<T, MnemonicStatus> {
+    if input.is_null() {
+        return Err(MnemonicStatus::NullPointer);
+    }
+    let input = CStr::from_ptr(input);
+    f(input.to_str().map_err(|_| MnemonicStatus::InvalidUtf8)?)
+}
+
+fn status(result: Result<(), MnemonicStatus>) -> MnemonicStatus {
+    match result {
+        Ok(()) => MnemonicStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// New empty set, released with [`mnemonic_wordset_free`].
+#[no_mangle]
+pub extern "C" fn mnemonic_wordset_new() -> *mut WordSet {
+    Box::into_raw(Box::new(WordSet::new()))
+}
+
+/// Zeroizes and releases a set. Null is ignored.
+///
+/// # Safety
+///
+/// `set` is null or came from [`mnemonic_wordset_new`] and is not used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_free(set: *mut WordSet) {
+    if !set.is_null() {
+        drop(Box::from_raw(set));
+    }
+}
+
+/// # Safety
+///
+/// `set` is null or a live set; `word` is null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_add_word(
+    set: *mut WordSet,
+    word: *const c_char,
+) -> MnemonicStatus {
+    let Some(set) = set.as_mut() else {
+        return MnemonicStatus::NullPointer;
+    };
+    status(with_str(word, |word| {
+        set.add_word(word, &InternalWordList)
+            .map_err(MnemonicStatus::from)
+    }))
+}
+
+/// Removes the last word; `false` if the set was empty or null.
+///
+/// # Safety
+///
+/// `set` is null or a live set.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_remove_last_word(set: *mut WordSet) -> bool {
+    match set.as_mut() {
+        Some(set) => set.remove_last_word().is_some(),
+        None => false,
+    }
+}
+
+/// Number of words entered, 0 for null.
+///
+/// # Safety
+///
+/// `set` is null or a live set.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_len(set: *const WordSet) -> usize {
+    set.as_ref().map_or(0, WordSet::len)
+}
+
+/// Whether the word count is a valid length; the checksum is not looked at.
+///
+/// # Safety
+///
+/// `set` is null or a live set.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_is_finalizable(set: *const WordSet) -> bool {
+    set.as_ref().is_some_and(WordSet::is_finalizable)
+}
+
+/// Length and checksum check.
+///
+/// # Safety
+///
+/// `set` is null or a live set.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_validate(set: *const WordSet) -> MnemonicStatus {
+    match set.as_ref() {
+        Some(set) => status(set.validate().map_err(MnemonicStatus::from)),
+        None => MnemonicStatus::NullPointer,
+    }
+}
+
+/// Entropy into `out`, its length into `written`. `BufferTooSmall` below 32
+/// bytes for a 24 word set; nothing is written on error.
+///
+/// # Safety
+///
+/// `set` is null or a live set, `out` is null or valid for `out_len` bytes,
+/// `written` is null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_wordset_to_entropy(
+    set: *const WordSet,
+    out: *mut u8,
+    out_len: usize,
+    written: *mut usize,
+) -> MnemonicStatus {
+    let (Some(set), false, false) = (set.as_ref(), out.is_null(), written.is_null()) else {
+        return MnemonicStatus::NullPointer;
+    };
+    let mut entropy = match set.to_entropy() {
+        Ok(entropy) => entropy,
+        Err(error) => return error.into(),
+    };
+    let result = if entropy.len() > out_len {
+        MnemonicStatus::BufferTooSmall
+    } else {
+        slice::from_raw_parts_mut(out, entropy.len()).copy_from_slice(&entropy);
+        *written = entropy.len();
+        MnemonicStatus::Ok
+    };
+    entropy.zeroize();
+    result
+}
+
+/// Runs `callback` for each word starting with `prefix`, in list order.
+///
+/// # Safety
+///
+/// `prefix` is null or a NUL-terminated string; `callback` is safe to call
+/// with `user_data`.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_words_by_prefix(
+    prefix: *const c_char,
+    callback: MnemonicWordCallback,
+    user_data: *mut c_void,
+) -> MnemonicStatus {
+    let Some(callback) = callback else {
+        return MnemonicStatus::NullPointer;
+    };
+    status(with_str(prefix, |prefix| {
+        for element in InternalWordList.iter_words_by_prefix(prefix) {
+            let element = element?;
+            let word = element.word.as_bytes();
+            // SAFETY: per the caller's contract for `callback`
+            if !unsafe { callback(user_data, element.bits11.bits(), word.as_ptr(), word.len()) } {
+                break;
+            }
+        }
+        Ok(())
+    }))
+}
@@ -0,0 +1,252 @@
+use core::fmt::{Result as FmtResult, Write as FmtWrite};
+use core::str;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::ct::ct_eq_u16;
+use crate::error::ErrorMnemonic;
+use crate::{
+    checksum, sha256_first_byte, AsWordList, Bits11, MnemonicType, WordSet, BITS_IN_BYTE,
+    BITS_IN_U11, MAX_ENTROPY_LEN,
+};
+
+/// Word set stored inline, up to `N` words. Nothing here allocates, so
+/// phrases can be entered, checked and rendered on targets without a heap;
+/// `N = MAX_SEED_LEN` fits every BIP-39 length. The word list must not
+/// allocate either, which holds for the built-in ones.
+#[derive(Clone, Debug, ZeroizeOnDrop)]
+pub struct WordSetFixed<const N: usize> {
+    bits11_set: [Bits11; N],
+    len: usize,
+}
+
+impl<const N: usize> WordSetFixed<N> {
+    pub const fn new() -> Self {
+        Self {
+            bits11_set: [Bits11(0); N],
+            len: 0,
+        }
+    }
+
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, ErrorMnemonic> {
+        let mnemonic_type = MnemonicType::from_entropy_len(entropy.len())?;
+        if mnemonic_type.word_count() > N {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        let checksum_byte = sha256_first_byte(entropy);
+
+        let mut word_set = Self::new();
+        let mut acc: u32 = 0;
+        let mut acc_bits = 0;
+        for byte in entropy.iter().chain(core::iter::once(&checksum_byte)) {
+            acc = (acc << BITS_IN_BYTE) | *byte as u32;
+            acc_bits += BITS_IN_BYTE;
+            if acc_bits >= BITS_IN_U11 && word_set.len < mnemonic_type.word_count() {
+                acc_bits -= BITS_IN_U11;
+                word_set.bits11_set[word_set.len] = Bits11(((acc >> acc_bits) & 0x7FF) as u16);
+                word_set.len += 1;
+            }
+        }
+        acc.zeroize();
+        Ok(word_set)
+    }
+
+    pub fn as_slice(&self) -> &[Bits11] {
+        &self.bits11_set[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Constant-time comparison, as [`WordSet::ct_eq`].
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let mut equal = u8::from(self.len == other.len);
+        for (a, b) in self.as_slice().iter().zip(other.as_slice().iter()) {
+            equal &= ct_eq_u16(a.0, b.0);
+        }
+        equal == 1
+    }
+
+    pub fn add_word<L: AsWordList>(
+        &mut self,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let bits11 = wordlist.bits11_for_word(word)?;
+        if self.len == N {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.bits11_set[self.len] = bits11;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Undo the last [`add_word`](Self::add_word); the slot is zeroized.
+    pub fn remove_last_word(&mut self) -> Option<Bits11> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let bits11 = self.bits11_set[self.len];
+        self.bits11_set[self.len].zeroize();
+        Some(bits11)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits11_set.zeroize();
+        self.len = 0;
+    }
+
+    pub fn mnemonic_type(&self) -> Result<MnemonicType, ErrorMnemonic> {
+        MnemonicType::from_word_count(self.len)
+    }
+
+    /// Entropy written to the start of `out`, returns its length.
+    pub fn to_entropy_into(&self, out: &mut [u8; MAX_ENTROPY_LEN]) -> Result<usize, ErrorMnemonic> {
+        let (entropy_len, actual_checksum, expected_checksum) = self.unpack_entropy(out)?;
+        if ct_eq_u16(actual_checksum.into(), expected_checksum.into()) == 0 {
+            out.zeroize();
+            Err(ErrorMnemonic::InvalidChecksum)
+        } else {
+            Ok(entropy_len)
+        }
+    }
+
+    /// Length and checksum check, without handing out the entropy.
+    pub fn validate(&self) -> Result<(), ErrorMnemonic> {
+        let mut entropy = [0u8; MAX_ENTROPY_LEN];
+        let result = self.to_entropy_into(&mut entropy).map(|_| ());
+        entropy.zeroize();
+        result
+    }
+
+    // The checksum bits are the low bits of the last word, as they never
+    // exceed one word.
+    fn unpack_entropy(
+        &self,
+        entropy: &mut [u8; MAX_ENTROPY_LEN],
+    ) -> Result<(usize, u8, u8), ErrorMnemonic> {
+        let mnemonic_type = self.mnemonic_type()?;
+        let entropy_len = mnemonic_type.entropy_bytes();
+        let checksum_bits = mnemonic_type.checksum_bits();
+
+        let mut acc: u32 = 0;
+        let mut acc_bits = 0;
+        let mut position = 0;
+        for bits11 in self.as_slice().iter() {
+            acc = (acc << BITS_IN_U11) | bits11.bits() as u32;
+            acc_bits += BITS_IN_U11;
+            while acc_bits >= BITS_IN_BYTE && position < entropy_len {
+                acc_bits -= BITS_IN_BYTE;
+                entropy[position] = (acc >> acc_bits) as u8;
+                position += 1;
+            }
+        }
+        acc.zeroize();
+
+        let last = self.bits11_set[self.len - 1].bits();
+        let actual_checksum = (last & ((1 << checksum_bits) - 1)) as u8;
+        let expected_checksum = checksum(sha256_first_byte(&entropy[..entropy_len]), checksum_bits);
+        Ok((entropy_len, actual_checksum, expected_checksum))
+    }
+
+    /// Phrase written into a caller-owned sink, words joined by `separator`.
+    /// On error the sink may hold part of the phrase.
+    pub fn write_phrase<L: AsWordList, W: FmtWrite>(
+        &self,
+        wordlist: &L,
+        separator: char,
+        out: &mut W,
+    ) -> Result<(), ErrorMnemonic> {
+        for (position, bits11) in self.as_slice().iter().enumerate() {
+            if position != 0 {
+                out.write_char(separator)
+                    .map_err(|_| ErrorMnemonic::PhraseWrite)?;
+            }
+            let word = wordlist
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?;
+            out.write_str(word.as_ref())
+                .map_err(|_| ErrorMnemonic::PhraseWrite)?;
+        }
+        Ok(())
+    }
+
+    /// Phrase rendered into a stack buffer, joined by the list separator.
+    /// `PhraseWrite` if it does not fit; the buffer is zeroized then.
+    /// `MAX_SEED_LEN * (WORD_MAX_LEN + SEPARATOR_LEN)` bytes fit any English
+    /// phrase.
+    pub fn phrase_into<'a, L: AsWordList>(
+        &self,
+        wordlist: &L,
+        buffer: &'a mut [u8],
+    ) -> Result<&'a str, ErrorMnemonic> {
+        let mut writer = SliceWriter {
+            buffer: &mut buffer[..],
+            len: 0,
+        };
+        let written = self
+            .write_phrase(wordlist, wordlist.separator(), &mut writer)
+            .map(|()| writer.len);
+        match written {
+            Ok(len) => str::from_utf8(&buffer[..len]).map_err(|_| ErrorMnemonic::PhraseWrite),
+            Err(error) => {
+                buffer.zeroize();
+                Err(error)
+            }
+        }
+    }
+
+    pub fn to_word_set(&self) -> WordSet {
+        let mut word_set = WordSet::new();
+        word_set.bits11_set.extend_from_slice(self.as_slice());
+        word_set
+    }
+
+    pub fn from_word_set(word_set: &WordSet) -> Result<Self, ErrorMnemonic> {
+        if word_set.bits11_set.len() > N {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        let mut fixed = Self::new();
+        fixed.bits11_set[..word_set.bits11_set.len()].copy_from_slice(&word_set.bits11_set);
+        fixed.len = word_set.bits11_set.len();
+        Ok(fixed)
+    }
+}
+
+impl<const N: usize> Default for WordSetFixed<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Constant-time, see `WordSetFixed::ct_eq`.
+impl<const N: usize> PartialEq for WordSetFixed<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<const N: usize> Eq for WordSetFixed<N> {}
+
+struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl FmtWrite for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let end = self.len + s.len();
+        if end > self.buffer.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -0,0 +1,541 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::error::ErrorMnemonic;
+use crate::raw::{RawWord, RAW_MAX_RECORD_LEN};
+use crate::{AsWordList, Bits11, WordListElement, TOTAL_WORDS};
+
+#[cfg(feature = "chinese-simplified")]
+use crate::wordlist::WORDLIST_CHINESE_SIMPLIFIED;
+#[cfg(feature = "chinese-traditional")]
+use crate::wordlist::WORDLIST_CHINESE_TRADITIONAL;
+#[cfg(feature = "czech")]
+use crate::wordlist::WORDLIST_CZECH;
+#[cfg(feature = "english-wordlist")]
+use crate::wordlist::WORDLIST_ENGLISH;
+#[cfg(feature = "french")]
+use crate::wordlist::WORDLIST_FRENCH;
+#[cfg(feature = "italian")]
+use crate::wordlist::WORDLIST_ITALIAN;
+#[cfg(feature = "japanese")]
+use crate::wordlist::WORDLIST_JAPANESE;
+#[cfg(feature = "korean")]
+use crate::wordlist::WORDLIST_KOREAN;
+#[cfg(feature = "portuguese")]
+use crate::wordlist::WORDLIST_PORTUGUESE;
+#[cfg(feature = "spanish")]
+use crate::wordlist::WORDLIST_SPANISH;
+
+// Compressed blob layout, for word lists kept in internal flash:
+//
+// | offset  | length            | content                                   |
+// |---------|-------------------|-------------------------------------------|
+// | 0       | 4                 | `FRONT_CODED_MAGIC`                       |
+// | 4       | 1                 | `FRONT_CODED_FORMAT_VERSION`              |
+// | 5       | 1                 | length width `l`, bits, 1 to 6            |
+// | 6       | 1                 | symbol width `s`, bits, 1 to 8            |
+// | 7       | 1                 | alphabet size `a`, at least 1             |
+// | 8       | 1                 | flags, `FRONT_CODED_SORTED` or none       |
+// | 9       | `a`               | bytes occurring in the words, ascending   |
+// | 9 + `a` | 2 * 128           | bucket starts, u16 little-endian          |
+// | ...     |                   | buckets                                   |
+//
+// Buckets hold `FRONT_CODED_BUCKET_LEN` words each and start on a byte, at
+// their start offset counted from the end of the start table. Each word is
+// `l` bits for the number of bytes shared with the word before it, zero for
+// the first of a bucket, `l` bits for the number of bytes that follow, and
+// `s` bits per following byte, its position in the alphabet. Bits are read
+// from the top of each byte down.
+//
+// `get_word` decodes the one bucket holding the word. On lists sorted by
+// byte value, flagged `FRONT_CODED_SORTED`, word and prefix searches find the
+// first bucket that can match by binary search over the first words of the
+// buckets, and decode from there only as far as matches can go; on the others
+// they decode the whole list. The English list takes about 6.5 KB this way,
+// against 11 KB of text and a table of 2048 string references for
+// `WORDLIST_ENGLISH`.
+
+pub const FRONT_CODED_MAGIC: [u8; 4] = *b"MNFC";
+pub const FRONT_CODED_FORMAT_VERSION: u8 = 1;
+pub const FRONT_CODED_HEADER_LEN: usize = 9;
+pub const FRONT_CODED_BUCKET_LEN: usize = 16;
+
+/// Header flag of a list sorted by byte value.
+pub const FRONT_CODED_SORTED: u8 = 1;
+
+const BUCKETS: usize = TOTAL_WORDS / FRONT_CODED_BUCKET_LEN;
+
+// widths and alphabet of a list, checked for the format
+struct Layout {
+    present: [bool; 256],
+    alphabet_len: usize,
+    length_bits: usize,
+    symbol_bits: usize,
+    sorted: bool,
+}
+
+const fn bit_width(value: usize) -> usize {
+    (usize::BITS - value.leading_zeros()) as usize
+}
+
+const fn shared_len(word: &[u8], previous: &[u8]) -> usize {
+    let mut len = 0;
+    while len < word.len() && len < previous.len() && word[len] == previous[len] {
+        len += 1;
+    }
+    len
+}
+
+const fn precedes(word: &[u8], next: &[u8]) -> bool {
+    let shared = shared_len(word, next);
+    if shared < word.len() && shared < next.len() {
+        word[shared] < next[shared]
+    } else {
+        word.len() < next.len()
+    }
+}
+
+const fn layout(words: &[&str; TOTAL_WORDS]) -> Layout {
+    let mut present = [false; 256];
+    let mut longest = 0;
+    let mut sorted = true;
+    let mut i = 0;
+    while i < TOTAL_WORDS {
+        let word = words[i].as_bytes();
+        if i > 0 && !precedes(words[i - 1].as_bytes(), word) {
+            sorted = false;
+        }
+        assert!(
+            !word.is_empty(),
+            "front-coded word lists have no empty words"
+        );
+        assert!(
+            word.len() <= RAW_MAX_RECORD_LEN,
+            "front-coded words are at most RAW_MAX_RECORD_LEN bytes"
+        );
+        if word.len() > longest {
+            longest = word.len();
+        }
+        let mut j = 0;
+        while j < word.len() {
+            present[word[j] as usize] = true;
+            j += 1;
+        }
+        i += 1;
+    }
+    let mut alphabet_len = 0;
+    let mut byte = 0;
+    while byte < 256 {
+        if present[byte] {
+            alphabet_len += 1;
+        }
+        byte += 1;
+    }
+    let symbol_bits = bit_width(alphabet_len - 1);
+    Layout {
+        present,
+        alphabet_len,
+        length_bits: bit_width(longest),
+        symbol_bits: if symbol_bits == 0 { 1 } else { symbol_bits },
+        sorted,
+    }
+}
+
+const fn bucket_len(words: &[&str; TOTAL_WORDS], bucket: usize, layout: &Layout) -> usize {
+    let mut bits = 0;
+    let first = bucket * FRONT_CODED_BUCKET_LEN;
+    let mut i = first;
+    while i < first + FRONT_CODED_BUCKET_LEN {
+        let word = words[i].as_bytes();
+        let shared = if i == first {
+            0
+        } else {
+            shared_len(word, words[i - 1].as_bytes())
+        };
+        bits += 2 * layout.length_bits + (word.len() - shared) * layout.symbol_bits;
+        i += 1;
+    }
+    bits.div_ceil(8)
+}
+
+/// Length of the blob [`front_coded_blob`] makes of `words`.
+pub const fn front_coded_len(words: &[&str; TOTAL_WORDS]) -> usize {
+    let layout = layout(words);
+    let mut len = FRONT_CODED_HEADER_LEN + layout.alphabet_len + 2 * BUCKETS;
+    let mut bucket = 0;
+    while bucket < BUCKETS {
+        len += bucket_len(words, bucket, &layout);
+        bucket += 1;
+    }
+    len
+}
+
+const fn write_bits(blob: &mut [u8], position: &mut usize, value: usize, width: usize) {
+    let mut bit = width;
+    while bit > 0 {
+        bit -= 1;
+        if (value >> bit) & 1 == 1 {
+            blob[*position / 8] |= 0x80 >> (*position % 8);
+        }
+        *position += 1;
+    }
+}
+
+/// Front-coded blob of `words`, in the layout [`FrontCodedWordList`] reads.
+/// `N` must be [`front_coded_len`] of the same words.
+///
+/// Meant to run at compile time, so that only the blob ends up in flash:
+///
+/// ```ignore
+/// static BLOB: [u8; front_coded_len(&WORDS)] = front_coded_blob(&WORDS);
+/// ```
+///
+/// Lists the format cannot hold, with an empty word, a word over
+/// [`RAW_MAX_RECORD_LEN`] bytes or more than 64 KB of buckets, stop the
+/// build.
+pub const fn front_coded_blob<const N: usize>(words: &[&str; TOTAL_WORDS]) -> [u8; N] {
+    assert!(
+        N == front_coded_len(words),
+        "N is not front_coded_len(words)"
+    );
+    let layout = layout(words);
+    let mut blob = [0u8; N];
+
+    let mut i = 0;
+    while i < FRONT_CODED_MAGIC.len() {
+        blob[i] = FRONT_CODED_MAGIC[i];
+        i += 1;
+    }
+    blob[4] = FRONT_CODED_FORMAT_VERSION;
+    blob[5] = layout.length_bits as u8;
+    blob[6] = layout.symbol_bits as u8;
+    blob[7] = layout.alphabet_len as u8;
+    blob[8] = if layout.sorted { FRONT_CODED_SORTED } else { 0 };
+
+    // alphabet, and the position of each byte in it
+    let mut symbols = [0u8; 256];
+    let mut alphabet_len = 0;
+    let mut byte = 0;
+    while byte < 256 {
+        if layout.present[byte] {
+            blob[FRONT_CODED_HEADER_LEN + alphabet_len] = byte as u8;
+            symbols[byte] = alphabet_len as u8;
+            alphabet_len += 1;
+        }
+        byte += 1;
+    }
+
+    let starts = FRONT_CODED_HEADER_LEN + layout.alphabet_len;
+    let data = starts + 2 * BUCKETS;
+    let mut start = 0;
+    let mut bucket = 0;
+    while bucket < BUCKETS {
+        assert!(
+            start <= u16::MAX as usize,
+            "front-coded buckets exceed 64 KB"
+        );
+        blob[starts + 2 * bucket] = start as u8;
+        blob[starts + 2 * bucket + 1] = (start >> 8) as u8;
+
+        let mut position = (data + start) * 8;
+        let first = bucket * FRONT_CODED_BUCKET_LEN;
+        let mut i = first;
+        while i < first + FRONT_CODED_BUCKET_LEN {
+            let word = words[i].as_bytes();
+            let shared = if i == first {
+                0
+            } else {
+                shared_len(word, words[i - 1].as_bytes())
+            };
+            write_bits(&mut blob, &mut position, shared, layout.length_bits);
+            write_bits(
+                &mut blob,
+                &mut position,
+                word.len() - shared,
+                layout.length_bits,
+            );
+            let mut j = shared;
+            while j < word.len() {
+                let symbol = symbols[word[j] as usize] as usize;
+                write_bits(&mut blob, &mut position, symbol, layout.symbol_bits);
+                j += 1;
+            }
+            i += 1;
+        }
+        start += bucket_len(words, bucket, &layout);
+        bucket += 1;
+    }
+    blob
+}
+
+// every language feature brings `english-wordlist` along
+#[cfg(feature = "english-wordlist")]
+macro_rules! front_coded_list {
+    ($name:ident, $list:ident) => {
+        #[doc = concat!("[`", stringify!($list), "`](crate::wordlist::", stringify!($list), ")")]
+        /// front-coded at compile time, for [`FrontCodedWordList::new`].
+        pub static $name: [u8; front_coded_len(&$list)] = front_coded_blob(&$list);
+    };
+}
+
+#[cfg(feature = "chinese-simplified")]
+front_coded_list!(
+    WORDLIST_CHINESE_SIMPLIFIED_FRONT_CODED,
+    WORDLIST_CHINESE_SIMPLIFIED
+);
+#[cfg(feature = "chinese-traditional")]
+front_coded_list!(
+    WORDLIST_CHINESE_TRADITIONAL_FRONT_CODED,
+    WORDLIST_CHINESE_TRADITIONAL
+);
+#[cfg(feature = "czech")]
+front_coded_list!(WORDLIST_CZECH_FRONT_CODED, WORDLIST_CZECH);
+#[cfg(feature = "english-wordlist")]
+front_coded_list!(WORDLIST_ENGLISH_FRONT_CODED, WORDLIST_ENGLISH);
+#[cfg(feature = "french")]
+front_coded_list!(WORDLIST_FRENCH_FRONT_CODED, WORDLIST_FRENCH);
+#[cfg(feature = "italian")]
+front_coded_list!(WORDLIST_ITALIAN_FRONT_CODED, WORDLIST_ITALIAN);
+#[cfg(feature = "japanese")]
+front_coded_list!(WORDLIST_JAPANESE_FRONT_CODED, WORDLIST_JAPANESE);
+#[cfg(feature = "korean")]
+front_coded_list!(WORDLIST_KOREAN_FRONT_CODED, WORDLIST_KOREAN);
+#[cfg(feature = "portuguese")]
+front_coded_list!(WORDLIST_PORTUGUESE_FRONT_CODED, WORDLIST_PORTUGUESE);
+#[cfg(feature = "spanish")]
+front_coded_list!(WORDLIST_SPANISH_FRONT_CODED, WORDLIST_SPANISH);
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, width: usize) -> Result<usize, ErrorMnemonic> {
+        let mut value = 0;
+        for _ in 0..width {
+            let byte = self
+                .bytes
+                .get(self.position / 8)
+                .ok_or(ErrorMnemonic::DamagedWord)?;
+            value = (value << 1) | ((byte >> (7 - self.position % 8)) & 1) as usize;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+}
+
+/// Word list over a blob made by [`front_coded_blob`], e.g. one of the
+/// `WORDLIST_*_FRONT_CODED` statics.
+///
+/// `get_word` decodes the bucket of the word, up to
+/// `FRONT_CODED_BUCKET_LEN` words. Word and prefix searches decode a few
+/// buckets on sorted lists and the whole list on the others. Blob damage
+/// found on the way is `DamagedWord`.
+pub struct FrontCodedWordList<'a> {
+    alphabet: &'a [u8],
+    starts: &'a [u8],
+    buckets: &'a [u8],
+    length_bits: usize,
+    symbol_bits: usize,
+    sorted: bool,
+}
+
+impl<'a> FrontCodedWordList<'a> {
+    /// Checks the blob header; `WordListFormat` if it does not match.
+    pub fn new(blob: &'a [u8]) -> Result<Self, ErrorMnemonic> {
+        let header = blob
+            .get(..FRONT_CODED_HEADER_LEN)
+            .ok_or(ErrorMnemonic::WordListFormat)?;
+        let length_bits = header[5] as usize;
+        let symbol_bits = header[6] as usize;
+        let alphabet_len = header[7] as usize;
+        let flags = header[8];
+        if header[..4] != FRONT_CODED_MAGIC
+            || header[4] != FRONT_CODED_FORMAT_VERSION
+            || !(1..=bit_width(RAW_MAX_RECORD_LEN)).contains(&length_bits)
+            || !(1..=8).contains(&symbol_bits)
+            || alphabet_len == 0
+            || flags & !FRONT_CODED_SORTED != 0
+        {
+            return Err(ErrorMnemonic::WordListFormat);
+        }
+        let starts = FRONT_CODED_HEADER_LEN + alphabet_len;
+        let buckets = starts + 2 * BUCKETS;
+        if blob.len() < buckets {
+            return Err(ErrorMnemonic::WordListFormat);
+        }
+        Ok(Self {
+            alphabet: &blob[FRONT_CODED_HEADER_LEN..starts],
+            starts: &blob[starts..buckets],
+            buckets: &blob[buckets..],
+            length_bits,
+            symbol_bits,
+            sorted: flags & FRONT_CODED_SORTED != 0,
+        })
+    }
+
+    /// Whether the words are sorted by byte value, which makes word and
+    /// prefix searches decode only the buckets that can match.
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    // First bucket a word starting with `prefix` can be in: the last one
+    // whose first word sorts before `prefix`, if any. Sorted lists only.
+    fn first_bucket(&self, prefix: &[u8]) -> Result<usize, ErrorMnemonic> {
+        let mut word = RawWord::new();
+        let (mut low, mut high) = (0, BUCKETS);
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            self.walk_bucket(middle, &mut word, |_, _| true)?;
+            if precedes(word.as_ref().as_bytes(), prefix) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(low)
+    }
+
+    // Bucket a search for words starting with `prefix` decodes from.
+    fn search_from(&self, prefix: &str) -> Result<usize, ErrorMnemonic> {
+        if self.sorted {
+            self.first_bucket(prefix.as_bytes())
+        } else {
+            Ok(0)
+        }
+    }
+
+    // Whether no word from `word` on can start with `prefix`.
+    fn past(&self, word: &RawWord, prefix: &str) -> bool {
+        self.sorted
+            && !word.as_ref().starts_with(prefix)
+            && precedes(prefix.as_bytes(), word.as_ref().as_bytes())
+    }
+
+    // Decodes the words of `bucket` in turn into `word`, handing each to
+    // `visit` with its index, until `visit` returns `true`. Whether it did.
+    fn walk_bucket<F>(
+        &self,
+        bucket: usize,
+        word: &mut RawWord,
+        mut visit: F,
+    ) -> Result<bool, ErrorMnemonic>
+    where
+        F: FnMut(usize, &RawWord) -> bool,
+    {
+        let start = u16::from_le_bytes([self.starts[2 * bucket], self.starts[2 * bucket + 1]]);
+        let mut reader = BitReader {
+            bytes: self
+                .buckets
+                .get(start as usize..)
+                .ok_or(ErrorMnemonic::DamagedWord)?,
+            position: 0,
+        };
+        let mut len = 0;
+        for index in 0..FRONT_CODED_BUCKET_LEN {
+            let shared = reader.read(self.length_bits)?;
+            let extra = reader.read(self.length_bits)?;
+            if (index == 0 && shared != 0) || shared > len || shared + extra > RAW_MAX_RECORD_LEN {
+                return Err(ErrorMnemonic::DamagedWord);
+            }
+            let buffer = word.buffer(RAW_MAX_RECORD_LEN);
+            for byte in buffer[shared..shared + extra].iter_mut() {
+                *byte = *self
+                    .alphabet
+                    .get(reader.read(self.symbol_bits)?)
+                    .ok_or(ErrorMnemonic::DamagedWord)?;
+            }
+            len = shared + extra;
+            word.settle(len)?;
+            if visit(index, word) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl Debug for FrontCodedWordList<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "FrontCodedWordList {{ alphabet_len: {}, buckets_len: {} }}",
+            self.alphabet.len(),
+            self.buckets.len()
+        )
+    }
+}
+
+impl AsWordList for FrontCodedWordList<'_> {
+    type Word = RawWord;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        let index = bits.bits() as usize;
+        let mut word = RawWord::new();
+        let found =
+            self.walk_bucket(index / FRONT_CODED_BUCKET_LEN, &mut word, |in_bucket, _| {
+                in_bucket == index % FRONT_CODED_BUCKET_LEN
+            })?;
+        if found {
+            Ok(word)
+        } else {
+            Err(ErrorMnemonic::DamagedWord)
+        }
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        let mut out: Vec<WordListElement<Self>> = Vec::new();
+        let mut word = RawWord::new();
+        for bucket in self.search_from(prefix)?..BUCKETS {
+            let past = self.walk_bucket(bucket, &mut word, |in_bucket, word| {
+                if word.as_ref().starts_with(prefix) {
+                    let bits = bucket * FRONT_CODED_BUCKET_LEN + in_bucket;
+                    out.push(WordListElement {
+                        word: word.clone(),
+                        bits11: Bits11(bits as u16),
+                    });
+                }
+                self.past(word, prefix)
+            })?;
+            if past {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        if word.len() > RAW_MAX_RECORD_LEN {
+            return Err(ErrorMnemonic::NoWord);
+        }
+        let mut record = RawWord::new();
+        for bucket in self.search_from(word)?..BUCKETS {
+            let mut found = None;
+            if self.walk_bucket(bucket, &mut record, |in_bucket, record| {
+                if record.as_ref() == word {
+                    found = Some(in_bucket);
+                }
+                found.is_some() || self.past(record, word)
+            })? {
+                return match found {
+                    Some(in_bucket) => {
+                        let bits = bucket * FRONT_CODED_BUCKET_LEN + in_bucket;
+                        Ok(Bits11(bits as u16))
+                    }
+                    None => Err(ErrorMnemonic::NoWord),
+                };
+            }
+        }
+        Err(ErrorMnemonic::NoWord)
+    }
+}
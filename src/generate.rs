@@ -0,0 +1,31 @@
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+#[cfg(feature = "os-rng")]
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "os-rng")]
+use crate::error::ErrorMnemonic;
+use crate::{MnemonicType, WordSet, MAX_ENTROPY_LEN};
+
+impl WordSet {
+    /// Fresh mnemonic of the requested length from a cryptographic RNG, such
+    /// as a hardware TRNG driver. The entropy buffer is zeroized.
+    pub fn generate<R: CryptoRngCore + ?Sized>(mnemonic_type: MnemonicType, rng: &mut R) -> Self {
+        let entropy_len = mnemonic_type.entropy_bytes();
+        let mut entropy = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+        rng.fill_bytes(&mut entropy[..entropy_len]);
+        Self::from_checked_entropy(&entropy[..entropy_len])
+    }
+
+    /// Fresh mnemonic from the operating system RNG, through `getrandom`.
+    #[cfg(feature = "os-rng")]
+    pub fn generate_os(mnemonic_type: MnemonicType) -> Result<Self, ErrorMnemonic> {
+        let entropy_len = mnemonic_type.entropy_bytes();
+        let mut entropy = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+        OsRng
+            .try_fill_bytes(&mut entropy[..entropy_len])
+            .map_err(|_| ErrorMnemonic::RandomSource)?;
+        Ok(Self::from_checked_entropy(&entropy[..entropy_len]))
+    }
+}
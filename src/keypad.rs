@@ -0,0 +1,57 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, WordListElement};
+
+// Word entry on a phone-style keypad, one key press per letter as in T9:
+// `2` is abc, `3` def, `4` ghi, `5` jkl, `6` mno, `7` pqrs, `8` tuv and `9`
+// wxyz. Only ASCII letters have a key, so lists with accented or non-Latin
+// words only match on their ASCII words.
+
+/// Key of `letter` on a phone keypad, `2` to `9`, either case.
+pub fn keypad_digit(letter: char) -> Option<u8> {
+    let digit = match letter.to_ascii_lowercase() {
+        'a'..='c' => 2,
+        'd'..='f' => 3,
+        'g'..='i' => 4,
+        'j'..='l' => 5,
+        'm'..='o' => 6,
+        'p'..='s' => 7,
+        't'..='v' => 8,
+        'w'..='z' => 9,
+        _ => return None,
+    };
+    Some(digit)
+}
+
+/// Words whose first letters are typed by `digits`, a run of `2` to `9`.
+///
+/// Words typed in full come first, then longer ones by length; ties keep
+/// list order. With the English list four key presses usually leave a
+/// handful of candidates. `KeypadDigits` for anything but `2` to `9`.
+pub fn keypad_candidates<L: AsWordList>(
+    digits: &str,
+    wordlist: &L,
+) -> Result<Vec<WordListElement<L>>, ErrorMnemonic> {
+    if !digits.bytes().all(|digit| (b'2'..=b'9').contains(&digit)) {
+        return Err(ErrorMnemonic::KeypadDigits);
+    }
+    let mut out: Vec<WordListElement<L>> = Vec::new();
+    for element in wordlist.iter_words_by_prefix("") {
+        let element = element?;
+        let mut letters = element.word.as_ref().chars();
+        let typed = digits
+            .bytes()
+            .all(|digit| letters.next().and_then(keypad_digit) == Some(digit - b'0'));
+        if typed {
+            out.push(element);
+        }
+    }
+    // stable, so list order is kept within a length
+    out.sort_by_key(|element| element.word.as_ref().chars().count());
+    Ok(out)
+}
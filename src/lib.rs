@@ -9,25 +9,119 @@ extern crate alloc;
 extern crate std;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 
 #[cfg(feature = "std")]
 use std::{string::String, vec::Vec};
 
+#[cfg(feature = "nfkd")]
+use core::cell::Cell;
+use core::fmt::{Debug, Formatter, Result as FmtResult, Write as FmtWrite};
+use core::hash::{Hash, Hasher};
 use sha2::{Digest, Sha256};
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[cfg(feature = "aezeed")]
+pub mod aezeed;
+
+#[cfg(feature = "async-wordlist")]
+pub mod async_wordlist;
+
+#[cfg(feature = "bip32")]
+pub mod bip32;
+
+#[cfg(feature = "bip85")]
+pub mod bip85;
+
+pub mod cache;
+
+#[cfg(feature = "cardano")]
+pub mod cardano;
+
+pub mod confirm;
+
+mod ct;
+
+pub mod custom;
+
+mod distance;
+
+#[cfg(feature = "electrum")]
+pub mod electrum;
+
+pub mod entropy;
 
 pub mod error;
 
-#[cfg(feature = "sufficient-memory")]
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+
+pub mod fixed;
+
+#[cfg(feature = "front-coded")]
+pub mod front_coded;
+
+pub mod keypad;
+
+pub mod raw;
+
+pub mod recover;
+
+#[cfg(feature = "english-wordlist")]
 pub mod regular;
 
+#[cfg(feature = "generate")]
+pub mod generate;
+
+#[cfg(any(feature = "seed", feature = "cardano"))]
+mod pbkdf2;
+
+#[cfg(feature = "monero")]
+pub mod monero;
+
+#[cfg(feature = "seed")]
+pub mod seed;
+
+pub mod seedqr;
+
+#[cfg(any(feature = "embedded-storage", feature = "embedded-storage-async"))]
+pub mod storage;
+
+#[cfg(any(feature = "testing", test))]
+pub mod testing;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+#[cfg(feature = "slip39")]
+pub mod slip39;
+
+#[cfg(feature = "substrate")]
+pub mod substrate;
+
 #[cfg(test)]
 mod tests;
 
-#[cfg(any(feature = "sufficient-memory", test))]
+pub mod verify;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub mod weak;
+
+#[cfg(any(feature = "english-wordlist", test))]
 pub mod wordlist;
 
+pub mod xor;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("mnemonic_external");
+
+use crate::ct::{ct_eq_bytes, ct_eq_u16};
+use crate::distance::bounded_edit_distance;
 use crate::error::ErrorMnemonic;
 
 pub const TOTAL_WORDS: usize = 2048;
@@ -35,8 +129,13 @@ pub const WORD_MAX_LEN: usize = 8;
 pub const SEPARATOR_LEN: usize = 1;
 
 pub const MAX_SEED_LEN: usize = 24;
+/// Entropy of the longest, 24 word, mnemonic in bytes.
+pub const MAX_ENTROPY_LEN: usize = 32;
 
-#[derive(Clone, Copy, Debug, Zeroize)]
+pub const EXPORT_HEADER: &str = "number,word";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Bits11(u16);
 
 impl Bits11 {
@@ -52,23 +151,332 @@ impl Bits11 {
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct WordListElement<L: AsWordList + ?Sized> {
+/// Index into a word list of `TOTAL` words: [`Bits11`] for the BIP-39
+/// lists, or e.g. `MoneroWordIndex` for the 1626-word Monero lists.
+pub trait WordIndex: Copy + Debug + Eq + Ord + Hash + Zeroize {
+    const TOTAL: usize;
+    fn index(self) -> u16;
+    /// `InvalidWordNumber` unless `i < TOTAL`.
+    fn from_index(i: u16) -> Result<Self, ErrorMnemonic>;
+}
+
+impl WordIndex for Bits11 {
+    const TOTAL: usize = TOTAL_WORDS;
+    fn index(self) -> u16 {
+        self.0
+    }
+    fn from_index(i: u16) -> Result<Self, ErrorMnemonic> {
+        Bits11::from(i)
+    }
+}
+
+/// Word and its index. The impls depend on the word type only, never on the
+/// list: elements of a `String` list can be zeroized, elements of a
+/// `&'static str` list can not and need not be.
+pub struct WordListElement<L: AsWordList<I> + ?Sized, I: WordIndex = Bits11> {
     pub word: L::Word,
-    pub bits11: Bits11,
+    pub bits11: I,
 }
 
-pub trait AsWordList {
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Clone for WordListElement<L, I>
+where
+    L::Word: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            word: self.word.clone(),
+            bits11: self.bits11,
+        }
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Copy for WordListElement<L, I> where L::Word: Copy {}
+
+/// With the `redact-debug` feature the word and index are hidden, so that
+/// logging a suggestion list does not record what was typed.
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Debug for WordListElement<L, I>
+where
+    L::Word: Debug,
+{
+    #[cfg(not(feature = "redact-debug"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WordListElement")
+            .field("word", &self.word)
+            .field("bits11", &self.bits11)
+            .finish()
+    }
+
+    #[cfg(feature = "redact-debug")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "WordListElement(..)")
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> PartialEq for WordListElement<L, I>
+where
+    L::Word: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bits11 == other.bits11 && self.word == other.word
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Eq for WordListElement<L, I> where L::Word: Eq {}
+
+/// List order first, which within one list is also word order for sorted
+/// lists.
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> PartialOrd for WordListElement<L, I>
+where
+    L::Word: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Ord for WordListElement<L, I>
+where
+    L::Word: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.bits11
+            .cmp(&other.bits11)
+            .then_with(|| self.word.cmp(&other.word))
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Hash for WordListElement<L, I>
+where
+    L::Word: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.word.hash(state);
+        self.bits11.hash(state);
+    }
+}
+
+impl<L: AsWordList<I> + ?Sized, I: WordIndex> Zeroize for WordListElement<L, I>
+where
+    L::Word: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.word.zeroize();
+        self.bits11.zeroize();
+    }
+}
+
+/// Source of the words of a list, indexed by `I`: the 2048 BIP-39 words by
+/// [`Bits11`] unless stated otherwise. The `bits11` in method and field names
+/// stands for whichever [`WordIndex`] the list uses.
+///
+/// The trait does not require the words to be sorted: default methods and
+/// callers only rely on `get_word` and `bits11_for_word` being consistent with
+/// each other. Implementors that know their list is sorted (as
+/// `InternalWordList` does for English) may use that for faster lookups.
+pub trait AsWordList<I: WordIndex = Bits11> {
     type Word: AsRef<str>;
-    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic>;
+    /// Must not panic, whatever the number of entries actually available:
+    /// a missing or unreadable word is reported as `DamagedWord`.
+    fn get_word(&self, bits: I) -> Result<Self::Word, ErrorMnemonic>;
     fn get_words_by_prefix(
         &self,
         prefix: &str,
-    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic>;
-    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic>;
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic>;
+    fn bits11_for_word(&self, word: &str) -> Result<I, ErrorMnemonic>;
+    /// As `bits11_for_word`, with surrounding whitespace trimmed and the
+    /// input lowercased first. Only suitable for all-lowercase lists, such
+    /// as the official ones.
+    fn bits11_for_word_lenient(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        self.bits11_for_word(&Zeroizing::new(word.trim().to_lowercase()))
+    }
+    /// As `bits11_for_word`, without the timing giving away the index: every
+    /// entry is read and compared in constant time, and the match is picked
+    /// out with a mask rather than a branch. A search stopping at the match
+    /// shows how far into the list it got, and so the word, to anyone timing
+    /// it or watching reads of an external memory.
+    ///
+    /// The price is reading all the words per lookup instead of a binary
+    /// search or an early exit. Only the input length and the length of each
+    /// entry, both public in practice, still affect the timing. A read error
+    /// stops the scan with `DamagedWord`.
+    ///
+    /// Matches are counted rather than flagged, so a word entered twice in a
+    /// custom list gives `NoWord` instead of its merged indices.
+    fn bits11_for_word_ct(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        let mut matches = 0u16;
+        let mut index = 0u16;
+        for bits_u16 in 0..I::TOTAL as u16 {
+            let candidate = self.get_word(I::from_index(bits_u16)?)?;
+            let equal = ct_eq_bytes(candidate.as_ref().as_bytes(), word.as_bytes());
+            matches += equal as u16;
+            index |= bits_u16 & 0u16.wrapping_sub(equal as u16);
+        }
+        if ct_eq_u16(matches, 1) == 1 {
+            I::from_index(index)
+        } else {
+            Err(ErrorMnemonic::NoWord)
+        }
+    }
+    /// Character joining the words of a phrase.
+    fn separator(&self) -> char {
+        ' '
+    }
+    /// Whether any entry has accents, i.e. combining marks once
+    /// NFKD-normalized. Only lists with accents are scanned word by word by
+    /// `ResolveMode::IgnoreAccents`; on the others the input, accents
+    /// dropped, is looked up as any other word. `true` unless known
+    /// otherwise, which is always correct, only slower.
+    fn has_accents(&self) -> bool {
+        true
+    }
+    fn get_words_by_prefix_range(
+        &self,
+        prefix: &str,
+        skip: usize,
+        take: usize,
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic> {
+        Ok(self
+            .get_words_by_prefix(prefix)?
+            .into_iter()
+            .skip(skip)
+            .take(take)
+            .collect())
+    }
+    fn count_by_prefix(&self, prefix: &str) -> Result<usize, ErrorMnemonic> {
+        Ok(self.get_words_by_prefix(prefix)?.len())
+    }
+    /// Words starting with `prefix` in list order, read one at a time, so a
+    /// UI showing a few suggestions only reads those and nothing is
+    /// collected. The default reads through the whole list.
+    fn iter_words_by_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = Result<WordListElement<Self, I>, ErrorMnemonic>> + 'a
+    where
+        Self: Sized,
+    {
+        (0..I::TOTAL as u16).filter_map(move |bits_u16| {
+            let bits11 = match I::from_index(bits_u16) {
+                Ok(bits11) => bits11,
+                Err(error) => return Some(Err(error)),
+            };
+            match self.get_word(bits11) {
+                Ok(word) if word.as_ref().starts_with(prefix) => {
+                    Some(Ok(WordListElement { word, bits11 }))
+                }
+                Ok(_) => None,
+                Err(error) => Some(Err(error)),
+            }
+        })
+    }
+    /// At most `max` words starting with `prefix`, and whether more exist.
+    fn get_words_by_prefix_limited(
+        &self,
+        prefix: &str,
+        max: usize,
+    ) -> Result<(Vec<WordListElement<Self, I>>, bool), ErrorMnemonic> {
+        let mut words = self.get_words_by_prefix(prefix)?;
+        let more = words.len() > max;
+        words.truncate(max);
+        Ok((words, more))
+    }
+    /// `word` itself if it is in the list, or else the only word starting
+    /// with it: BIP-39 lists keep the first four letters of every word
+    /// unique, and phrases are often backed up that way. `AmbiguousPrefix` if
+    /// several words start with it.
+    fn bits11_for_unique_prefix(&self, word: &str) -> Result<I, ErrorMnemonic>
+    where
+        Self: Sized,
+    {
+        match self.bits11_for_word(word) {
+            Err(ErrorMnemonic::NoWord) => {}
+            found => return found,
+        }
+        let mut matches = self.iter_words_by_prefix(word);
+        let first = matches.next().ok_or(ErrorMnemonic::NoWord)??;
+        match matches.next().transpose()? {
+            Some(_) => Err(ErrorMnemonic::AmbiguousPrefix),
+            None => Ok(first.bits11),
+        }
+    }
+    /// Words within `max_distance` edits of `word`, closest first, at most
+    /// `max_results` of them. For "did you mean" hints on `NoWord`. Edits are
+    /// insertions, deletions, substitutions and swaps of adjacent characters.
+    fn suggest_within(
+        &self,
+        word: &str,
+        max_distance: u8,
+        max_results: usize,
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic> {
+        if max_results == 0 {
+            return Ok(Vec::new());
+        }
+        let typed: Vec<char> = word.chars().collect();
+        let mut out: Vec<(usize, WordListElement<Self, I>)> = Vec::with_capacity(max_results);
+        for bits_u16 in 0..I::TOTAL {
+            let bits11 = I::from_index(bits_u16 as u16)?;
+            let candidate = self.get_word(bits11)?;
+            if let Some(distance) =
+                bounded_edit_distance(&typed, candidate.as_ref(), max_distance as usize)
+            {
+                // keep `out` sorted by distance, earlier words first on ties
+                let position = out.partition_point(|(d, _)| *d <= distance);
+                if position < max_results {
+                    if out.len() == max_results {
+                        out.pop();
+                    }
+                    out.insert(
+                        position,
+                        (
+                            distance,
+                            WordListElement {
+                                word: candidate,
+                                bits11,
+                            },
+                        ),
+                    );
+                }
+            }
+        }
+        Ok(out.into_iter().map(|(_, element)| element).collect())
+    }
+    /// Characters that may follow `prefix`, sorted and deduplicated, for
+    /// greying out impossible keys. The prefix is expected in the form the
+    /// list stores its words in.
+    fn next_chars(&self, prefix: &str) -> Result<NextChars, ErrorMnemonic> {
+        let mut next_chars = NextChars {
+            chars: Vec::new(),
+            is_word: false,
+        };
+        for element in self.get_words_by_prefix(prefix)?.iter() {
+            match element
+                .word
+                .as_ref()
+                .strip_prefix(prefix)
+                .map(|rest| rest.chars().next())
+            {
+                Some(Some(c)) => {
+                    if let Err(position) = next_chars.chars.binary_search(&c) {
+                        next_chars.chars.insert(position, c)
+                    }
+                }
+                Some(None) => next_chars.is_word = true,
+                None => {}
+            }
+        }
+        Ok(next_chars)
+    }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NextChars {
+    pub chars: Vec<char>,
+    /// The prefix is itself a complete word.
+    pub is_word: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum MnemonicType {
     Words12,
     Words15,
@@ -78,7 +486,16 @@ pub enum MnemonicType {
 }
 
 impl MnemonicType {
-    fn from(len: usize) -> Result<Self, ErrorMnemonic> {
+    /// Every length, shortest first, e.g. for a length picker.
+    pub const ALL: [MnemonicType; 5] = [
+        Self::Words12,
+        Self::Words15,
+        Self::Words18,
+        Self::Words21,
+        Self::Words24,
+    ];
+
+    pub fn from_word_count(len: usize) -> Result<Self, ErrorMnemonic> {
         match len {
             12 => Ok(Self::Words12),
             15 => Ok(Self::Words15),
@@ -88,7 +505,19 @@ impl MnemonicType {
             _ => Err(ErrorMnemonic::WordsNumber),
         }
     }
-    fn checksum_bits(&self) -> u8 {
+    /// Entropy length in bytes.
+    pub fn from_entropy_len(len: usize) -> Result<Self, ErrorMnemonic> {
+        check_entropy_len(len)?;
+        match len {
+            16 => Ok(Self::Words12),
+            20 => Ok(Self::Words15),
+            24 => Ok(Self::Words18),
+            28 => Ok(Self::Words21),
+            // only 32 is left after the check
+            _ => Ok(Self::Words24),
+        }
+    }
+    pub fn checksum_bits(&self) -> u8 {
         match &self {
             Self::Words12 => 4,
             Self::Words15 => 5,
@@ -97,7 +526,7 @@ impl MnemonicType {
             Self::Words24 => 8,
         }
     }
-    fn entropy_bits(&self) -> usize {
+    pub fn entropy_bits(&self) -> usize {
         match &self {
             Self::Words12 => 128,
             Self::Words15 => 160,
@@ -106,47 +535,72 @@ impl MnemonicType {
             Self::Words24 => 256,
         }
     }
-    fn total_bits(&self) -> usize {
+    pub fn total_bits(&self) -> usize {
         self.entropy_bits() + self.checksum_bits() as usize
     }
+    pub fn entropy_bytes(&self) -> usize {
+        self.entropy_bits() / BITS_IN_BYTE
+    }
+    pub fn word_count(&self) -> usize {
+        self.total_bits() / BITS_IN_U11
+    }
 }
 
-#[derive(Clone, Debug, ZeroizeOnDrop)]
-struct BitsHelper {
-    bits: Vec<bool>,
-}
+/// From a word count, as [`MnemonicType::from_word_count`].
+impl TryFrom<usize> for MnemonicType {
+    type Error = ErrorMnemonic;
 
-impl BitsHelper {
-    fn with_capacity(cap: usize) -> Self {
-        Self {
-            bits: Vec::with_capacity(cap),
-        }
+    fn try_from(word_count: usize) -> Result<Self, Self::Error> {
+        Self::from_word_count(word_count)
     }
+}
 
-    fn extend_from_byte(&mut self, byte: u8) {
-        for i in (0..BITS_IN_BYTE).rev() {
-            let bit = (byte & (1 << i)) != 0;
-            self.bits.push(bit);
-        }
-    }
+// Bits not yet taken out, most significant first. At most 18 are ever
+// held: fewer than 11 left over plus 8 new, or fewer than 8 plus 11 new.
+#[derive(Default, ZeroizeOnDrop)]
+struct BitAccumulator {
+    bits: u32,
+    len: usize,
+}
 
-    fn extend_from_bits11(&mut self, bits11: &Bits11) {
-        let two_bytes = bits11.0.to_be_bytes();
+impl BitAccumulator {
+    fn push(&mut self, value: u16, width: usize) {
+        self.bits = self.bits << width | value as u32;
+        self.len += width;
+    }
 
-        // last 3 bits of first byte - others are always zero
-        for i in (0..BITS_IN_U11 % BITS_IN_BYTE).rev() {
-            let bit = (two_bytes[0] & (1 << i)) != 0;
-            self.bits.push(bit);
+    // the oldest `width` bits, if there are that many
+    fn pop(&mut self, width: usize) -> Option<u16> {
+        if self.len < width {
+            return None;
         }
-
-        // all bits of second byte
-        self.extend_from_byte(two_bytes[1])
+        self.len -= width;
+        let value = (self.bits >> self.len) as u16;
+        self.bits &= (1 << self.len) - 1;
+        Some(value)
     }
 }
 
 pub const BITS_IN_BYTE: usize = 8;
 pub const BITS_IN_U11: usize = 11;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Big-endian, as specified by BIP-39.
+    #[default]
+    Bip39,
+    Reversed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MnemonicDiagnostics {
+    pub mnemonic_type: MnemonicType,
+    pub entropy_len: usize,
+    pub stored_checksum: u8,
+    pub computed_checksum: u8,
+    pub checksum_valid: bool,
+}
+
 #[derive(Clone, Debug, ZeroizeOnDrop)]
 pub struct WordSet {
     pub bits11_set: Vec<Bits11>,
@@ -154,29 +608,57 @@ pub struct WordSet {
 
 impl WordSet {
     pub fn from_entropy(entropy: &[u8]) -> Result<Self, ErrorMnemonic> {
-        if entropy.len() < 16 || entropy.len() > 32 || entropy.len() % 4 != 0 {
-            return Err(ErrorMnemonic::InvalidEntropy);
-        }
+        check_entropy_len(entropy.len())?;
+        Ok(Self::from_checked_entropy(entropy))
+    }
+
+    /// As [`from_entropy`](Self::from_entropy), with the checksum hashed by
+    /// `hasher`.
+    pub fn from_entropy_with_hasher<H: ChecksumHasher + ?Sized>(
+        entropy: &[u8],
+        hasher: &mut H,
+    ) -> Result<Self, ErrorMnemonic> {
+        check_entropy_len(entropy.len())?;
+        Ok(Self::from_checked_entropy_with_hasher(entropy, hasher))
+    }
 
-        let checksum_byte = sha256_first_byte(entropy);
+    // `entropy` length must already be checked.
+    fn from_checked_entropy(entropy: &[u8]) -> Self {
+        Self::from_checked_entropy_with_hasher(entropy, &mut Sha2Hasher)
+    }
 
-        let mut entropy_bits = BitsHelper::with_capacity((entropy.len() + 1) * BITS_IN_BYTE);
-        for byte in entropy {
-            entropy_bits.extend_from_byte(*byte);
-        }
-        entropy_bits.extend_from_byte(checksum_byte);
+    fn from_checked_entropy_with_hasher<H: ChecksumHasher + ?Sized>(
+        entropy: &[u8],
+        hasher: &mut H,
+    ) -> Self {
+        let checksum_byte = hasher.sha256_first_byte(entropy);
 
+        // checksum bits past the last whole word are left in `accumulator`
+        let mut accumulator = BitAccumulator::default();
         let mut bits11_set: Vec<Bits11> = Vec::with_capacity(MAX_SEED_LEN);
-        for chunk in entropy_bits.bits.chunks_exact(BITS_IN_U11) {
-            let mut bits11: u16 = 0;
-            for (i, bit) in chunk.iter().rev().enumerate() {
-                if *bit {
-                    bits11 |= 1 << i
-                }
+        for byte in entropy.iter().chain([checksum_byte].iter()) {
+            accumulator.push(*byte as u16, BITS_IN_BYTE);
+            if let Some(bits11) = accumulator.pop(BITS_IN_U11) {
+                bits11_set.push(Bits11(bits11));
             }
-            bits11_set.push(Bits11(bits11));
         }
-        Ok(Self { bits11_set })
+        let word_set = Self { bits11_set };
+
+        // the checksum is calculated here, so a failing round trip can only be
+        // a bit packing bug
+        #[cfg(debug_assertions)]
+        {
+            let mut round_trip = word_set.to_entropy_with_hasher(hasher);
+            debug_assert!(
+                matches!(&round_trip, Ok(calculated) if calculated[..] == entropy[..]),
+                "from_entropy produced a set that does not convert back to its entropy"
+            );
+            if let Ok(calculated) = round_trip.as_mut() {
+                calculated.zeroize();
+            }
+        }
+
+        word_set
     }
 
     pub fn new() -> Self {
@@ -185,85 +667,930 @@ impl WordSet {
         }
     }
 
+    /// Set from raw word indices, e.g. taken from another BIP-39
+    /// implementation. Partial sets are accepted, the checksum is not
+    /// verified here.
+    pub fn from_indices(indices: &[u16]) -> Result<Self, ErrorMnemonic> {
+        if indices.len() > MAX_SEED_LEN {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        let mut word_set = Self::new();
+        for index in indices.iter() {
+            word_set.bits11_set.push(Bits11::from(*index)?);
+        }
+        Ok(word_set)
+    }
+
+    pub fn to_indices(&self) -> Vec<u16> {
+        self.bits11_set.iter().map(|bits11| bits11.bits()).collect()
+    }
+
+    /// Words may be separated by any run of whitespace: spaces, tabs,
+    /// newlines, the ideographic space of Japanese phrases or a mix of them,
+    /// with leading and trailing whitespace ignored. All of these parse
+    /// exactly as the single-space form does.
+    ///
+    /// Each word is looked up in [`ResolveMode::TYPED`], which depends on the
+    /// `nfkd` feature: with it, case, surrounding whitespace and accents are
+    /// ignored and the phrase is NFKD-normalized first, so that precomposed
+    /// input matches lists stored in NFKD form, as BIP-39 lists are. Without
+    /// it only case and surrounding whitespace are ignored, and words typed
+    /// without their accents are not found. Use
+    /// [`from_phrase_with_mode`](Self::from_phrase_with_mode) for the same
+    /// behaviour in every build.
+    pub fn from_phrase<L: AsWordList>(phrase: &str, wordlist: &L) -> Result<Self, ErrorMnemonic> {
+        #[cfg(feature = "nfkd")]
+        let normalized: Zeroizing<String> = {
+            use unicode_normalization::UnicodeNormalization;
+            Zeroizing::new(phrase.nfkd().collect())
+        };
+        #[cfg(feature = "nfkd")]
+        let phrase = normalized.as_str();
+
+        Self::from_phrase_with_mode(phrase, wordlist, ResolveMode::TYPED)
+    }
+
+    /// As `from_phrase`, each word looked up with [`resolve_word`] in `mode`
+    /// and the phrase not normalized; `ResolveMode::Strict` for exact
+    /// matches only.
+    pub fn from_phrase_with_mode<L: AsWordList>(
+        phrase: &str,
+        wordlist: &L,
+        mode: ResolveMode,
+    ) -> Result<Self, ErrorMnemonic> {
+        let mut word_set = Self::new();
+        for (position, word) in phrase.split_whitespace().enumerate() {
+            if word_set.bits11_set.len() == MAX_SEED_LEN {
+                return Err(ErrorMnemonic::WordsNumber);
+            }
+            let bits11 = resolve_word(word, wordlist, mode).map_err(|error| error.at(position))?;
+            word_set.bits11_set.push(bits11);
+        }
+        MnemonicType::from_word_count(word_set.bits11_set.len())?;
+        Ok(word_set)
+    }
+
+    /// `word` is looked up in [`ResolveMode::TYPED`], which ignores accents
+    /// only with the `nfkd` feature, see [`from_phrase`](Self::from_phrase).
     pub fn add_word<L: AsWordList>(
         &mut self,
         word: &str,
         wordlist: &L,
     ) -> Result<(), ErrorMnemonic> {
-        if self.bits11_set.len() < MAX_SEED_LEN {
-            let bits11 = wordlist.bits11_for_word(word)?;
-            self.bits11_set.push(bits11);
+        let bits11 = resolve_word(word, wordlist, ResolveMode::TYPED)?;
+        if self.bits11_set.len() == MAX_SEED_LEN {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.bits11_set.push(bits11);
+        Ok(())
+    }
+
+    /// As [`add_word`](Self::add_word), also taking a unique prefix of the
+    /// word, trimmed and lowercased, see
+    /// [`AsWordList::bits11_for_unique_prefix`].
+    pub fn add_word_prefix<L: AsWordList>(
+        &mut self,
+        prefix: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let bits11 = match resolve_word(prefix, wordlist, ResolveMode::TYPED) {
+            Err(ErrorMnemonic::NoWord) => {
+                wordlist.bits11_for_unique_prefix(&Zeroizing::new(prefix.trim().to_lowercase()))?
+            }
+            found => found?,
+        };
+        if self.bits11_set.len() == MAX_SEED_LEN {
+            return Err(ErrorMnemonic::TooManyWords);
         }
+        self.bits11_set.push(bits11);
+        Ok(())
+    }
+
+    /// Undo the last [`add_word`](Self::add_word). The vacated slot is
+    /// zeroized, the capacity is kept.
+    pub fn remove_last_word(&mut self) -> Option<Bits11> {
+        let slot = self.bits11_set.last_mut()?;
+        let bits11 = *slot;
+        slot.zeroize();
+        self.bits11_set.pop();
+        Some(bits11)
+    }
+
+    /// Remove the word at zero-based `position`, moving the later ones up.
+    /// The vacated slot is zeroized, no copy is left in the spare capacity.
+    pub fn remove_word(&mut self, position: usize) -> Option<Bits11> {
+        if position >= self.bits11_set.len() {
+            return None;
+        }
+        self.bits11_set[position..].rotate_left(1);
+        self.remove_last_word()
+    }
+
+    /// Overwrite the word at zero-based `position` in place, with `word`
+    /// looked up as in [`add_word`](Self::add_word). On error the set is
+    /// unchanged.
+    pub fn replace_word<L: AsWordList>(
+        &mut self,
+        position: usize,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let bits11 = resolve_word(word, wordlist, ResolveMode::TYPED)?;
+        let slot = self
+            .bits11_set
+            .get_mut(position)
+            .ok_or(ErrorMnemonic::PositionOutOfRange { position })?;
+        *slot = bits11;
         Ok(())
     }
 
+    /// Zeroize all entered words, keeping the capacity.
+    pub fn clear(&mut self) {
+        self.bits11_set.zeroize();
+    }
+
+    pub fn mnemonic_type(&self) -> Result<MnemonicType, ErrorMnemonic> {
+        MnemonicType::from_word_count(self.bits11_set.len())
+    }
+
     pub fn is_finalizable(&self) -> bool {
-        MnemonicType::from(self.bits11_set.len()).is_ok()
+        MnemonicType::from_word_count(self.bits11_set.len()).is_ok()
     }
 
-    pub fn to_entropy(&self) -> Result<Vec<u8>, ErrorMnemonic> {
-        let mnemonic_type = MnemonicType::from(self.bits11_set.len())?;
+    /// Exactly one more word would make the set a valid length (11, 14, 17,
+    /// 20 or 23 words entered).
+    pub fn is_one_from_valid(&self) -> bool {
+        MnemonicType::from_word_count(self.bits11_set.len() + 1).is_ok()
+    }
 
-        let mut entropy_bits = BitsHelper::with_capacity(mnemonic_type.total_bits());
+    /// Adding words can still bring the set to a valid length. Says nothing
+    /// about the checksum, see [`valid_last_words`](Self::valid_last_words)
+    /// for that.
+    pub fn could_be_valid_prefix(&self) -> bool {
+        (self.bits11_set.len()..=MAX_SEED_LEN).any(|len| MnemonicType::from_word_count(len).is_ok())
+    }
 
-        for bits11 in self.bits11_set.iter() {
-            entropy_bits.extend_from_bits11(bits11);
+    /// Live feedback during word entry: whether the words so far form a valid
+    /// mnemonic, and which lengths are still reachable. A 12 word set with a
+    /// bad checksum may still be the start of a longer mnemonic.
+    pub fn validate_progress(&self) -> EntryProgress {
+        let word_count = self.bits11_set.len();
+        let checksum_valid = match self.validate() {
+            Ok(()) => Some(true),
+            Err(ErrorMnemonic::InvalidChecksum) => Some(false),
+            Err(_) => None,
+        };
+        EntryProgress {
+            word_count,
+            checksum_valid,
+            reachable: MnemonicType::ALL
+                .into_iter()
+                .filter(|mnemonic_type| mnemonic_type.word_count() >= word_count)
+                .collect(),
         }
+    }
 
-        let mut entropy: Vec<u8> = Vec::with_capacity(mnemonic_type.total_bits() / BITS_IN_BYTE);
-
-        let chunks_exact = entropy_bits.bits.chunks_exact(BITS_IN_BYTE);
-        let remainder = chunks_exact.remainder();
+    /// Every last word that completes the set with a valid checksum: 128 of
+    /// them after 11 words, 8 after 23. The last word carries the final
+    /// entropy bits followed by the checksum, so each choice of those entropy
+    /// bits gives exactly one valid word.
+    pub fn valid_last_words<L: AsWordList>(
+        &self,
+        wordlist: &L,
+    ) -> Result<Vec<WordListElement<L>>, ErrorMnemonic> {
+        if !self.is_one_from_valid() {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let mut candidate = self.clone();
+        candidate.bits11_set.push(Bits11(0));
+        let last = self.bits11_set.len();
+        let checksum_bits = candidate.mnemonic_type()?.checksum_bits();
+        let free_bits = BITS_IN_U11 - checksum_bits as usize;
 
-        for chunk in chunks_exact {
-            let mut byte: u8 = 0;
-            for (i, bit) in chunk.iter().rev().enumerate() {
-                if *bit {
-                    byte |= 1 << i
-                }
-            }
-            entropy.push(byte);
+        let mut out: Vec<WordListElement<L>> = Vec::with_capacity(1 << free_bits);
+        for entropy_part in 0..1u16 << free_bits {
+            candidate.bits11_set[last] = Bits11(entropy_part << checksum_bits);
+            let (_, mut entropy, _, expected_checksum) = candidate.unpack_entropy()?;
+            entropy.zeroize();
+            let bits11 = Bits11::from(candidate.bits11_set[last].0 | expected_checksum as u16)?;
+            out.push(WordListElement {
+                word: wordlist.get_word(bits11)?,
+                bits11,
+            });
         }
+        Ok(out)
+    }
+
+    // Entropy with the checksum bits stripped, together with the checksum
+    // found in the set and the checksum calculated from the entropy.
+    fn unpack_entropy(&self) -> Result<(MnemonicType, Vec<u8>, u8, u8), ErrorMnemonic> {
+        self.unpack_entropy_with_hasher(&mut Sha2Hasher)
+    }
+
+    fn unpack_entropy_with_hasher<H: ChecksumHasher + ?Sized>(
+        &self,
+        hasher: &mut H,
+    ) -> Result<(MnemonicType, Vec<u8>, u8, u8), ErrorMnemonic> {
+        let mnemonic_type = MnemonicType::from_word_count(self.bits11_set.len())?;
+
+        // room for the partial checksum byte too, so that the buffer is never
+        // reallocated and no unwiped copy is left behind
+        let mut entropy: Vec<u8> =
+            Vec::with_capacity(mnemonic_type.total_bits().div_ceil(BITS_IN_BYTE));
 
-        let mut last_byte: u8 = 0;
-        for (i, bit) in remainder.iter().rev().enumerate() {
-            if *bit {
-                last_byte |= 1 << (BITS_IN_BYTE - remainder.len() + i)
+        let mut accumulator = BitAccumulator::default();
+        for bits11 in self.bits11_set.iter() {
+            accumulator.push(bits11.0, BITS_IN_U11);
+            while let Some(byte) = accumulator.pop(BITS_IN_BYTE) {
+                entropy.push(byte as u8);
             }
         }
 
-        entropy.push(last_byte);
+        // checksum bits left over, aligned to the top of the byte
+        if accumulator.len != 0 {
+            let shift = BITS_IN_BYTE - accumulator.len;
+            entropy.push((accumulator.bits << shift) as u8);
+        }
 
-        let entropy_len = mnemonic_type.entropy_bits().div_ceil(BITS_IN_BYTE);
+        let entropy_len = mnemonic_type.entropy_bytes();
 
         let actual_checksum = checksum(entropy[entropy_len], mnemonic_type.checksum_bits());
 
+        // `truncate` leaves the checksum byte in the spare capacity
+        entropy[entropy_len..].zeroize();
         entropy.truncate(entropy_len);
 
-        let checksum_byte = sha256_first_byte(&entropy);
+        let checksum_byte = hasher.sha256_first_byte(&entropy);
 
         let expected_checksum = checksum(checksum_byte, mnemonic_type.checksum_bits());
 
-        if actual_checksum != expected_checksum {
+        Ok((mnemonic_type, entropy, actual_checksum, expected_checksum))
+    }
+
+    pub fn to_entropy(&self) -> Result<Vec<u8>, ErrorMnemonic> {
+        self.to_entropy_with_hasher(&mut Sha2Hasher)
+    }
+
+    /// As [`to_entropy`](Self::to_entropy), with the checksum hashed by
+    /// `hasher`.
+    pub fn to_entropy_with_hasher<H: ChecksumHasher + ?Sized>(
+        &self,
+        hasher: &mut H,
+    ) -> Result<Vec<u8>, ErrorMnemonic> {
+        let (_, mut entropy, actual_checksum, expected_checksum) =
+            self.unpack_entropy_with_hasher(hasher)?;
+
+        if ct_eq_u16(actual_checksum.into(), expected_checksum.into()) == 0 {
+            entropy.zeroize();
             Err(ErrorMnemonic::InvalidChecksum)
         } else {
             Ok(entropy)
         }
     }
 
+    /// As `to_entropy`, wiped when dropped.
+    pub fn to_entropy_secret(&self) -> Result<Zeroizing<Vec<u8>>, ErrorMnemonic> {
+        self.to_entropy().map(Zeroizing::new)
+    }
+
+    /// Entropy whatever the checksum, and whether the checksum matched, for
+    /// recovery tools that search around a phrase with a typo. Never use it
+    /// to accept a phrase: `to_entropy` is the check. The word count is still
+    /// checked. The entropy is wiped when dropped.
+    pub fn to_entropy_unchecked(&self) -> Result<(Zeroizing<Vec<u8>>, bool), ErrorMnemonic> {
+        let (_, entropy, actual_checksum, expected_checksum) = self.unpack_entropy()?;
+        let checksum_valid = ct_eq_u16(actual_checksum.into(), expected_checksum.into()) == 1;
+        Ok((Zeroizing::new(entropy), checksum_valid))
+    }
+
+    /// Length and checksum check of `to_entropy`, without handing out the
+    /// entropy.
+    pub fn validate(&self) -> Result<(), ErrorMnemonic> {
+        self.validate_with_hasher(&mut Sha2Hasher)
+    }
+
+    pub fn validate_with_hasher<H: ChecksumHasher + ?Sized>(
+        &self,
+        hasher: &mut H,
+    ) -> Result<(), ErrorMnemonic> {
+        let (_, mut entropy, actual_checksum, expected_checksum) =
+            self.unpack_entropy_with_hasher(hasher)?;
+        entropy.zeroize();
+        if ct_eq_u16(actual_checksum.into(), expected_checksum.into()) == 0 {
+            Err(ErrorMnemonic::InvalidChecksum)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Entropy in a non-standard byte layout, for interop with wallets that
+    /// do not store it as BIP-39 does. `ByteOrder::Bip39` is `to_entropy`.
+    pub fn to_entropy_with_byte_order(&self, order: ByteOrder) -> Result<Vec<u8>, ErrorMnemonic> {
+        let mut entropy = self.to_entropy()?;
+        match order {
+            ByteOrder::Bip39 => {}
+            ByteOrder::Reversed => entropy.reverse(),
+        }
+        Ok(entropy)
+    }
+
+    /// Everything `to_entropy` works out on the way, for debug and recovery
+    /// screens. The entropy itself is not retained.
+    pub fn diagnostics(&self) -> Result<MnemonicDiagnostics, ErrorMnemonic> {
+        let (mnemonic_type, mut entropy, actual_checksum, expected_checksum) =
+            self.unpack_entropy()?;
+        let entropy_len = entropy.len();
+        entropy.zeroize();
+        Ok(MnemonicDiagnostics {
+            mnemonic_type,
+            entropy_len,
+            stored_checksum: actual_checksum,
+            computed_checksum: expected_checksum,
+            checksum_valid: actual_checksum == expected_checksum,
+        })
+    }
+
+    /// First `target` entropy bytes of this set. Lossy: the result carries
+    /// only `target` bits of security and is not a mnemonic of this set, so
+    /// it is only for protocols that explicitly want a shorter key.
+    pub fn truncate_entropy(&self, target: MnemonicType) -> Result<Vec<u8>, ErrorMnemonic> {
+        let mut entropy = self.to_entropy()?;
+        let target_len = target.entropy_bytes();
+        if target_len > entropy.len() {
+            entropy.zeroize();
+            return Err(ErrorMnemonic::TruncationTarget);
+        }
+        entropy[target_len..].zeroize();
+        entropy.truncate(target_len);
+        Ok(entropy)
+    }
+
+    /// Number of different words in the set. Informational: repeated words
+    /// are legal.
+    pub fn distinct_word_count(&self) -> usize {
+        let mut bits: Vec<u16> = self.bits11_set.iter().map(|bits11| bits11.0).collect();
+        bits.sort_unstable();
+        bits.dedup();
+        let count = bits.len();
+        bits.zeroize();
+        count
+    }
+
+    /// Positions holding a word that is also the prefix of other words in
+    /// `wordlist` (e.g. "act" for "action", "actor"), where a user could have
+    /// written down too little. Informational only.
+    pub fn ambiguous_positions<L: AsWordList>(
+        &self,
+        wordlist: &L,
+    ) -> Result<Vec<usize>, ErrorMnemonic> {
+        let mut positions: Vec<usize> = Vec::new();
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            let word = wordlist
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?;
+            if wordlist.count_by_prefix(word.as_ref())? > 1 {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+
+    /// CSV rows for spreadsheet backups: a `number,word` header, then one
+    /// row per word with its 1-based position. Word list words contain no
+    /// commas, so no quoting is done.
+    pub fn to_export_rows<L: AsWordList>(
+        &self,
+        wordlist: &L,
+    ) -> Result<Vec<String>, ErrorMnemonic> {
+        let mut rows: Vec<String> = Vec::with_capacity(self.bits11_set.len() + 1);
+        rows.push(String::from(EXPORT_HEADER));
+        for (i, bits11) in self.bits11_set.iter().enumerate() {
+            let word = wordlist.get_word(*bits11).map_err(|error| error.at(i))?;
+            rows.push(format!("{},{}", i + 1, word.as_ref()));
+        }
+        Ok(rows)
+    }
+
+    /// Same words in the same order. All positions are compared, so the
+    /// timing shows the lengths but not how many words matched, e.g. when a
+    /// re-entered backup is checked against the original.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let mut equal = u8::from(self.bits11_set.len() == other.bits11_set.len());
+        for (a, b) in self.bits11_set.iter().zip(other.bits11_set.iter()) {
+            equal &= ct_eq_u16(a.0, b.0);
+        }
+        equal == 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits11_set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits11_set.is_empty()
+    }
+
+    /// Words that can still be added before `add_word` fails with
+    /// `TooManyWords`.
+    pub fn remaining_capacity(&self) -> usize {
+        MAX_SEED_LEN.saturating_sub(self.bits11_set.len())
+    }
+
+    /// Words of the set one by one, without building the phrase. The
+    /// iterator ends right after the first failed lookup.
+    pub fn words<'a, L: AsWordList>(
+        &'a self,
+        wordlist: &'a L,
+    ) -> impl Iterator<Item = Result<WordListElement<L>, ErrorMnemonic>> + 'a {
+        let mut failed = false;
+        self.bits11_set.iter().map_while(move |bits11| {
+            if failed {
+                return None;
+            }
+            let element = wordlist.get_word(*bits11).map(|word| WordListElement {
+                word,
+                bits11: *bits11,
+            });
+            failed = element.is_err();
+            Some(element)
+        })
+    }
+
     pub fn to_phrase<L: AsWordList>(&self, wordlist: &L) -> Result<String, ErrorMnemonic> {
+        let separator = wordlist.separator();
+        let separator_len = separator.len_utf8();
         let mut phrase = String::with_capacity(
-            self.bits11_set.len() * (WORD_MAX_LEN + SEPARATOR_LEN) - SEPARATOR_LEN,
+            (self.bits11_set.len() * (WORD_MAX_LEN + separator_len)).saturating_sub(separator_len),
         );
-        for bits11 in self.bits11_set.iter() {
-            if !phrase.is_empty() {
-                phrase.push(' ')
+        match self.write_phrase(wordlist, separator, &mut phrase) {
+            Ok(()) => Ok(phrase),
+            Err(error) => {
+                phrase.zeroize();
+                Err(error)
             }
-            let word = wordlist.get_word(*bits11)?;
-            phrase.push_str(word.as_ref());
         }
+    }
+
+    /// As `to_phrase`, wiped when dropped. The words are measured first, so
+    /// the buffer is allocated once at its final size and never leaves an
+    /// unwiped copy behind when growing.
+    pub fn to_phrase_secret<L: AsWordList>(
+        &self,
+        wordlist: &L,
+    ) -> Result<Zeroizing<String>, ErrorMnemonic> {
+        let separator = wordlist.separator();
+        let mut len = self.bits11_set.len().saturating_sub(1) * separator.len_utf8();
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            let word = wordlist
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?;
+            len += word.as_ref().len();
+        }
+        let mut phrase = Zeroizing::new(String::with_capacity(len));
+        self.write_phrase(wordlist, separator, &mut *phrase)?;
         Ok(phrase)
     }
+
+    /// Phrase written into a caller-owned sink, words joined by `separator`.
+    /// On error the sink may hold part of the phrase.
+    pub fn write_phrase<L: AsWordList, W: FmtWrite>(
+        &self,
+        wordlist: &L,
+        separator: char,
+        out: &mut W,
+    ) -> Result<(), ErrorMnemonic> {
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            if position != 0 {
+                out.write_char(separator)
+                    .map_err(|_| ErrorMnemonic::PhraseWrite)?;
+            }
+            let word = wordlist
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?;
+            out.write_str(word.as_ref())
+                .map_err(|_| ErrorMnemonic::PhraseWrite)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for WordSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Constant-time, see `WordSet::ct_eq`.
+impl PartialEq for WordSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for WordSet {}
+
+/// Mnemonic entropy. Unlike a seed, it converts back to the phrase.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct Entropy(Vec<u8>);
+
+impl Entropy {
+    pub fn new(entropy: &[u8]) -> Result<Self, ErrorMnemonic> {
+        check_entropy_len(entropy.len())?;
+        Ok(Self(entropy.to_vec()))
+    }
+
+    pub fn from_word_set(word_set: &WordSet) -> Result<Self, ErrorMnemonic> {
+        Ok(Self(word_set.to_entropy()?))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Same entropy as `other`, compared in constant time; only the lengths
+    /// show in the timing.
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        ct_eq_bytes(&self.0, other) == 1
+    }
+
+    pub fn to_word_set(&self) -> Result<WordSet, ErrorMnemonic> {
+        WordSet::from_entropy(&self.0)
+    }
+
+    pub fn to_phrase<L: AsWordList>(&self, wordlist: &L) -> Result<String, ErrorMnemonic> {
+        self.to_word_set()?.to_phrase(wordlist)
+    }
+}
+
+// Constant-time, see `Entropy::ct_eq`.
+impl PartialEq for Entropy {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(&other.0)
+    }
+}
+
+impl Eq for Entropy {}
+
+impl Debug for Entropy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Entropy(..)")
+    }
+}
+
+/// The phrase can only be rebuilt from entropy. A seed is a one-way hash of
+/// the phrase and can never be turned back into it.
+pub fn phrase_from_entropy_not_seed<L: AsWordList>(
+    entropy: &[u8],
+    wordlist: &L,
+) -> Result<String, ErrorMnemonic> {
+    WordSet::from_entropy(entropy)?.to_phrase(wordlist)
+}
+
+/// Canonical spelling of a typed word or unique word prefix, matched
+/// case-insensitively, so the UI can echo what the word list stores.
+/// `None` if nothing or more than one word matches.
+pub fn canonical_word<L: AsWordList>(
+    typed: &str,
+    wordlist: &L,
+) -> Result<Option<L::Word>, ErrorMnemonic> {
+    let lowercase = Zeroizing::new(typed.trim().to_lowercase());
+    match wordlist.bits11_for_word(&lowercase) {
+        Ok(bits11) => return wordlist.get_word(bits11).map(Some),
+        Err(ErrorMnemonic::NoWord) => {}
+        Err(e) => return Err(e),
+    }
+    if lowercase.is_empty() {
+        return Ok(None);
+    }
+    let mut candidates = wordlist.get_words_by_prefix(&lowercase)?;
+    if candidates.len() == 1 {
+        Ok(candidates.pop().map(|element| element.word))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Outcome of [`WordSet::validate_progress`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryProgress {
+    pub word_count: usize,
+    /// Whether the checksum matches, once the count is a valid length.
+    pub checksum_valid: Option<bool>,
+    /// Lengths of at least `word_count` words, shortest first; empty past 24.
+    pub reachable: Vec<MnemonicType>,
+}
+
+impl EntryProgress {
+    pub fn is_valid(&self) -> bool {
+        self.checksum_valid == Some(true)
+    }
+
+    /// Words short of the nearest reachable length, `None` if none is left.
+    pub fn words_to_next_length(&self) -> Option<usize> {
+        self.reachable
+            .first()
+            .map(|mnemonic_type| mnemonic_type.word_count() - self.word_count)
+    }
+}
+
+/// Outcome of [`check_phrase`], for forms that highlight every wrong field at
+/// once instead of stopping at the first. The rejected words are not kept:
+/// they are likely a mistyped secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhraseCheck {
+    /// Zero-based positions of the words not in the list.
+    pub unknown_positions: Vec<usize>,
+    pub word_count: usize,
+    /// Whether the checksum matches, once every word is found and the count
+    /// is a valid length.
+    pub checksum_valid: Option<bool>,
+}
+
+impl PhraseCheck {
+    pub fn is_valid(&self) -> bool {
+        self.checksum_valid == Some(true)
+    }
+}
+
+/// Every problem with `phrase` at once, split on whitespace and looked up
+/// word by word as [`WordSet::from_phrase`] does. Only word list failures other than
+/// `NoWord` are returned as errors, with their position.
+pub fn check_phrase<L: AsWordList>(
+    phrase: &str,
+    wordlist: &L,
+) -> Result<PhraseCheck, ErrorMnemonic> {
+    let mut word_set = WordSet::new();
+    let mut unknown_positions: Vec<usize> = Vec::new();
+    let mut word_count = 0;
+    for (position, word) in phrase.split_whitespace().enumerate() {
+        match resolve_word(word, wordlist, ResolveMode::TYPED) {
+            Ok(bits11) => word_set.bits11_set.push(bits11),
+            Err(ErrorMnemonic::NoWord) => unknown_positions.push(position),
+            Err(error) => return Err(error.at(position)),
+        }
+        word_count += 1;
+    }
+    let checksum_valid = if unknown_positions.is_empty() {
+        match word_set.validate() {
+            Ok(()) => Some(true),
+            Err(ErrorMnemonic::InvalidChecksum) => Some(false),
+            Err(_) => None,
+        }
+    } else {
+        None
+    };
+    Ok(PhraseCheck {
+        unknown_positions,
+        word_count,
+        checksum_valid,
+    })
+}
+
+/// Whether `phrase` is a valid mnemonic, without revealing why it is not.
+/// Every word is looked up and the checksum is evaluated even after a failed
+/// lookup, so the failure reason is not exposed through the result or an
+/// early return. Words are looked up with `bits11_for_word_ct`.
+pub fn validate_opaque<L: AsWordList>(phrase: &str, wordlist: &L) -> bool {
+    let mut word_set = WordSet::new();
+    let mut words_found = true;
+    for word in phrase.split_whitespace() {
+        let bits11 = match wordlist.bits11_for_word_ct(word) {
+            Ok(bits11) => bits11,
+            Err(_) => {
+                words_found = false;
+                Bits11(0)
+            }
+        };
+        word_set.bits11_set.push(bits11);
+    }
+    let checksum_valid = match word_set.to_entropy() {
+        Ok(mut entropy) => {
+            entropy.zeroize();
+            true
+        }
+        Err(_) => false,
+    };
+    words_found & checksum_valid
+}
+
+/// How forgiving [`resolve_word`] is with the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResolveMode {
+    /// Exact match, as `bits11_for_word`.
+    #[default]
+    Strict,
+    /// Input lowercased before matching.
+    CaseInsensitive,
+    /// Surrounding whitespace trimmed and input lowercased.
+    Lenient,
+    /// As `Lenient`, with the input NFKD-normalized first.
+    #[cfg(feature = "nfkd")]
+    Nfkd,
+    /// As `Nfkd`, also matching words typed without their accents, as is
+    /// common with the French and Spanish lists: "abaco" finds "ábaco".
+    /// Accents are dropped from both the input and the list words. The French
+    /// and Spanish lists stay unambiguous without accents; on a list where
+    /// several words match, such as Japanese where the voicing marks are
+    /// accents too, the lookup fails with `NoWord`. Without an exact match
+    /// the whole list is scanned, unless `AsWordList::has_accents` says there
+    /// is nothing to drop.
+    #[cfg(feature = "nfkd")]
+    IgnoreAccents,
+}
+
+impl ResolveMode {
+    /// Mode of `WordSet::from_phrase` and `WordSet::add_word`, for words as
+    /// people type them: `IgnoreAccents` with the `nfkd` feature, `Lenient`
+    /// without it, so that enabling the feature accepts more phrases.
+    #[cfg(feature = "nfkd")]
+    pub const TYPED: Self = ResolveMode::IgnoreAccents;
+    /// Mode of `WordSet::from_phrase` and `WordSet::add_word`, for words as
+    /// people type them: `IgnoreAccents` with the `nfkd` feature, `Lenient`
+    /// without it, so that enabling the feature accepts more phrases.
+    #[cfg(not(feature = "nfkd"))]
+    pub const TYPED: Self = ResolveMode::Lenient;
+}
+
+/// Single entry point for word lookup, with the leniency chosen per call.
+/// Crate features only decide which modes exist: `Nfkd` and `IgnoreAccents`
+/// need `nfkd`, and so does the default of [`ResolveMode::TYPED`].
+pub fn resolve_word<L: AsWordList>(
+    input: &str,
+    wordlist: &L,
+    mode: ResolveMode,
+) -> Result<Bits11, ErrorMnemonic> {
+    // exact match first, so that words typed right are never copied
+    let exact = wordlist.bits11_for_word(input);
+    if mode == ResolveMode::Strict || !matches!(exact, Err(ErrorMnemonic::NoWord)) {
+        return exact;
+    }
+    match mode {
+        ResolveMode::Strict => exact,
+        ResolveMode::CaseInsensitive => {
+            wordlist.bits11_for_word(&Zeroizing::new(input.to_lowercase()))
+        }
+        ResolveMode::Lenient => wordlist.bits11_for_word_lenient(input),
+        #[cfg(feature = "nfkd")]
+        ResolveMode::Nfkd => bits11_for_word_nfkd(input, wordlist),
+        #[cfg(feature = "nfkd")]
+        ResolveMode::IgnoreAccents => {
+            match bits11_for_word_nfkd(input, wordlist) {
+                Err(ErrorMnemonic::NoWord) => {}
+                found => return found,
+            }
+            let input: Zeroizing<String> = Zeroizing::new(without_accents(input).collect());
+            if !wordlist.has_accents() {
+                return wordlist.bits11_for_word(&input);
+            }
+            let mut found = None;
+            for bits_u16 in 0..TOTAL_WORDS as u16 {
+                let word = wordlist.get_word(Bits11(bits_u16))?;
+                if without_accents(word.as_ref()).eq(input.chars()) {
+                    if found.is_some() {
+                        return Err(ErrorMnemonic::NoWord);
+                    }
+                    found = Some(Bits11(bits_u16));
+                }
+            }
+            found.ok_or(ErrorMnemonic::NoWord)
+        }
+    }
+}
+
+// `ResolveMode::Nfkd` once the exact lookup has missed.
+#[cfg(feature = "nfkd")]
+fn bits11_for_word_nfkd<L: AsWordList>(input: &str, wordlist: &L) -> Result<Bits11, ErrorMnemonic> {
+    use unicode_normalization::UnicodeNormalization;
+    let normalized: Zeroizing<String> = Zeroizing::new(input.nfkd().collect());
+    wordlist.bits11_for_word_lenient(&normalized)
+}
+
+// Trimmed, NFKD-normalized and lowercased, with combining marks dropped.
+#[cfg(feature = "nfkd")]
+fn without_accents(word: &str) -> impl Iterator<Item = char> + '_ {
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+    word.trim()
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+}
+
+// `has_accents` of a list that can only find out by reading all its words,
+// remembered in `accents` once read. A read error counts as accents, without
+// being remembered.
+#[cfg(feature = "nfkd")]
+pub(crate) fn has_accents_cached<L: AsWordList<I> + ?Sized, I: WordIndex>(
+    wordlist: &L,
+    accents: &Cell<Option<bool>>,
+) -> bool {
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+    if let Some(known) = accents.get() {
+        return known;
+    }
+    let mut found = false;
+    for bits_u16 in 0..I::TOTAL as u16 {
+        match I::from_index(bits_u16).and_then(|bits11| wordlist.get_word(bits11)) {
+            Ok(word) => found |= word.as_ref().nfkd().any(is_combining_mark),
+            Err(_) => return true,
+        }
+    }
+    accents.set(Some(found));
+    found
+}
+
+/// Features this build of the crate was compiled with, for consumers that
+/// can not inspect Cargo features directly (e.g. over FFI).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    pub std: bool,
+    pub english: bool,
+    pub aezeed: bool,
+    pub async_wordlist: bool,
+    pub defmt: bool,
+    pub embedded_storage: bool,
+    pub embedded_storage_async: bool,
+    pub front_coded: bool,
+    pub ffi: bool,
+    pub wasm: bool,
+    pub uniffi: bool,
+    pub electrum: bool,
+    pub chinese_simplified: bool,
+    pub chinese_traditional: bool,
+    pub czech: bool,
+    pub french: bool,
+    pub italian: bool,
+    pub japanese: bool,
+    pub korean: bool,
+    pub portuguese: bool,
+    pub spanish: bool,
+    pub generate: bool,
+    pub os_rng: bool,
+    pub monero: bool,
+    pub nfkd: bool,
+    pub seed: bool,
+    pub serde: bool,
+    pub redact_debug: bool,
+    pub substrate: bool,
+    pub bip32: bool,
+    pub bip85: bool,
+    pub cardano: bool,
+    pub slip39: bool,
+    pub testing: bool,
+    pub test_utils: bool,
+    pub cli: bool,
+}
+
+pub const fn capabilities() -> Capabilities {
+    Capabilities {
+        std: cfg!(feature = "std"),
+        english: cfg!(feature = "english-wordlist"),
+        aezeed: cfg!(feature = "aezeed"),
+        async_wordlist: cfg!(feature = "async-wordlist"),
+        defmt: cfg!(feature = "defmt"),
+        embedded_storage: cfg!(feature = "embedded-storage"),
+        embedded_storage_async: cfg!(feature = "embedded-storage-async"),
+        front_coded: cfg!(feature = "front-coded"),
+        ffi: cfg!(feature = "ffi"),
+        wasm: cfg!(feature = "wasm"),
+        uniffi: cfg!(feature = "uniffi"),
+        electrum: cfg!(feature = "electrum"),
+        chinese_simplified: cfg!(feature = "chinese-simplified"),
+        chinese_traditional: cfg!(feature = "chinese-traditional"),
+        czech: cfg!(feature = "czech"),
+        french: cfg!(feature = "french"),
+        italian: cfg!(feature = "italian"),
+        japanese: cfg!(feature = "japanese"),
+        korean: cfg!(feature = "korean"),
+        portuguese: cfg!(feature = "portuguese"),
+        spanish: cfg!(feature = "spanish"),
+        generate: cfg!(feature = "generate"),
+        os_rng: cfg!(feature = "os-rng"),
+        monero: cfg!(feature = "monero"),
+        nfkd: cfg!(feature = "nfkd"),
+        seed: cfg!(feature = "seed"),
+        serde: cfg!(feature = "serde"),
+        redact_debug: cfg!(feature = "redact-debug"),
+        substrate: cfg!(feature = "substrate"),
+        bip32: cfg!(feature = "bip32"),
+        bip85: cfg!(feature = "bip85"),
+        cardano: cfg!(feature = "cardano"),
+        slip39: cfg!(feature = "slip39"),
+        testing: cfg!(feature = "testing"),
+        test_utils: cfg!(feature = "test-utils"),
+        cli: cfg!(feature = "cli"),
+    }
+}
+
+fn check_entropy_len(len: usize) -> Result<(), ErrorMnemonic> {
+    if len < 16 {
+        Err(ErrorMnemonic::EntropyTooShort { len })
+    } else if len > 32 {
+        Err(ErrorMnemonic::EntropyTooLong { len })
+    } else if len % 4 != 0 {
+        Err(ErrorMnemonic::EntropyNotMultipleOfFour { len })
+    } else {
+        Ok(())
+    }
 }
 
 fn checksum(source: u8, bits: u8) -> u8 {
@@ -274,3 +1601,19 @@ fn checksum(source: u8, bits: u8) -> u8 {
 fn sha256_first_byte(input: &[u8]) -> u8 {
     Sha256::digest(input)[0]
 }
+
+/// SHA-256 of the BIP-39 checksum, pluggable so that firmware can run it on a
+/// hardware accelerator. Only the first byte of the digest is ever used.
+pub trait ChecksumHasher {
+    fn sha256_first_byte(&mut self, input: &[u8]) -> u8;
+}
+
+/// Software SHA-256 from `sha2`, what the methods without a hasher use.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha2Hasher;
+
+impl ChecksumHasher for Sha2Hasher {
+    fn sha256_first_byte(&mut self, input: &[u8]) -> u8 {
+        sha256_first_byte(input)
+    }
+}
@@ -0,0 +1,115 @@
+use std::string::String;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+use crate::error::ErrorMnemonic;
+use crate::regular::InternalWordList;
+use crate::{AsWordList, Bits11, WordSet};
+
+// UniFFI interface over the English list, for the Kotlin and Swift companion
+// apps. Bindings are generated from the built library in library mode, which
+// reads a `cdylib`; the crate is only a `lib`, so the crate type is given on
+// the command line:
+//
+//     cargo rustc --release --lib --crate-type cdylib --features uniffi
+//     uniffi-bindgen generate --library target/release/libmnemonic_external.so \
+//         --language kotlin --out-dir <dir>
+//
+// For iOS, build a `staticlib` the same way for each Apple target.
+//
+// Errors cross as `ErrorMnemonic`, flattened to its text.
+
+/// [`WordSet`] for Kotlin and Swift. Objects are shared across the boundary,
+/// so the set sits behind a lock.
+#[derive(uniffi::Object)]
+pub struct MobileWordSet(Mutex<WordSet>);
+
+impl MobileWordSet {
+    fn with<T>(&self, f: impl FnOnce(&mut WordSet) -> T) -> T {
+        // a panic cannot leave a `WordSet` half updated, so a poisoned lock
+        // is still usable
+        let mut word_set = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut word_set)
+    }
+}
+
+#[uniffi::export]
+impl MobileWordSet {
+    #[uniffi::constructor]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Mutex::new(WordSet::new()))
+    }
+
+    #[uniffi::constructor]
+    pub fn from_phrase(phrase: String) -> Result<Self, ErrorMnemonic> {
+        WordSet::from_phrase(&phrase, &InternalWordList).map(|word_set| Self(Mutex::new(word_set)))
+    }
+
+    pub fn add_word(&self, word: String) -> Result<(), ErrorMnemonic> {
+        self.with(|word_set| word_set.add_word(&word, &InternalWordList))
+    }
+
+    /// Index of the removed word, `null` if the set was empty.
+    pub fn remove_last_word(&self) -> Option<u16> {
+        self.with(|word_set| word_set.remove_last_word().map(Bits11::bits))
+    }
+
+    pub fn clear(&self) {
+        self.with(WordSet::clear)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.with(|word_set| word_set.len() as u32)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.with(|word_set| word_set.is_empty())
+    }
+
+    pub fn is_finalizable(&self) -> bool {
+        self.with(|word_set| word_set.is_finalizable())
+    }
+
+    /// Length and checksum check.
+    pub fn validate(&self) -> Result<(), ErrorMnemonic> {
+        self.with(|word_set| word_set.validate())
+    }
+
+    /// Words that would complete the set with a valid checksum.
+    pub fn valid_last_words(&self) -> Result<Vec<String>, ErrorMnemonic> {
+        self.with(|word_set| word_set.valid_last_words(&InternalWordList))
+            .map(|elements| {
+                elements
+                    .into_iter()
+                    .map(|element| String::from(element.word))
+                    .collect()
+            })
+    }
+
+    pub fn to_phrase(&self) -> Result<String, ErrorMnemonic> {
+        self.with(|word_set| word_set.to_phrase(&InternalWordList))
+    }
+}
+
+/// Words starting with `prefix`, in list order.
+#[uniffi::export]
+pub fn words_by_prefix(prefix: String) -> Result<Vec<String>, ErrorMnemonic> {
+    InternalWordList
+        .get_words_by_prefix(&prefix)
+        .map(|elements| {
+            elements
+                .into_iter()
+                .map(|element| String::from(element.word))
+                .collect()
+        })
+}
+
+/// Length and checksum check of a whole phrase.
+#[uniffi::export]
+pub fn validate_phrase(phrase: String) -> Result<(), ErrorMnemonic> {
+    WordSet::from_phrase(&phrase, &InternalWordList)?.validate()
+}
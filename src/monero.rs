@@ -0,0 +1,346 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::ops::Range;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::ct::ct_eq_u16;
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, WordIndex, WordListElement};
+
+mod wordlist;
+
+pub use wordlist::WORDLIST_MONERO_ENGLISH;
+
+pub const MONERO_TOTAL_WORDS: usize = 1626;
+pub const MONERO_WORD_MAX_LEN: usize = 12;
+/// Leading characters of each key word that enter the checksum, those that
+/// tell the words of the English list apart.
+pub const MONERO_UNIQUE_PREFIX_LEN: usize = 3;
+
+/// Words carrying the key, three for each 4 bytes.
+pub const MONERO_KEY_WORDS: usize = 24;
+/// Key words and the checksum word.
+pub const MONERO_PHRASE_WORDS: usize = MONERO_KEY_WORDS + 1;
+
+pub const SPEND_KEY_LEN: usize = 32;
+
+/// Index into a 1626-word Monero list, for the lists that implement
+/// `AsWordList<MoneroWordIndex>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+pub struct MoneroWordIndex(u16);
+
+impl MoneroWordIndex {
+    pub fn index(self) -> u16 {
+        self.0
+    }
+    pub fn from(i: u16) -> Result<Self, ErrorMnemonic> {
+        if (i as usize) < MONERO_TOTAL_WORDS {
+            Ok(Self(i))
+        } else {
+            Err(ErrorMnemonic::InvalidWordNumber)
+        }
+    }
+}
+
+impl WordIndex for MoneroWordIndex {
+    const TOTAL: usize = MONERO_TOTAL_WORDS;
+    fn index(self) -> u16 {
+        self.0
+    }
+    fn from_index(i: u16) -> Result<Self, ErrorMnemonic> {
+        Self::from(i)
+    }
+}
+
+/// Monero English list.
+pub struct InternalMoneroWordList;
+
+impl InternalMoneroWordList {
+    // `WORDLIST_MONERO_ENGLISH` is sorted, so all words with a common prefix
+    // are adjacent.
+    fn prefix_range(prefix: &str) -> Range<usize> {
+        let start = WORDLIST_MONERO_ENGLISH.partition_point(|word| *word < prefix);
+        let len = WORDLIST_MONERO_ENGLISH[start..].partition_point(|word| word.starts_with(prefix));
+        start..start + len
+    }
+}
+
+impl AsWordList<MoneroWordIndex> for InternalMoneroWordList {
+    type Word = &'static str;
+
+    fn get_word(&self, index: MoneroWordIndex) -> Result<Self::Word, ErrorMnemonic> {
+        WORDLIST_MONERO_ENGLISH
+            .get(index.index() as usize)
+            .copied()
+            .ok_or(ErrorMnemonic::DamagedWord)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self, MoneroWordIndex>>, ErrorMnemonic> {
+        let range = Self::prefix_range(prefix);
+        let mut out: Vec<WordListElement<Self, MoneroWordIndex>> = Vec::with_capacity(range.len());
+        for i in range {
+            out.push(WordListElement {
+                word: WORDLIST_MONERO_ENGLISH[i],
+                bits11: MoneroWordIndex::from(i as u16)?,
+            })
+        }
+        Ok(out)
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<MoneroWordIndex, ErrorMnemonic> {
+        match WORDLIST_MONERO_ENGLISH.binary_search(&word) {
+            Ok(i) => MoneroWordIndex::from(i as u16),
+            Err(_) => Err(ErrorMnemonic::NoWord),
+        }
+    }
+}
+
+/// Words of a Monero phrase, entered one by one: the 24 key words, then the
+/// checksum word.
+#[derive(Clone, Debug, ZeroizeOnDrop)]
+pub struct MoneroWordSet {
+    pub indices: Vec<MoneroWordIndex>,
+}
+
+impl MoneroWordSet {
+    pub fn new() -> Self {
+        Self {
+            indices: Vec::with_capacity(MONERO_PHRASE_WORDS),
+        }
+    }
+
+    pub fn from_phrase<L: AsWordList<MoneroWordIndex>>(
+        phrase: &str,
+        wordlist: &L,
+    ) -> Result<Self, ErrorMnemonic> {
+        let mut word_set = Self::new();
+        for (position, word) in phrase.split_whitespace().enumerate() {
+            if word_set.indices.len() == MONERO_PHRASE_WORDS {
+                return Err(ErrorMnemonic::WordsNumber);
+            }
+            let index = wordlist
+                .bits11_for_word(word)
+                .map_err(|error| error.at(position))?;
+            word_set.indices.push(index);
+        }
+        if word_set.indices.len() != MONERO_PHRASE_WORDS {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        Ok(word_set)
+    }
+
+    pub fn add_word<L: AsWordList<MoneroWordIndex>>(
+        &mut self,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let index = wordlist.bits11_for_word(word)?;
+        if self.indices.len() == MONERO_PHRASE_WORDS {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.indices.push(index);
+        Ok(())
+    }
+
+    /// Undo the last [`add_word`](Self::add_word), zeroizing the slot.
+    pub fn remove_last_word(&mut self) -> Option<MoneroWordIndex> {
+        let slot = self.indices.last_mut()?;
+        let index = *slot;
+        slot.zeroize();
+        self.indices.pop();
+        Some(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Checksum word due after the 24 key words.
+    pub fn checksum_word<L: AsWordList<MoneroWordIndex>>(
+        &self,
+        wordlist: &L,
+    ) -> Result<MoneroWordIndex, ErrorMnemonic> {
+        if self.indices.len() < MONERO_KEY_WORDS {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let key_words = &self.indices[..MONERO_KEY_WORDS];
+        let mut crc = Crc32::new();
+        for (position, index) in key_words.iter().enumerate() {
+            let word = wordlist
+                .get_word(*index)
+                .map_err(|error| error.at(position))?;
+            let word = word.as_ref();
+            let prefix_end = word
+                .char_indices()
+                .nth(MONERO_UNIQUE_PREFIX_LEN)
+                .map_or(word.len(), |(end, _)| end);
+            crc.update(&word.as_bytes()[..prefix_end]);
+        }
+        Ok(key_words[crc.finalize() as usize % MONERO_KEY_WORDS])
+    }
+
+    /// Spend key of a complete phrase, after verifying the checksum word.
+    pub fn to_spend_key<L: AsWordList<MoneroWordIndex>>(
+        &self,
+        wordlist: &L,
+    ) -> Result<MoneroSpendKey, ErrorMnemonic> {
+        if self.indices.len() != MONERO_PHRASE_WORDS {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let expected = self.checksum_word(wordlist)?;
+        if ct_eq_u16(expected.0, self.indices[MONERO_KEY_WORDS].0) == 0 {
+            return Err(ErrorMnemonic::InvalidChecksum);
+        }
+
+        let n = MONERO_TOTAL_WORDS as u64;
+        let mut spend_key = MoneroSpendKey([0u8; SPEND_KEY_LEN]);
+        for (chunk, triple) in spend_key
+            .0
+            .chunks_exact_mut(4)
+            .zip(self.indices[..MONERO_KEY_WORDS].chunks_exact(3))
+        {
+            let w1 = triple[0].0 as u64;
+            let w2 = triple[1].0 as u64;
+            let w3 = triple[2].0 as u64;
+            let mut value = w1 + n * ((n - w1 + w2) % n) + n * n * ((n - w2 + w3) % n);
+            if value > u32::MAX as u64 {
+                value.zeroize();
+                return Err(ErrorMnemonic::WordTriple);
+            }
+            chunk.copy_from_slice(&(value as u32).to_le_bytes());
+            value.zeroize();
+        }
+        Ok(spend_key)
+    }
+
+    pub fn to_phrase<L: AsWordList<MoneroWordIndex>>(
+        &self,
+        wordlist: &L,
+    ) -> Result<String, ErrorMnemonic> {
+        let separator = wordlist.separator();
+        let mut phrase = String::with_capacity(
+            self.indices.len() * (MONERO_WORD_MAX_LEN + separator.len_utf8()),
+        );
+        for (position, index) in self.indices.iter().enumerate() {
+            if position != 0 {
+                phrase.push(separator);
+            }
+            match wordlist.get_word(*index) {
+                Ok(word) => phrase.push_str(word.as_ref()),
+                Err(error) => {
+                    phrase.zeroize();
+                    return Err(error.at(position));
+                }
+            }
+        }
+        Ok(phrase)
+    }
+}
+
+impl Default for MoneroWordSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Monero private spend key, as carried by the 25-word phrase. Wallets
+/// reduce the decoded value modulo the group order; for phrases made by a
+/// wallet the key is already reduced and this changes nothing.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct MoneroSpendKey([u8; SPEND_KEY_LEN]);
+
+impl MoneroSpendKey {
+    pub fn from_bytes(bytes: &[u8; SPEND_KEY_LEN]) -> Self {
+        Self(*bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; SPEND_KEY_LEN] {
+        &self.0
+    }
+
+    pub fn from_phrase<L: AsWordList<MoneroWordIndex>>(
+        phrase: &str,
+        wordlist: &L,
+    ) -> Result<Self, ErrorMnemonic> {
+        MoneroWordSet::from_phrase(phrase, wordlist)?.to_spend_key(wordlist)
+    }
+
+    /// The 24 key words, each 4 little-endian bytes giving three words, and
+    /// the checksum word.
+    pub fn to_word_set<L: AsWordList<MoneroWordIndex>>(
+        &self,
+        wordlist: &L,
+    ) -> Result<MoneroWordSet, ErrorMnemonic> {
+        let n = MONERO_TOTAL_WORDS as u32;
+        let mut word_set = MoneroWordSet::new();
+        for chunk in self.0.chunks_exact(4) {
+            let mut value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let w1 = value % n;
+            let w2 = (value / n + w1) % n;
+            let w3 = (value / n / n + w2) % n;
+            value.zeroize();
+            for w in [w1, w2, w3] {
+                word_set.indices.push(MoneroWordIndex(w as u16));
+            }
+        }
+        let checksum = word_set.checksum_word(wordlist)?;
+        word_set.indices.push(checksum);
+        Ok(word_set)
+    }
+
+    pub fn to_phrase<L: AsWordList<MoneroWordIndex>>(
+        &self,
+        wordlist: &L,
+    ) -> Result<String, ErrorMnemonic> {
+        self.to_word_set(wordlist)?.to_phrase(wordlist)
+    }
+}
+
+impl AsRef<[u8]> for MoneroSpendKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for MoneroSpendKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "MoneroSpendKey(..)")
+    }
+}
+
+// CRC-32 (IEEE 802.3, as in zlib), bit by bit: it only ever runs over the
+// word prefixes of one phrase.
+struct Crc32(u32);
+
+impl Crc32 {
+    fn new() -> Self {
+        Self(0xFFFFFFFF)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 ^= *byte as u32;
+            for _ in 0..8 {
+                let mask = 0u32.wrapping_sub(self.0 & 1);
+                self.0 = (self.0 >> 1) ^ (0xEDB88320 & mask);
+            }
+        }
+    }
+
+    fn finalize(self) -> u32 {
+        !self.0
+    }
+}
@@ -0,0 +1,41 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::error::ErrorMnemonic;
+
+const BLOCK_LEN: usize = 64;
+
+// PBKDF2-HMAC-SHA512 filling `out`, one 64 byte block at a time.
+// Intermediate blocks are zeroized; the internal state of the `sha2` hasher
+// is not reachable and can not be wiped from here.
+pub(crate) fn pbkdf2_sha512(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out: &mut [u8],
+) -> Result<(), ErrorMnemonic> {
+    let keyed = <Hmac<Sha512> as Mac>::new_from_slice(password)
+        .map_err(|_| ErrorMnemonic::SeedDerivation)?;
+    let mut sum = Zeroizing::new([0u8; BLOCK_LEN]);
+    for (index, out_block) in out.chunks_mut(BLOCK_LEN).enumerate() {
+        let mut mac = keyed.clone();
+        mac.update(salt);
+        mac.update(&(index as u32 + 1).to_be_bytes());
+        let mut block = Zeroizing::new(<[u8; BLOCK_LEN]>::from(mac.finalize().into_bytes()));
+        sum.copy_from_slice(block.as_ref());
+
+        for _ in 1..rounds {
+            let mut mac = keyed.clone();
+            mac.update(block.as_ref());
+            let mut next = <[u8; BLOCK_LEN]>::from(mac.finalize().into_bytes());
+            block.copy_from_slice(&next);
+            next.zeroize();
+            for (sum_byte, block_byte) in sum.iter_mut().zip(block.iter()) {
+                *sum_byte ^= block_byte;
+            }
+        }
+        out_block.copy_from_slice(&sum[..out_block.len()]);
+    }
+    Ok(())
+}
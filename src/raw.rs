@@ -0,0 +1,310 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "nfkd")]
+use core::cell::Cell;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::str;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "embedded-storage-async")]
+use crate::async_wordlist::{AsWordListAsync, WordListElementAsync};
+use crate::custom::audit_wordlist_indexed;
+use crate::error::{ErrorMnemonic, WordlistAudit};
+#[cfg(feature = "nfkd")]
+use crate::has_accents_cached;
+#[cfg(feature = "embedded-storage-async")]
+use crate::TOTAL_WORDS;
+use crate::{AsWordList, Bits11, WordIndex, WordListElement};
+
+// Blob layout, for word lists kept in external flash:
+//
+// | offset | length                 | content                            |
+// |--------|------------------------|------------------------------------|
+// | 0      | 4                      | `RAW_MAGIC`                        |
+// | 4      | 1                      | `RAW_FORMAT_VERSION`               |
+// | 5      | 1                      | record length `n`, 1 to 48         |
+// | 6      | `TOTAL_WORDS * n`      | words in list order, UTF-8, padded |
+//
+// Lists of another length, e.g. Monero ones, have as many records as words.
+//
+// Padding is `0xFF`, which never occurs in UTF-8 and is what erased flash
+// reads as, so a record ends at its first `0xFF` byte.
+
+pub const RAW_MAGIC: [u8; 4] = *b"MNWL";
+pub const RAW_FORMAT_VERSION: u8 = 1;
+pub const RAW_HEADER_LEN: usize = 6;
+
+/// Longest record a blob may declare; the longest word of the official lists,
+/// in Korean, is 33 bytes.
+pub const RAW_MAX_RECORD_LEN: usize = 48;
+
+const RAW_PADDING: u8 = 0xFF;
+
+/// Blob of `words` in the layout [`RawWordList`] reads, records as wide as
+/// the longest word.
+pub fn raw_wordlist_blob(words: &[&str]) -> Result<Vec<u8>, WordlistAudit> {
+    raw_wordlist_blob_indexed::<Bits11>(words)
+}
+
+/// As [`raw_wordlist_blob`], for a list indexed by `I`, e.g. a Monero list.
+pub fn raw_wordlist_blob_indexed<I: WordIndex>(words: &[&str]) -> Result<Vec<u8>, WordlistAudit> {
+    audit_wordlist_indexed::<I, _>(words)?;
+    let mut record_len = 0;
+    for (index, word) in words.iter().enumerate() {
+        if word.len() > RAW_MAX_RECORD_LEN {
+            return Err(WordlistAudit::WordTooLong { index });
+        }
+        record_len = record_len.max(word.len());
+    }
+    let mut blob = Vec::with_capacity(RAW_HEADER_LEN + I::TOTAL * record_len);
+    blob.extend_from_slice(&RAW_MAGIC);
+    blob.push(RAW_FORMAT_VERSION);
+    blob.push(record_len as u8);
+    for word in words.iter() {
+        blob.extend_from_slice(word.as_bytes());
+        blob.resize(blob.len() + record_len - word.len(), RAW_PADDING);
+    }
+    Ok(blob)
+}
+
+/// Word list over a blob made by [`raw_wordlist_blob`], read through
+/// `read(offset, buffer)`, which fills `buffer` from `offset` bytes into the
+/// blob. Any reader error is `DamagedWord`.
+///
+/// Nothing is cached: each lookup reads records again, and word and prefix
+/// searches scan the whole list. Only whether the list has accents is
+/// remembered, once a lookup has needed it.
+pub struct RawWordList<F> {
+    read: F,
+    record_len: usize,
+    #[cfg(feature = "nfkd")]
+    accents: Cell<Option<bool>>,
+}
+
+impl<F, E> RawWordList<F>
+where
+    F: Fn(usize, &mut [u8]) -> Result<(), E>,
+{
+    /// Reads and checks the header; `WordListFormat` if it does not match.
+    pub fn new(read: F) -> Result<Self, ErrorMnemonic> {
+        let mut header = [0u8; RAW_HEADER_LEN];
+        read(0, &mut header).map_err(|_| ErrorMnemonic::DamagedWord)?;
+        let record_len = raw_record_len(&header)?;
+        Ok(Self {
+            read,
+            record_len,
+            #[cfg(feature = "nfkd")]
+            accents: Cell::new(None),
+        })
+    }
+
+    pub fn record_len(&self) -> usize {
+        self.record_len
+    }
+
+    fn read_record(&self, bits: Bits11, record: &mut RawWord) -> Result<(), ErrorMnemonic> {
+        (self.read)(
+            raw_record_offset(bits, self.record_len),
+            record.buffer(self.record_len),
+        )
+        .map_err(|_| ErrorMnemonic::DamagedWord)?;
+        record.settle(self.record_len)
+    }
+}
+
+// Record length declared by a blob header, checked along with the rest of it.
+pub(crate) fn raw_record_len(header: &[u8; RAW_HEADER_LEN]) -> Result<usize, ErrorMnemonic> {
+    let record_len = header[5] as usize;
+    if header[..4] != RAW_MAGIC
+        || header[4] != RAW_FORMAT_VERSION
+        || record_len == 0
+        || record_len > RAW_MAX_RECORD_LEN
+    {
+        return Err(ErrorMnemonic::WordListFormat);
+    }
+    Ok(record_len)
+}
+
+pub(crate) fn raw_record_offset<I: WordIndex>(bits: I, record_len: usize) -> usize {
+    RAW_HEADER_LEN + bits.index() as usize * record_len
+}
+
+// Whole-list scans of the blob word lists, over `read_record`, which fills a
+// record with the entry at an index from wherever the blob is kept.
+pub(crate) fn raw_words_by_prefix<L, I, R>(
+    prefix: &str,
+    mut read_record: R,
+) -> Result<Vec<WordListElement<L, I>>, ErrorMnemonic>
+where
+    L: AsWordList<I, Word = RawWord> + ?Sized,
+    I: WordIndex,
+    R: FnMut(I, &mut RawWord) -> Result<(), ErrorMnemonic>,
+{
+    let mut out: Vec<WordListElement<L, I>> = Vec::new();
+    for bits_u16 in 0..I::TOTAL as u16 {
+        let bits11 = I::from_index(bits_u16)?;
+        let mut word = RawWord::new();
+        read_record(bits11, &mut word)?;
+        if word.as_ref().starts_with(prefix) {
+            out.push(WordListElement { word, bits11 })
+        }
+    }
+    Ok(out)
+}
+
+pub(crate) fn raw_bits11_for_word<I, R>(
+    word: &str,
+    record_len: usize,
+    mut read_record: R,
+) -> Result<I, ErrorMnemonic>
+where
+    I: WordIndex,
+    R: FnMut(I, &mut RawWord) -> Result<(), ErrorMnemonic>,
+{
+    if word.len() > record_len {
+        return Err(ErrorMnemonic::NoWord);
+    }
+    let mut record = RawWord::new();
+    for bits_u16 in 0..I::TOTAL as u16 {
+        let bits11 = I::from_index(bits_u16)?;
+        read_record(bits11, &mut record)?;
+        if record.as_ref() == word {
+            return Ok(bits11);
+        }
+    }
+    Err(ErrorMnemonic::NoWord)
+}
+
+// The same scans over a reader that awaits the device.
+#[cfg(feature = "embedded-storage-async")]
+pub(crate) async fn raw_words_by_prefix_async<L, R>(
+    prefix: &str,
+    mut read_record: R,
+) -> Result<Vec<WordListElementAsync<L>>, ErrorMnemonic>
+where
+    L: AsWordListAsync<Word = RawWord> + ?Sized,
+    R: AsyncFnMut(Bits11, &mut RawWord) -> Result<(), ErrorMnemonic>,
+{
+    let mut out: Vec<WordListElementAsync<L>> = Vec::new();
+    for bits_u16 in 0..TOTAL_WORDS as u16 {
+        let bits11 = Bits11(bits_u16);
+        let mut word = RawWord::new();
+        read_record(bits11, &mut word).await?;
+        if word.as_ref().starts_with(prefix) {
+            out.push(WordListElementAsync { word, bits11 })
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "embedded-storage-async")]
+pub(crate) async fn raw_bits11_for_word_async<R>(
+    word: &str,
+    record_len: usize,
+    mut read_record: R,
+) -> Result<Bits11, ErrorMnemonic>
+where
+    R: AsyncFnMut(Bits11, &mut RawWord) -> Result<(), ErrorMnemonic>,
+{
+    if word.len() > record_len {
+        return Err(ErrorMnemonic::NoWord);
+    }
+    let mut record = RawWord::new();
+    for bits_u16 in 0..TOTAL_WORDS as u16 {
+        let bits11 = Bits11(bits_u16);
+        read_record(bits11, &mut record).await?;
+        if record.as_ref() == word {
+            return Ok(bits11);
+        }
+    }
+    Err(ErrorMnemonic::NoWord)
+}
+
+impl<F> Debug for RawWordList<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "RawWordList {{ record_len: {} }}", self.record_len)
+    }
+}
+
+impl<F, E> AsWordList for RawWordList<F>
+where
+    F: Fn(usize, &mut [u8]) -> Result<(), E>,
+{
+    type Word = RawWord;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        let mut word = RawWord::new();
+        self.read_record(bits, &mut word)?;
+        Ok(word)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        raw_words_by_prefix(prefix, |bits11, record| self.read_record(bits11, record))
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        raw_bits11_for_word(word, self.record_len, |bits11, record| {
+            self.read_record(bits11, record)
+        })
+    }
+
+    #[cfg(feature = "nfkd")]
+    fn has_accents(&self) -> bool {
+        has_accents_cached(self, &self.accents)
+    }
+}
+
+/// Word read from a [`RawWordList`] or another blob word list, held inline
+/// and zeroized on drop.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct RawWord {
+    bytes: [u8; RAW_MAX_RECORD_LEN],
+    len: usize,
+}
+
+impl RawWord {
+    pub(crate) fn new() -> Self {
+        Self {
+            bytes: [0; RAW_MAX_RECORD_LEN],
+            len: 0,
+        }
+    }
+
+    // space for a record to be read into
+    pub(crate) fn buffer(&mut self, record_len: usize) -> &mut [u8] {
+        &mut self.bytes[..record_len]
+    }
+
+    // finds the end of a record just read, which must be a UTF-8 word
+    pub(crate) fn settle(&mut self, record_len: usize) -> Result<(), ErrorMnemonic> {
+        self.len = self.bytes[..record_len]
+            .iter()
+            .position(|byte| *byte == RAW_PADDING)
+            .unwrap_or(record_len);
+        if self.len == 0 || str::from_utf8(&self.bytes[..self.len]).is_err() {
+            return Err(ErrorMnemonic::DamagedWord);
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<str> for RawWord {
+    fn as_ref(&self) -> &str {
+        // only ever built from records checked to be UTF-8
+        str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Debug for RawWord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "RawWord(..)")
+    }
+}
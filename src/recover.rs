@@ -0,0 +1,119 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "nfkd")]
+use zeroize::Zeroizing;
+
+#[cfg(all(feature = "nfkd", not(feature = "std")))]
+use alloc::string::String;
+
+#[cfg(all(feature = "nfkd", feature = "std"))]
+use std::string::String;
+
+use crate::error::ErrorMnemonic;
+use crate::{
+    resolve_word, AsWordList, Bits11, MnemonicType, ResolveMode, WordListElement, WordSet,
+    MAX_SEED_LEN, TOTAL_WORDS,
+};
+
+// Recovery of a phrase with one bad word, illegible on the backup or written
+// down wrong: every word of the list is tried in its place and those giving
+// a valid checksum are kept. The checksum is all there is to go by, so the
+// candidates are many, 128 per position for 12 words and 8 for 24, and each
+// has to be checked against the wallet, e.g. by its first address.
+
+/// Stands for the word that cannot be read, in [`recover_phrase`].
+pub const UNKNOWN_WORD: &str = "?";
+
+impl WordSet {
+    /// Words that give a valid checksum at zero-based `position`, in list
+    /// order. The word now at `position` is ignored, so any word may stand
+    /// in for an illegible one.
+    pub fn recover_word_at<L: AsWordList>(
+        &self,
+        position: usize,
+        wordlist: &L,
+    ) -> Result<Vec<WordListElement<L>>, ErrorMnemonic> {
+        MnemonicType::from_word_count(self.bits11_set.len())?;
+        if position >= self.bits11_set.len() {
+            return Err(ErrorMnemonic::PositionOutOfRange { position });
+        }
+        let mut candidate = self.clone();
+        let mut out: Vec<WordListElement<L>> = Vec::new();
+        for bits_u16 in 0..TOTAL_WORDS {
+            let bits11 = Bits11::from(bits_u16 as u16)?;
+            candidate.bits11_set[position] = bits11;
+            if candidate.validate().is_ok() {
+                out.push(WordListElement {
+                    word: wordlist.get_word(bits11)?,
+                    bits11,
+                });
+            }
+        }
+        Ok(out)
+    }
+
+    /// Single-word changes that give a valid checksum, for a set with one
+    /// wrong word at an unknown position: `(position, word)` pairs, by
+    /// position and then in list order. The words already in the set are
+    /// not offered.
+    pub fn recover_any_word<L: AsWordList>(
+        &self,
+        wordlist: &L,
+    ) -> Result<Vec<(usize, WordListElement<L>)>, ErrorMnemonic> {
+        let mut out: Vec<(usize, WordListElement<L>)> = Vec::new();
+        for (position, bits11) in self.bits11_set.iter().enumerate() {
+            for element in self.recover_word_at(position, wordlist)? {
+                if element.bits11 != *bits11 {
+                    out.push((position, element));
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Candidates for `phrase` as [`WordSet::recover_word_at`] gives them, with
+/// the illegible word written as [`UNKNOWN_WORD`]; without one, as
+/// [`WordSet::recover_any_word`] gives them. The other words are looked up
+/// as `from_phrase` does. `UnknownWords` for more than one unknown word.
+pub fn recover_phrase<L: AsWordList>(
+    phrase: &str,
+    wordlist: &L,
+) -> Result<Vec<(usize, WordListElement<L>)>, ErrorMnemonic> {
+    #[cfg(feature = "nfkd")]
+    let normalized: Zeroizing<String> = {
+        use unicode_normalization::UnicodeNormalization;
+        Zeroizing::new(phrase.nfkd().collect())
+    };
+    #[cfg(feature = "nfkd")]
+    let phrase = normalized.as_str();
+
+    let mut word_set = WordSet::new();
+    let mut unknown: Option<usize> = None;
+    for (position, word) in phrase.split_whitespace().enumerate() {
+        if word_set.bits11_set.len() == MAX_SEED_LEN {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        let bits11 = if word == UNKNOWN_WORD {
+            if unknown.replace(position).is_some() {
+                return Err(ErrorMnemonic::UnknownWords);
+            }
+            Bits11(0)
+        } else {
+            resolve_word(word, wordlist, ResolveMode::TYPED).map_err(|error| error.at(position))?
+        };
+        word_set.bits11_set.push(bits11);
+    }
+    match unknown {
+        Some(position) => Ok(word_set
+            .recover_word_at(position, wordlist)?
+            .into_iter()
+            .map(|element| (position, element))
+            .collect()),
+        None => word_set.recover_any_word(wordlist),
+    }
+}
@@ -4,42 +4,329 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::vec::Vec;
 
+use core::ops::Range;
+use core::str::FromStr;
+
+use crate::custom::slice_get_word;
 use crate::error::ErrorMnemonic;
+use crate::verify;
 use crate::wordlist::WORDLIST_ENGLISH;
-use crate::{AsWordList, Bits11, WordListElement};
+use crate::{resolve_word, AsWordList, Bits11, ResolveMode, WordListElement, WordSet};
+
+#[cfg(any(
+    feature = "chinese-simplified",
+    feature = "chinese-traditional",
+    feature = "czech",
+    feature = "french",
+    feature = "italian",
+    feature = "japanese",
+    feature = "korean",
+    feature = "portuguese",
+    feature = "spanish",
+))]
+mod languages;
+
+#[cfg(any(
+    feature = "chinese-simplified",
+    feature = "chinese-traditional",
+    feature = "czech",
+    feature = "french",
+    feature = "italian",
+    feature = "japanese",
+    feature = "korean",
+    feature = "portuguese",
+    feature = "spanish",
+))]
+pub use languages::*;
 
 pub struct InternalWordList;
 
+impl InternalWordList {
+    fn prefix_range(prefix: &str) -> Range<usize> {
+        sorted_prefix_range(&WORDLIST_ENGLISH, prefix)
+    }
+}
+
+// In a list sorted by byte value all words with a common prefix are adjacent.
+fn sorted_prefix_range(list: &[&str], prefix: &str) -> Range<usize> {
+    let start = list.partition_point(|word| *word < prefix);
+    let len = list[start..].partition_point(|word| word.starts_with(prefix));
+    start..start + len
+}
+
 impl AsWordList for InternalWordList {
     type Word = &'static str;
 
     fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
-        let word_order = bits.bits() as usize;
-        Ok(WORDLIST_ENGLISH[word_order])
+        slice_get_word(&WORDLIST_ENGLISH, bits)
     }
 
     fn get_words_by_prefix(
         &self,
         prefix: &str,
     ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
-        let mut out: Vec<WordListElement<Self>> = Vec::new();
-        for (i, word) in WORDLIST_ENGLISH.iter().enumerate() {
-            if word.starts_with(prefix) {
-                out.push(WordListElement {
-                    word,
-                    bits11: Bits11::from(i as u16)?,
-                })
-            }
+        let range = Self::prefix_range(prefix);
+        let mut out: Vec<WordListElement<Self>> = Vec::with_capacity(range.len());
+        for i in range {
+            out.push(WordListElement {
+                word: WORDLIST_ENGLISH[i],
+                bits11: Bits11::from(i as u16)?,
+            })
         }
         Ok(out)
     }
 
     fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
-        for (i, element) in WORDLIST_ENGLISH.iter().enumerate() {
-            if element == &word {
-                return Bits11::from(i as u16);
+        match WORDLIST_ENGLISH.binary_search(&word) {
+            Ok(i) => Bits11::from(i as u16),
+            Err(_) => Err(ErrorMnemonic::NoWord),
+        }
+    }
+
+    fn count_by_prefix(&self, prefix: &str) -> Result<usize, ErrorMnemonic> {
+        Ok(Self::prefix_range(prefix).len())
+    }
+
+    fn has_accents(&self) -> bool {
+        false
+    }
+
+    fn iter_words_by_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = Result<WordListElement<Self>, ErrorMnemonic>> + 'a {
+        Self::prefix_range(prefix).map(|i| {
+            Ok(WordListElement {
+                word: WORDLIST_ENGLISH[i],
+                bits11: Bits11(i as u16),
+            })
+        })
+    }
+
+    fn get_words_by_prefix_range(
+        &self,
+        prefix: &str,
+        skip: usize,
+        take: usize,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        let range = Self::prefix_range(prefix);
+        let start = range.start.saturating_add(skip).min(range.end);
+        let end = start.saturating_add(take).min(range.end);
+        let mut out: Vec<WordListElement<Self>> = Vec::with_capacity(end - start);
+        for (i, word) in WORDLIST_ENGLISH[start..end].iter().enumerate() {
+            out.push(WordListElement {
+                word,
+                bits11: Bits11::from((start + i) as u16)?,
+            })
+        }
+        Ok(out)
+    }
+
+    fn get_words_by_prefix_limited(
+        &self,
+        prefix: &str,
+        max: usize,
+    ) -> Result<(Vec<WordListElement<Self>>, bool), ErrorMnemonic> {
+        let more = Self::prefix_range(prefix).len() > max;
+        Ok((self.get_words_by_prefix_range(prefix, 0, max)?, more))
+    }
+}
+
+/// Word lists built into this build of the crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    #[cfg(feature = "chinese-simplified")]
+    ChineseSimplified,
+    #[cfg(feature = "chinese-traditional")]
+    ChineseTraditional,
+    #[cfg(feature = "czech")]
+    Czech,
+    #[cfg(feature = "french")]
+    French,
+    #[cfg(feature = "italian")]
+    Italian,
+    #[cfg(feature = "japanese")]
+    Japanese,
+    #[cfg(feature = "korean")]
+    Korean,
+    #[cfg(feature = "portuguese")]
+    Portuguese,
+    #[cfg(feature = "spanish")]
+    Spanish,
+}
+
+// Runs `$body` with `$list` bound to the word list of `$language`.
+macro_rules! with_word_list {
+    ($language:expr, $list:ident => $body:expr) => {
+        match $language {
+            Language::English => {
+                let $list = &InternalWordList;
+                $body
+            }
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => {
+                let $list = &InternalWordListChineseSimplified;
+                $body
+            }
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => {
+                let $list = &InternalWordListChineseTraditional;
+                $body
+            }
+            #[cfg(feature = "czech")]
+            Language::Czech => {
+                let $list = &InternalWordListCzech;
+                $body
+            }
+            #[cfg(feature = "french")]
+            Language::French => {
+                let $list = &InternalWordListFrench;
+                $body
+            }
+            #[cfg(feature = "italian")]
+            Language::Italian => {
+                let $list = &InternalWordListItalian;
+                $body
+            }
+            #[cfg(feature = "japanese")]
+            Language::Japanese => {
+                let $list = &InternalWordListJapanese;
+                $body
+            }
+            #[cfg(feature = "korean")]
+            Language::Korean => {
+                let $list = &InternalWordListKorean;
+                $body
+            }
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => {
+                let $list = &InternalWordListPortuguese;
+                $body
+            }
+            #[cfg(feature = "spanish")]
+            Language::Spanish => {
+                let $list = &InternalWordListSpanish;
+                $body
+            }
+        }
+    };
+}
+
+impl Language {
+    pub const ALL: &'static [Language] = &[
+        Language::English,
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified,
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional,
+        #[cfg(feature = "czech")]
+        Language::Czech,
+        #[cfg(feature = "french")]
+        Language::French,
+        #[cfg(feature = "italian")]
+        Language::Italian,
+        #[cfg(feature = "japanese")]
+        Language::Japanese,
+        #[cfg(feature = "korean")]
+        Language::Korean,
+        #[cfg(feature = "portuguese")]
+        Language::Portuguese,
+        #[cfg(feature = "spanish")]
+        Language::Spanish,
+    ];
+
+    /// Whether `word` is in the list, looked up as in
+    /// [`WordSet::add_word`].
+    pub fn contains_word(self, word: &str) -> bool {
+        with_word_list!(self, list => resolve_word(word, list, ResolveMode::TYPED).is_ok())
+    }
+
+    pub fn word_set_from_phrase(self, phrase: &str) -> Result<WordSet, ErrorMnemonic> {
+        with_word_list!(self, list => WordSet::from_phrase(phrase, list))
+    }
+
+    /// Digest of the official list, for
+    /// [`verify_wordlist_sha256`](verify::verify_wordlist_sha256).
+    pub fn wordlist_sha256(self) -> [u8; 32] {
+        match self {
+            Language::English => verify::WORDLIST_SHA256_ENGLISH,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => verify::WORDLIST_SHA256_CHINESE_SIMPLIFIED,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => verify::WORDLIST_SHA256_CHINESE_TRADITIONAL,
+            #[cfg(feature = "czech")]
+            Language::Czech => verify::WORDLIST_SHA256_CZECH,
+            #[cfg(feature = "french")]
+            Language::French => verify::WORDLIST_SHA256_FRENCH,
+            #[cfg(feature = "italian")]
+            Language::Italian => verify::WORDLIST_SHA256_ITALIAN,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => verify::WORDLIST_SHA256_JAPANESE,
+            #[cfg(feature = "korean")]
+            Language::Korean => verify::WORDLIST_SHA256_KOREAN,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => verify::WORDLIST_SHA256_PORTUGUESE,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => verify::WORDLIST_SHA256_SPANISH,
+        }
+    }
+}
+
+/// Built-in languages whose list contains every word of the phrase. Lists
+/// share words (English and French, the two Chinese lists), so more than one
+/// may match.
+pub fn detect_language(phrase: &str) -> Vec<Language> {
+    if phrase.split_whitespace().next().is_none() {
+        return Vec::new();
+    }
+    Language::ALL
+        .iter()
+        .copied()
+        .filter(|language| {
+            phrase
+                .split_whitespace()
+                .all(|word| language.contains_word(word))
+        })
+        .collect()
+}
+
+impl WordSet {
+    /// Phrase in any built-in language. When several lists contain all the
+    /// words, the first one in [`Language::ALL`] giving a valid checksum is
+    /// taken.
+    pub fn from_phrase_any_language(phrase: &str) -> Result<(Self, Language), ErrorMnemonic> {
+        let mut error = ErrorMnemonic::UnknownLanguage;
+        for language in detect_language(phrase) {
+            match language
+                .word_set_from_phrase(phrase)
+                .and_then(|word_set| word_set.validate().map(|()| word_set))
+            {
+                Ok(word_set) => return Ok((word_set, language)),
+                Err(e) => error = e,
             }
         }
-        Err(ErrorMnemonic::NoWord)
+        Err(error)
+    }
+}
+
+/// Words of the set borrowed straight from `WORDLIST_ENGLISH`.
+pub fn words_of(set: &WordSet) -> Result<Vec<&'static str>, ErrorMnemonic> {
+    let mut words: Vec<&'static str> = Vec::with_capacity(set.bits11_set.len());
+    for (position, bits11) in set.bits11_set.iter().enumerate() {
+        words.push(
+            InternalWordList
+                .get_word(*bits11)
+                .map_err(|error| error.at(position))?,
+        );
+    }
+    Ok(words)
+}
+
+impl FromStr for WordSet {
+    type Err = ErrorMnemonic;
+
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        WordSet::from_phrase(phrase, &InternalWordList)
     }
 }
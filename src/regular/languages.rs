@@ -0,0 +1,160 @@
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, vec::Vec};
+
+use super::sorted_prefix_range;
+use crate::custom::{slice_bits11_for_word, slice_get_word, slice_words_by_prefix};
+use crate::error::ErrorMnemonic;
+#[cfg(feature = "chinese-simplified")]
+use crate::wordlist::WORDLIST_CHINESE_SIMPLIFIED;
+#[cfg(feature = "chinese-traditional")]
+use crate::wordlist::WORDLIST_CHINESE_TRADITIONAL;
+#[cfg(feature = "czech")]
+use crate::wordlist::WORDLIST_CZECH;
+#[cfg(feature = "french")]
+use crate::wordlist::WORDLIST_FRENCH;
+#[cfg(feature = "italian")]
+use crate::wordlist::WORDLIST_ITALIAN;
+#[cfg(feature = "japanese")]
+use crate::wordlist::WORDLIST_JAPANESE;
+#[cfg(feature = "korean")]
+use crate::wordlist::WORDLIST_KOREAN;
+#[cfg(feature = "portuguese")]
+use crate::wordlist::WORDLIST_PORTUGUESE;
+#[cfg(feature = "spanish")]
+use crate::wordlist::WORDLIST_SPANISH;
+use crate::{AsWordList, Bits11, WordListElement};
+
+// Non-English lists are stored NFKD, as BIP-39 mandates for the phrase. With
+// the `nfkd` feature the input is brought to the same form, otherwise it must
+// already be NFKD.
+#[cfg(feature = "nfkd")]
+fn normalize(word: &str) -> Cow<'_, str> {
+    use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
+    match is_nfkd_quick(word.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(word),
+        _ => Cow::Owned(word.nfkd().collect()),
+    }
+}
+
+#[cfg(not(feature = "nfkd"))]
+fn normalize(word: &str) -> Cow<'_, str> {
+    Cow::Borrowed(word)
+}
+
+// Most lists other than English are not sorted by byte value, so lookups
+// scan. Those that are (`sorted`) use binary search, as English does. None of
+// the sorted lists has accents; those that do are marked `accented`.
+macro_rules! internal_word_list {
+    ($name:ident, $list:ident, $separator:expr) => {
+        internal_word_list!($name, $list, $separator, false, false);
+    };
+    ($name:ident, $list:ident, $separator:expr, sorted) => {
+        internal_word_list!($name, $list, $separator, true, false);
+    };
+    ($name:ident, $list:ident, $separator:expr, accented) => {
+        internal_word_list!($name, $list, $separator, false, true);
+    };
+    ($name:ident, $list:ident, $separator:expr, $sorted:literal, $accents:literal) => {
+        pub struct $name;
+
+        impl AsWordList for $name {
+            type Word = &'static str;
+
+            fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+                slice_get_word(&$list, bits)
+            }
+
+            fn get_words_by_prefix(
+                &self,
+                prefix: &str,
+            ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+                let prefix = normalize(prefix);
+                if !$sorted {
+                    return slice_words_by_prefix(&$list, prefix.as_ref());
+                }
+                let mut out: Vec<WordListElement<Self>> = Vec::new();
+                for i in sorted_prefix_range(&$list, prefix.as_ref()) {
+                    out.push(WordListElement {
+                        word: $list[i],
+                        bits11: Bits11::from(i as u16)?,
+                    })
+                }
+                Ok(out)
+            }
+
+            fn iter_words_by_prefix<'a>(
+                &'a self,
+                prefix: &'a str,
+            ) -> impl Iterator<Item = Result<WordListElement<Self>, ErrorMnemonic>> + 'a {
+                let prefix = normalize(prefix);
+                let range = if $sorted {
+                    sorted_prefix_range(&$list, prefix.as_ref())
+                } else {
+                    0..$list.len()
+                };
+                range.filter_map(move |i| {
+                    $list[i].starts_with(prefix.as_ref()).then(|| {
+                        Ok(WordListElement {
+                            word: $list[i],
+                            bits11: Bits11(i as u16),
+                        })
+                    })
+                })
+            }
+
+            fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+                let word = normalize(word);
+                if !$sorted {
+                    return slice_bits11_for_word(&$list, word.as_ref());
+                }
+                match $list.binary_search(&word.as_ref()) {
+                    Ok(i) => Bits11::from(i as u16),
+                    Err(_) => Err(ErrorMnemonic::NoWord),
+                }
+            }
+
+            fn separator(&self) -> char {
+                $separator
+            }
+
+            fn has_accents(&self) -> bool {
+                $accents
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chinese-simplified")]
+internal_word_list!(
+    InternalWordListChineseSimplified,
+    WORDLIST_CHINESE_SIMPLIFIED,
+    ' '
+);
+#[cfg(feature = "chinese-traditional")]
+internal_word_list!(
+    InternalWordListChineseTraditional,
+    WORDLIST_CHINESE_TRADITIONAL,
+    ' '
+);
+#[cfg(feature = "czech")]
+internal_word_list!(InternalWordListCzech, WORDLIST_CZECH, ' ');
+#[cfg(feature = "french")]
+internal_word_list!(InternalWordListFrench, WORDLIST_FRENCH, ' ', accented);
+#[cfg(feature = "italian")]
+internal_word_list!(InternalWordListItalian, WORDLIST_ITALIAN, ' ', sorted);
+#[cfg(feature = "japanese")]
+internal_word_list!(
+    InternalWordListJapanese,
+    WORDLIST_JAPANESE,
+    '\u{3000}',
+    accented
+);
+#[cfg(feature = "korean")]
+internal_word_list!(InternalWordListKorean, WORDLIST_KOREAN, ' ', sorted);
+#[cfg(feature = "portuguese")]
+internal_word_list!(InternalWordListPortuguese, WORDLIST_PORTUGUESE, ' ', sorted);
+#[cfg(feature = "spanish")]
+internal_word_list!(InternalWordListSpanish, WORDLIST_SPANISH, ' ', accented);
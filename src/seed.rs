@@ -0,0 +1,155 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::error::ErrorMnemonic;
+use crate::pbkdf2::pbkdf2_sha512;
+use crate::{AsWordList, WordSet};
+
+pub const SEED_LEN: usize = 64;
+pub const PBKDF2_ROUNDS: u32 = 2048;
+pub const SALT_PREFIX: &str = "mnemonic";
+
+/// BIP-39 seed. The derivation is one-way: there is no way back from a seed
+/// to its mnemonic, keep the [`Entropy`](crate::Entropy) if the phrase must be
+/// recoverable.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct Seed(pub(crate) [u8; SEED_LEN]);
+
+impl Seed {
+    pub fn as_bytes(&self) -> &[u8; SEED_LEN] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for Seed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Seed(..)")
+    }
+}
+
+impl WordSet {
+    /// BIP-39 seed: PBKDF2-HMAC-SHA512 over the NFKD phrase, salted with
+    /// `"mnemonic"` and the NFKD passphrase. The checksum is verified first.
+    pub fn to_seed<L: AsWordList>(
+        &self,
+        wordlist: &L,
+        passphrase: &str,
+    ) -> Result<Seed, ErrorMnemonic> {
+        let mut entropy = self.to_entropy()?;
+        entropy.zeroize();
+
+        let phrase = self.to_phrase_secret(wordlist)?;
+        let phrase: Zeroizing<String> = Zeroizing::new(phrase.nfkd().collect());
+
+        let passphrase = normalize_passphrase(passphrase);
+        let mut salt = Zeroizing::new(String::with_capacity(SALT_PREFIX.len() + passphrase.len()));
+        salt.push_str(SALT_PREFIX);
+        salt.push_str(&passphrase);
+
+        let mut seed = Seed([0u8; SEED_LEN]);
+        pbkdf2_hmac_sha512(phrase.as_bytes(), salt.as_bytes(), &mut seed.0)?;
+        Ok(seed)
+    }
+}
+
+// PBKDF2 as BIP-39 runs it: the seed is exactly one SHA-512 block.
+pub(crate) fn pbkdf2_hmac_sha512(
+    password: &[u8],
+    salt: &[u8],
+    out: &mut [u8; SEED_LEN],
+) -> Result<(), ErrorMnemonic> {
+    pbkdf2_sha512(password, salt, PBKDF2_ROUNDS, out)
+}
+
+/// NFKD form of the passphrase, as it enters the seed derivation.
+pub fn normalize_passphrase(passphrase: &str) -> Zeroizing<String> {
+    Zeroizing::new(passphrase.nfkd().collect())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PassphraseStrength {
+    Empty,
+    Weak,
+    Moderate,
+    Strong,
+}
+
+/// What the user should double-check before committing to a passphrase: it
+/// has no checksum, so a typo silently yields a different wallet.
+#[derive(Debug)]
+pub struct PassphraseFeedback {
+    /// Exactly what goes into the seed derivation.
+    pub normalized: Zeroizing<String>,
+    pub char_count: usize,
+    pub changed_by_normalization: bool,
+    pub surrounding_whitespace: bool,
+    /// Rough estimate from length and character classes.
+    pub estimated_bits: usize,
+    pub strength: PassphraseStrength,
+}
+
+const WEAK_BITS_BELOW: usize = 40;
+const STRONG_BITS_FROM: usize = 64;
+
+pub fn passphrase_feedback(passphrase: &str) -> PassphraseFeedback {
+    let normalized = normalize_passphrase(passphrase);
+    let char_count = normalized.chars().count();
+
+    let mut lower = false;
+    let mut upper = false;
+    let mut digit = false;
+    let mut symbol = false;
+    let mut other = false;
+    for c in normalized.chars() {
+        match c {
+            'a'..='z' => lower = true,
+            'A'..='Z' => upper = true,
+            '0'..='9' => digit = true,
+            c if c.is_ascii() => symbol = true,
+            _ => other = true,
+        }
+    }
+    let pool = 26 * usize::from(lower)
+        + 26 * usize::from(upper)
+        + 10 * usize::from(digit)
+        + 33 * usize::from(symbol)
+        + 100 * usize::from(other);
+    let estimated_bits = if pool > 1 {
+        char_count * pool.ilog2() as usize
+    } else {
+        0
+    };
+
+    let strength = if char_count == 0 {
+        PassphraseStrength::Empty
+    } else if estimated_bits < WEAK_BITS_BELOW {
+        PassphraseStrength::Weak
+    } else if estimated_bits < STRONG_BITS_FROM {
+        PassphraseStrength::Moderate
+    } else {
+        PassphraseStrength::Strong
+    };
+
+    PassphraseFeedback {
+        changed_by_normalization: normalized.as_str() != passphrase,
+        surrounding_whitespace: passphrase.trim() != passphrase,
+        normalized,
+        char_count,
+        estimated_bits,
+        strength,
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use zeroize::Zeroizing;
+
+use crate::error::ErrorMnemonic;
+use crate::{Bits11, MnemonicType, WordSet};
+
+/// Each word of a SeedQR is its zero-padded decimal index.
+pub const SEEDQR_DIGITS_PER_WORD: usize = 4;
+
+impl WordSet {
+    /// SeedQR payload: the word indices as 4-digit decimal numbers, run
+    /// together, for a QR code in numeric mode. The set must have a BIP-39
+    /// word count; SeedSigner itself only makes 12 and 24 word codes.
+    pub fn to_seedqr(&self) -> Result<Zeroizing<String>, ErrorMnemonic> {
+        self.mnemonic_type()?;
+        let mut digits = Zeroizing::new(String::with_capacity(
+            self.bits11_set.len() * SEEDQR_DIGITS_PER_WORD,
+        ));
+        for bits11 in self.bits11_set.iter() {
+            let index = bits11.bits();
+            for divisor in [1000, 100, 10, 1] {
+                digits.push(char::from(b'0' + (index / divisor % 10) as u8));
+            }
+        }
+        Ok(digits)
+    }
+
+    /// Set from a SeedQR payload. As with [`from_phrase`](Self::from_phrase),
+    /// the checksum is not verified here.
+    pub fn from_seedqr(digits: &str) -> Result<Self, ErrorMnemonic> {
+        let digits = digits.as_bytes();
+        if digits.len() % SEEDQR_DIGITS_PER_WORD != 0 {
+            return Err(ErrorMnemonic::WordsNumber);
+        }
+        MnemonicType::from_word_count(digits.len() / SEEDQR_DIGITS_PER_WORD)?;
+
+        let mut word_set = Self::new();
+        for chunk in digits.chunks_exact(SEEDQR_DIGITS_PER_WORD) {
+            let mut index: u16 = 0;
+            for digit in chunk.iter() {
+                if !digit.is_ascii_digit() {
+                    return Err(ErrorMnemonic::SeedQrDigits);
+                }
+                index = index * 10 + (digit - b'0') as u16;
+            }
+            word_set.bits11_set.push(Bits11::from(index)?);
+        }
+        Ok(word_set)
+    }
+
+    /// CompactSeedQR payload: the entropy bytes, without the checksum, for a
+    /// QR code in byte mode. The checksum must match.
+    pub fn to_compact_seedqr(&self) -> Result<Zeroizing<Vec<u8>>, ErrorMnemonic> {
+        self.to_entropy_secret()
+    }
+
+    /// Set from a CompactSeedQR payload; the checksum is recalculated.
+    pub fn from_compact_seedqr(bytes: &[u8]) -> Result<Self, ErrorMnemonic> {
+        Self::from_entropy(bytes)
+    }
+}
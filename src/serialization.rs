@@ -0,0 +1,73 @@
+use core::fmt::{Formatter, Result as FmtResult};
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ErrorMnemonic;
+use crate::{Bits11, MnemonicType, WordSet, MAX_SEED_LEN};
+
+// Word sets serialize as arrays of word indices. These are secret: whoever
+// reads the serialized form has the mnemonic.
+
+impl Serialize for Bits11 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bits11 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u16::deserialize(deserializer)?;
+        Bits11::from(bits).map_err(D::Error::custom)
+    }
+}
+
+// Mnemonic types serialize as their word count.
+impl Serialize for MnemonicType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.word_count() as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for MnemonicType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let word_count = u8::deserialize(deserializer)?;
+        MnemonicType::from_word_count(word_count as usize).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for WordSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits11_set.serialize(serializer)
+    }
+}
+
+// Indices go straight into a `WordSet`, so that whatever was read before an
+// error is zeroized with it. Partially entered sets are fine, oversized ones
+// are refused at the first extra index.
+struct WordSetVisitor;
+
+impl<'de> Visitor<'de> for WordSetVisitor {
+    type Value = WordSet;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "an array of at most {MAX_SEED_LEN} word indices")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut word_set = WordSet::new();
+        while let Some(bits11) = seq.next_element::<Bits11>()? {
+            if word_set.bits11_set.len() == MAX_SEED_LEN {
+                return Err(A::Error::custom(ErrorMnemonic::TooManyWords));
+            }
+            word_set.bits11_set.push(bits11);
+        }
+        Ok(word_set)
+    }
+}
+
+impl<'de> Deserialize<'de> for WordSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(WordSetVisitor)
+    }
+}
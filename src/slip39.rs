@@ -0,0 +1,850 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec, vec::Vec};
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::ops::Range;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[cfg(feature = "generate")]
+use rand_core::CryptoRngCore;
+
+use crate::error::{ErrorMnemonic, ErrorShare};
+use crate::WORD_MAX_LEN;
+
+mod wordlist;
+
+pub use wordlist::WORDLIST_SLIP39;
+
+pub const SHARE_TOTAL_WORDS: usize = 1024;
+
+pub const MIN_SECRET_LEN: usize = 16;
+pub const MAX_SECRET_LEN: usize = 32;
+
+/// Limit on both the number of groups and the number of members in a group.
+pub const MAX_SHARE_COUNT: u8 = 16;
+pub const MAX_ITERATION_EXPONENT: u8 = 15;
+
+pub const MIN_SHARE_WORDS: usize = METADATA_WORDS + 13;
+pub const MAX_SHARE_WORDS: usize = METADATA_WORDS + 26;
+
+const BITS_IN_U10: usize = 10;
+const METADATA_WORDS: usize = 7;
+const CHECKSUM_WORDS: usize = 3;
+
+const CUSTOMIZATION: &[u8] = b"shamir";
+const CUSTOMIZATION_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+
+const DIGEST_LEN: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Zeroize)]
+pub struct Bits10(u16);
+
+impl Bits10 {
+    pub fn bits(self) -> u16 {
+        self.0
+    }
+    pub fn from(i: u16) -> Result<Self, ErrorMnemonic> {
+        if (i as usize) < SHARE_TOTAL_WORDS {
+            Ok(Self(i))
+        } else {
+            Err(ErrorMnemonic::InvalidWordNumber)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ShareWordListElement<L: AsShareWordList + ?Sized> {
+    pub word: L::Word,
+    pub bits10: Bits10,
+}
+
+/// Source of the 1024 SLIP-0039 words, indexed by [`Bits10`]. Same contract
+/// as [`AsWordList`](crate::AsWordList), so the list may live in external
+/// memory as well.
+pub trait AsShareWordList {
+    type Word: AsRef<str>;
+    /// Must not panic: a missing or unreadable word is `DamagedWord`.
+    fn get_word(&self, bits: Bits10) -> Result<Self::Word, ErrorMnemonic>;
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<ShareWordListElement<Self>>, ErrorMnemonic>;
+    fn bits10_for_word(&self, word: &str) -> Result<Bits10, ErrorMnemonic>;
+    fn separator(&self) -> char {
+        ' '
+    }
+}
+
+pub struct InternalShareWordList;
+
+impl InternalShareWordList {
+    // `WORDLIST_SLIP39` is sorted, so all words with a common prefix are
+    // adjacent.
+    fn prefix_range(prefix: &str) -> Range<usize> {
+        let start = WORDLIST_SLIP39.partition_point(|word| *word < prefix);
+        let len = WORDLIST_SLIP39[start..].partition_point(|word| word.starts_with(prefix));
+        start..start + len
+    }
+}
+
+impl AsShareWordList for InternalShareWordList {
+    type Word = &'static str;
+
+    fn get_word(&self, bits: Bits10) -> Result<Self::Word, ErrorMnemonic> {
+        WORDLIST_SLIP39
+            .get(bits.bits() as usize)
+            .copied()
+            .ok_or(ErrorMnemonic::DamagedWord)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<ShareWordListElement<Self>>, ErrorMnemonic> {
+        let range = Self::prefix_range(prefix);
+        let mut out: Vec<ShareWordListElement<Self>> = Vec::with_capacity(range.len());
+        for i in range {
+            out.push(ShareWordListElement {
+                word: WORDLIST_SLIP39[i],
+                bits10: Bits10::from(i as u16)?,
+            })
+        }
+        Ok(out)
+    }
+
+    fn bits10_for_word(&self, word: &str) -> Result<Bits10, ErrorMnemonic> {
+        match WORDLIST_SLIP39.binary_search(&word) {
+            Ok(i) => Bits10::from(i as u16),
+            Err(_) => Err(ErrorMnemonic::NoWord),
+        }
+    }
+}
+
+/// Words of one share, entered one by one. Nothing beyond the word count is
+/// checked until [`to_share`](Self::to_share).
+#[derive(Clone, Debug, ZeroizeOnDrop)]
+pub struct ShareWordSet {
+    pub bits10_set: Vec<Bits10>,
+}
+
+impl ShareWordSet {
+    pub fn new() -> Self {
+        Self {
+            bits10_set: Vec::with_capacity(MAX_SHARE_WORDS),
+        }
+    }
+
+    pub fn from_indices(indices: &[u16]) -> Result<Self, ErrorMnemonic> {
+        if indices.len() > MAX_SHARE_WORDS {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        let mut word_set = Self::new();
+        for index in indices.iter() {
+            word_set.bits10_set.push(Bits10::from(*index)?);
+        }
+        Ok(word_set)
+    }
+
+    pub fn to_indices(&self) -> Vec<u16> {
+        self.bits10_set.iter().map(|bits10| bits10.bits()).collect()
+    }
+
+    pub fn from_phrase<L: AsShareWordList>(
+        phrase: &str,
+        wordlist: &L,
+    ) -> Result<Self, ErrorMnemonic> {
+        let mut word_set = Self::new();
+        for (position, word) in phrase.split_whitespace().enumerate() {
+            if word_set.bits10_set.len() == MAX_SHARE_WORDS {
+                return Err(ErrorMnemonic::WordsNumber);
+            }
+            let bits10 = wordlist
+                .bits10_for_word(word)
+                .map_err(|error| error.at(position))?;
+            word_set.bits10_set.push(bits10);
+        }
+        Ok(word_set)
+    }
+
+    pub fn add_word<L: AsShareWordList>(
+        &mut self,
+        word: &str,
+        wordlist: &L,
+    ) -> Result<(), ErrorMnemonic> {
+        let bits10 = wordlist.bits10_for_word(word)?;
+        if self.bits10_set.len() == MAX_SHARE_WORDS {
+            return Err(ErrorMnemonic::TooManyWords);
+        }
+        self.bits10_set.push(bits10);
+        Ok(())
+    }
+
+    /// Undo the last [`add_word`](Self::add_word), zeroizing the slot.
+    pub fn remove_last_word(&mut self) -> Option<Bits10> {
+        let slot = self.bits10_set.last_mut()?;
+        let bits10 = *slot;
+        slot.zeroize();
+        self.bits10_set.pop();
+        Some(bits10)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits10_set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits10_set.is_empty()
+    }
+
+    pub fn to_phrase<L: AsShareWordList>(&self, wordlist: &L) -> Result<String, ErrorMnemonic> {
+        let separator = wordlist.separator();
+        let mut phrase =
+            String::with_capacity(self.bits10_set.len() * (WORD_MAX_LEN + separator.len_utf8()));
+        for (position, bits10) in self.bits10_set.iter().enumerate() {
+            if position != 0 {
+                phrase.push(separator);
+            }
+            match wordlist.get_word(*bits10) {
+                Ok(word) => phrase.push_str(word.as_ref()),
+                Err(error) => {
+                    phrase.zeroize();
+                    return Err(error.at(position));
+                }
+            }
+        }
+        Ok(phrase)
+    }
+
+    /// Decode the share, verifying the RS1024 checksum and the padding.
+    pub fn to_share(&self) -> Result<Share, ErrorShare> {
+        let words = &self.bits10_set;
+        if words.len() < MIN_SHARE_WORDS || words.len() > MAX_SHARE_WORDS {
+            return Err(ErrorShare::Length);
+        }
+        let value_words = words.len() - METADATA_WORDS;
+        // the value is a whole number of 16-bit units, left-padded with zeros
+        let padding_len = (BITS_IN_U10 * value_words) % 16;
+        if padding_len > 8 {
+            return Err(ErrorShare::Length);
+        }
+
+        let id_exp = (words[0].0 as u32) << BITS_IN_U10 | words[1].0 as u32;
+        let extendable = (id_exp >> 4) & 1 == 1;
+        if rs1024_polymod(
+            customization(extendable),
+            words.iter().map(|bits10| bits10.0),
+        ) != 1
+        {
+            return Err(ErrorShare::Checksum);
+        }
+
+        let parameters = (words[2].0 as u32) << BITS_IN_U10 | words[3].0 as u32;
+        let nibble = |shift: u32| ((parameters >> shift) & 0xF) as u8;
+        let group_threshold = nibble(12) + 1;
+        let group_count = nibble(8) + 1;
+        if group_threshold > group_count {
+            return Err(ErrorShare::GroupThreshold);
+        }
+
+        let value = unpack_value(&words[4..words.len() - CHECKSUM_WORDS], padding_len)?;
+        Ok(Share {
+            identifier: (id_exp >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_exp & 0xF) as u8,
+            group_index: nibble(16),
+            group_threshold,
+            group_count,
+            member_index: nibble(4),
+            member_threshold: nibble(0) + 1,
+            value,
+        })
+    }
+}
+
+impl Default for ShareWordSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One mnemonic share. The metadata is public; the share value is not
+/// exposed and is zeroized on drop.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Share {
+    pub fn from_phrase<L: AsShareWordList>(phrase: &str, wordlist: &L) -> Result<Self, ErrorShare> {
+        ShareWordSet::from_phrase(phrase, wordlist)
+            .map_err(ErrorShare::Word)?
+            .to_share()
+    }
+
+    pub fn to_phrase<L: AsShareWordList>(&self, wordlist: &L) -> Result<String, ErrorShare> {
+        self.to_word_set()
+            .to_phrase(wordlist)
+            .map_err(ErrorShare::Word)
+    }
+
+    pub fn to_word_set(&self) -> ShareWordSet {
+        let mut word_set = ShareWordSet::new();
+        let id_exp = (self.identifier as u32) << 5
+            | (self.extendable as u32) << 4
+            | self.iteration_exponent as u32;
+        let parameters = (self.group_index as u32) << 16
+            | ((self.group_threshold - 1) as u32) << 12
+            | ((self.group_count - 1) as u32) << 8
+            | (self.member_index as u32) << 4
+            | (self.member_threshold - 1) as u32;
+        for packed in [id_exp, parameters] {
+            word_set
+                .bits10_set
+                .push(Bits10((packed >> BITS_IN_U10) as u16));
+            word_set.bits10_set.push(Bits10((packed & 0x3FF) as u16));
+        }
+        pack_value(&self.value, &mut word_set.bits10_set);
+
+        let polymod = rs1024_polymod(
+            customization(self.extendable),
+            word_set
+                .bits10_set
+                .iter()
+                .map(|bits10| bits10.0)
+                .chain([0; CHECKSUM_WORDS]),
+        ) ^ 1;
+        for i in (0..CHECKSUM_WORDS).rev() {
+            word_set
+                .bits10_set
+                .push(Bits10(((polymod >> (BITS_IN_U10 * i)) & 0x3FF) as u16));
+        }
+        word_set
+    }
+
+    /// Random identifier common to all shares of one split.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+    pub fn extendable(&self) -> bool {
+        self.extendable
+    }
+    pub fn iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    fn same_split(&self, other: &Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+    }
+}
+
+impl Debug for Share {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Share {{ identifier: {}, group: {}/{} of {}, member: {}/{}, .. }}",
+            self.identifier,
+            self.group_index,
+            self.group_threshold,
+            self.group_count,
+            self.member_index,
+            self.member_threshold
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroupSpec {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SplitParameters<'a> {
+    pub group_threshold: u8,
+    pub groups: &'a [GroupSpec],
+    /// PBKDF2 runs `10000 << iteration_exponent` iterations in total.
+    pub iteration_exponent: u8,
+    /// Extendable splits do not salt the encryption with the identifier, so
+    /// further splits of the same secret and passphrase stay compatible.
+    pub extendable: bool,
+}
+
+/// Split `master_secret` into shares, one `Vec` per group in the order of
+/// `parameters.groups`. A member threshold of 1 is only allowed for a group
+/// of a single share.
+#[cfg(feature = "generate")]
+pub fn split_master_secret<R: CryptoRngCore + ?Sized>(
+    master_secret: &[u8],
+    passphrase: &str,
+    parameters: &SplitParameters,
+    rng: &mut R,
+) -> Result<Vec<Vec<Share>>, ErrorShare> {
+    check_secret_len(master_secret.len())?;
+    if parameters.iteration_exponent > MAX_ITERATION_EXPONENT {
+        return Err(ErrorShare::IterationExponent);
+    }
+    let group_count = parameters.groups.len();
+    if group_count > MAX_SHARE_COUNT as usize
+        || parameters.group_threshold == 0
+        || parameters.group_threshold as usize > group_count
+    {
+        return Err(ErrorShare::GroupThreshold);
+    }
+    for group in parameters.groups.iter() {
+        if group.member_count > MAX_SHARE_COUNT
+            || group.member_threshold == 0
+            || group.member_threshold > group.member_count
+            || (group.member_threshold == 1 && group.member_count > 1)
+        {
+            return Err(ErrorShare::MemberThreshold);
+        }
+    }
+
+    let mut identifier = [0u8; 2];
+    rng.fill_bytes(&mut identifier);
+    let identifier = u16::from_be_bytes(identifier) & 0x7FFF;
+
+    let encrypted = feistel(
+        master_secret,
+        passphrase.as_bytes(),
+        parameters.iteration_exponent,
+        identifier,
+        parameters.extendable,
+        true,
+    )?;
+    let group_secrets = split_secret(
+        parameters.group_threshold,
+        group_count as u8,
+        &encrypted,
+        rng,
+    )?;
+
+    let mut groups: Vec<Vec<Share>> = Vec::with_capacity(group_count);
+    for (group_index, (group, group_secret)) in parameters
+        .groups
+        .iter()
+        .zip(group_secrets.iter())
+        .enumerate()
+    {
+        let member_values = split_secret(
+            group.member_threshold,
+            group.member_count,
+            group_secret,
+            rng,
+        )?;
+        groups.push(
+            member_values
+                .iter()
+                .enumerate()
+                .map(|(member_index, value)| Share {
+                    identifier,
+                    extendable: parameters.extendable,
+                    iteration_exponent: parameters.iteration_exponent,
+                    group_index: group_index as u8,
+                    group_threshold: parameters.group_threshold,
+                    group_count: group_count as u8,
+                    member_index: member_index as u8,
+                    member_threshold: group.member_threshold,
+                    value: value.to_vec(),
+                })
+                .collect(),
+        );
+    }
+    Ok(groups)
+}
+
+/// Master secret from exactly the threshold number of groups, each with
+/// exactly its member threshold of shares. Repeated identical shares are
+/// ignored.
+pub fn combine_shares(
+    shares: &[Share],
+    passphrase: &str,
+) -> Result<Zeroizing<Vec<u8>>, ErrorShare> {
+    let first = shares.first().ok_or(ErrorShare::NoShares)?;
+
+    let mut groups: Vec<(u8, Vec<&Share>)> = Vec::new();
+    for share in shares.iter() {
+        if !share.same_split(first) {
+            return Err(ErrorShare::MismatchedShares);
+        }
+        let members = match groups
+            .iter_mut()
+            .find(|(group_index, _)| *group_index == share.group_index)
+        {
+            Some((_, members)) => members,
+            None => {
+                groups.push((share.group_index, Vec::new()));
+                &mut groups.last_mut().expect("just pushed").1
+            }
+        };
+        if members
+            .iter()
+            .any(|member| member.member_threshold != share.member_threshold)
+        {
+            return Err(ErrorShare::MismatchedShares);
+        }
+        match members
+            .iter()
+            .find(|member| member.member_index == share.member_index)
+        {
+            Some(member) if member.value == share.value => {}
+            Some(_) => return Err(ErrorShare::DuplicateIndex),
+            None => members.push(share),
+        }
+    }
+
+    if groups.len() != first.group_threshold as usize {
+        return Err(ErrorShare::ShareCount);
+    }
+    let mut group_secrets: Vec<(u8, Zeroizing<Vec<u8>>)> = Vec::with_capacity(groups.len());
+    for (group_index, members) in groups.iter() {
+        let member_threshold = members[0].member_threshold;
+        if members.len() != member_threshold as usize {
+            return Err(ErrorShare::ShareCount);
+        }
+        let points: Vec<(u8, &[u8])> = members
+            .iter()
+            .map(|member| (member.member_index, member.value.as_slice()))
+            .collect();
+        group_secrets.push((*group_index, recover_secret(member_threshold, &points)?));
+    }
+
+    let points: Vec<(u8, &[u8])> = group_secrets
+        .iter()
+        .map(|(group_index, secret)| (*group_index, secret.as_slice()))
+        .collect();
+    let encrypted = recover_secret(first.group_threshold, &points)?;
+    feistel(
+        &encrypted,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        false,
+    )
+}
+
+#[cfg(feature = "generate")]
+fn check_secret_len(len: usize) -> Result<(), ErrorShare> {
+    if !(MIN_SECRET_LEN..=MAX_SECRET_LEN).contains(&len) || len % 2 != 0 {
+        Err(ErrorShare::SecretLength)
+    } else {
+        Ok(())
+    }
+}
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_EXTENDABLE
+    } else {
+        CUSTOMIZATION
+    }
+}
+
+// Reed-Solomon code over GF(1024), with the customization string prepended
+// to the words.
+fn rs1024_polymod(customization: &[u8], words: impl Iterator<Item = u16>) -> u32 {
+    const GEN: [u32; 10] = [
+        0x00E0E040, 0x01C1C080, 0x03838100, 0x07070200, 0x0E0E0009, 0x1C0C2412, 0x38086C24,
+        0x3090FC48, 0x21B1F890, 0x03F3F120,
+    ];
+    let mut chk: u32 = 1;
+    for value in customization.iter().map(|byte| *byte as u16).chain(words) {
+        let top = chk >> 20;
+        chk = (chk & 0xFFFFF) << BITS_IN_U10 ^ value as u32;
+        for (i, generator) in GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+// Big-endian bits of `value`, left-padded with zeros to whole words.
+fn pack_value(value: &[u8], out: &mut Vec<Bits10>) {
+    let word_count = (value.len() * 8).div_ceil(BITS_IN_U10);
+    let mut acc: u32 = 0;
+    let mut acc_bits = word_count * BITS_IN_U10 - value.len() * 8;
+    for byte in value.iter() {
+        acc = acc << 8 | *byte as u32;
+        acc_bits += 8;
+        if acc_bits >= BITS_IN_U10 {
+            acc_bits -= BITS_IN_U10;
+            out.push(Bits10((acc >> acc_bits) as u16));
+            acc &= (1 << acc_bits) - 1;
+        }
+    }
+    acc.zeroize();
+}
+
+fn unpack_value(words: &[Bits10], padding_len: usize) -> Result<Vec<u8>, ErrorShare> {
+    let mut value = Vec::with_capacity((words.len() * BITS_IN_U10 - padding_len) / 8);
+    let mut acc: u32 = 0;
+    let mut acc_bits = 0;
+    let mut skip = padding_len;
+    for word in words.iter() {
+        acc = acc << BITS_IN_U10 | word.0 as u32;
+        acc_bits += BITS_IN_U10;
+        if skip != 0 {
+            acc_bits -= skip;
+            skip = 0;
+            if acc >> acc_bits != 0 {
+                acc.zeroize();
+                value.zeroize();
+                return Err(ErrorShare::Padding);
+            }
+        }
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            value.push((acc >> acc_bits) as u8);
+            acc &= (1 << acc_bits) - 1;
+        }
+    }
+    acc.zeroize();
+    Ok(value)
+}
+
+// Multiplication in GF(256) modulo x^8 + x^4 + x^3 + x + 1, without secret
+// dependent branches or table lookups.
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1B & carry);
+        b >>= 1;
+    }
+    product
+}
+
+// `a^254`, the inverse of a non-zero `a`.
+fn gf256_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut power = a;
+    let mut exponent = 254u8;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = gf256_mul(result, power);
+        }
+        power = gf256_mul(power, power);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Value at `x` of the polynomials through `points`, byte by byte. Only the
+// x coordinates, which are public, decide the Lagrange basis.
+fn interpolate(points: &[(u8, &[u8])], x: u8) -> Result<Zeroizing<Vec<u8>>, ErrorShare> {
+    let len = points.first().ok_or(ErrorShare::NoShares)?.1.len();
+    for (i, (xi, value)) in points.iter().enumerate() {
+        if value.len() != len {
+            return Err(ErrorShare::MismatchedShares);
+        }
+        if points[..i].iter().any(|(xj, _)| xj == xi) {
+            return Err(ErrorShare::DuplicateIndex);
+        }
+    }
+    if let Some((_, value)) = points.iter().find(|(xi, _)| *xi == x) {
+        return Ok(Zeroizing::new(value.to_vec()));
+    }
+
+    let mut result = Zeroizing::new(vec![0u8; len]);
+    for (i, (xi, value)) in points.iter().enumerate() {
+        let mut numerator = 1u8;
+        let mut denominator = 1u8;
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                numerator = gf256_mul(numerator, x ^ xj);
+                denominator = gf256_mul(denominator, xi ^ xj);
+            }
+        }
+        let basis = gf256_mul(numerator, gf256_inv(denominator));
+        for (out, byte) in result.iter_mut().zip(value.iter()) {
+            *out ^= gf256_mul(*byte, basis);
+        }
+    }
+    Ok(result)
+}
+
+fn digest(random_part: &[u8], secret: &[u8]) -> Result<[u8; DIGEST_LEN], ErrorShare> {
+    let mut mac =
+        <Hmac<Sha256> as Mac>::new_from_slice(random_part).map_err(|_| ErrorShare::Encryption)?;
+    mac.update(secret);
+    let mut full = <[u8; 32]>::from(mac.finalize().into_bytes());
+    let mut out = [0u8; DIGEST_LEN];
+    out.copy_from_slice(&full[..DIGEST_LEN]);
+    full.zeroize();
+    Ok(out)
+}
+
+#[cfg(feature = "generate")]
+fn split_secret<R: CryptoRngCore + ?Sized>(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+    rng: &mut R,
+) -> Result<Vec<Zeroizing<Vec<u8>>>, ErrorShare> {
+    if threshold == 1 {
+        return Ok((0..count)
+            .map(|_| Zeroizing::new(secret.to_vec()))
+            .collect());
+    }
+    let mut values: Vec<Zeroizing<Vec<u8>>> = Vec::with_capacity(count as usize);
+    for _ in 0..threshold - 2 {
+        let mut value = Zeroizing::new(vec![0u8; secret.len()]);
+        rng.fill_bytes(&mut value);
+        values.push(value);
+    }
+    let mut digest_value = Zeroizing::new(vec![0u8; secret.len()]);
+    rng.fill_bytes(&mut digest_value[DIGEST_LEN..]);
+    let digest = digest(&digest_value[DIGEST_LEN..], secret)?;
+    digest_value[..DIGEST_LEN].copy_from_slice(&digest);
+
+    for x in threshold - 2..count {
+        let mut points: Vec<(u8, &[u8])> = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (i as u8, value.as_slice()))
+            .collect();
+        points.push((DIGEST_INDEX, &digest_value));
+        points.push((SECRET_INDEX, secret));
+        let value = interpolate(&points, x)?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+fn recover_secret(threshold: u8, points: &[(u8, &[u8])]) -> Result<Zeroizing<Vec<u8>>, ErrorShare> {
+    if threshold == 1 {
+        let (_, value) = points.first().ok_or(ErrorShare::NoShares)?;
+        return Ok(Zeroizing::new(value.to_vec()));
+    }
+    let secret = interpolate(points, SECRET_INDEX)?;
+    let digest_value = interpolate(points, DIGEST_INDEX)?;
+    if digest_value.len() < DIGEST_LEN {
+        return Err(ErrorShare::Digest);
+    }
+    let expected = digest(&digest_value[DIGEST_LEN..], &secret)?;
+    let mut difference = 0u8;
+    for (a, b) in expected.iter().zip(digest_value.iter()) {
+        difference |= a ^ b;
+    }
+    if difference != 0 {
+        return Err(ErrorShare::Digest);
+    }
+    Ok(secret)
+}
+
+// Four-round Feistel network over the two halves of the secret, each round
+// function a PBKDF2-HMAC-SHA256 keyed with the round number and passphrase.
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    encrypt: bool,
+) -> Result<Zeroizing<Vec<u8>>, ErrorShare> {
+    let half = input.len() / 2;
+    let mut left = Zeroizing::new(input[..half].to_vec());
+    let mut right = Zeroizing::new(input[half..].to_vec());
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+
+    let mut salt: Zeroizing<Vec<u8>> = Zeroizing::new(Vec::new());
+    if !extendable {
+        salt.extend_from_slice(CUSTOMIZATION);
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let salt_len = salt.len();
+
+    let mut key = Zeroizing::new(Vec::with_capacity(passphrase.len() + 1));
+    key.push(0);
+    key.extend_from_slice(passphrase);
+
+    for i in 0..ROUND_COUNT {
+        let round = if encrypt { i } else { ROUND_COUNT - 1 - i };
+        key[0] = round;
+        salt.truncate(salt_len);
+        salt.extend_from_slice(&right);
+        let mut round_output = Zeroizing::new(vec![0u8; right.len()]);
+        pbkdf2_hmac_sha256(&key, &salt, iterations, &mut round_output)?;
+        for (out, byte) in round_output.iter_mut().zip(left.iter()) {
+            *out ^= byte;
+        }
+        core::mem::swap(&mut left, &mut right);
+        right = round_output;
+    }
+
+    let mut output = Zeroizing::new(Vec::with_capacity(input.len()));
+    output.extend_from_slice(&right);
+    output.extend_from_slice(&left);
+    Ok(output)
+}
+
+fn pbkdf2_hmac_sha256(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    out: &mut [u8],
+) -> Result<(), ErrorShare> {
+    let keyed =
+        <Hmac<Sha256> as Mac>::new_from_slice(password).map_err(|_| ErrorShare::Encryption)?;
+    for (block_index, out_chunk) in out.chunks_mut(32).enumerate() {
+        let mut mac = keyed.clone();
+        mac.update(salt);
+        mac.update(&(block_index as u32 + 1).to_be_bytes());
+        let mut block = Zeroizing::new(<[u8; 32]>::from(mac.finalize().into_bytes()));
+        let mut sum = Zeroizing::new(*block);
+        for _ in 1..iterations {
+            let mut mac = keyed.clone();
+            mac.update(block.as_ref());
+            let mut next = <[u8; 32]>::from(mac.finalize().into_bytes());
+            block.copy_from_slice(&next);
+            next.zeroize();
+            for (sum_byte, block_byte) in sum.iter_mut().zip(block.iter()) {
+                *sum_byte ^= block_byte;
+            }
+        }
+        out_chunk.copy_from_slice(&sum[..out_chunk.len()]);
+    }
+    Ok(())
+}
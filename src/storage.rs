@@ -0,0 +1,251 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(all(feature = "embedded-storage", feature = "nfkd"))]
+use core::cell::Cell;
+use core::cell::RefCell;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+#[cfg(feature = "embedded-storage")]
+use core::marker::PhantomData;
+
+use crate::error::ErrorMnemonic;
+use crate::raw::{raw_record_len, raw_record_offset, RawWord, RAW_HEADER_LEN};
+use crate::Bits11;
+
+#[cfg(all(feature = "embedded-storage", feature = "nfkd"))]
+use crate::has_accents_cached;
+#[cfg(feature = "embedded-storage")]
+use crate::raw::{raw_bits11_for_word, raw_words_by_prefix};
+#[cfg(feature = "embedded-storage")]
+use crate::{AsWordList, WordIndex, WordListElement};
+
+#[cfg(feature = "embedded-storage-async")]
+use crate::async_wordlist::{AsWordListAsync, WordListElementAsync};
+#[cfg(feature = "embedded-storage-async")]
+use crate::raw::{raw_bits11_for_word_async, raw_words_by_prefix_async};
+
+// Word lists in the `raw` blob format on a flash device, read through the
+// `embedded-storage` traits. The blob may sit anywhere on the device, at
+// `address`. Lookups scan the records as `RawWordList` does.
+//
+// The traits read through `&mut self` while lookups take `&self`, so the
+// device sits in a `RefCell`. A lookup made while another one holds the
+// device, e.g. from a second task on the same executor, fails with
+// `WordListBusy` rather than waiting.
+
+fn storage_offset(address: u32, offset: usize) -> Result<u32, ErrorMnemonic> {
+    u32::try_from(offset)
+        .ok()
+        .and_then(|offset| address.checked_add(offset))
+        .ok_or(ErrorMnemonic::DamagedWord)
+}
+
+/// Blob word list on a blocking [`embedded_storage::ReadStorage`] device:
+///
+/// ```ignore
+/// let wordlist = StorageWordList::new(flash, WORDLIST_ADDRESS)?;
+/// let word_set = WordSet::from_phrase(phrase, &wordlist)?;
+/// ```
+///
+/// Device errors are `DamagedWord`. A blob of another list length is read
+/// with `StorageWordList::<_, I>::new_indexed`, e.g. `I = MoneroWordIndex`.
+#[cfg(feature = "embedded-storage")]
+pub struct StorageWordList<S, I: WordIndex = Bits11> {
+    storage: RefCell<S>,
+    address: u32,
+    record_len: usize,
+    #[cfg(feature = "nfkd")]
+    accents: Cell<Option<bool>>,
+    index: PhantomData<I>,
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<S: embedded_storage::ReadStorage> StorageWordList<S> {
+    /// Reads and checks the blob header at `address`; `WordListFormat` if it
+    /// does not match.
+    pub fn new(storage: S, address: u32) -> Result<Self, ErrorMnemonic> {
+        Self::new_indexed(storage, address)
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<S: embedded_storage::ReadStorage, I: WordIndex> StorageWordList<S, I> {
+    /// As `new`, for a blob of a list indexed by `I`.
+    pub fn new_indexed(mut storage: S, address: u32) -> Result<Self, ErrorMnemonic> {
+        let mut header = [0u8; RAW_HEADER_LEN];
+        storage
+            .read(address, &mut header)
+            .map_err(|_| ErrorMnemonic::DamagedWord)?;
+        let record_len = raw_record_len(&header)?;
+        Ok(Self {
+            storage: RefCell::new(storage),
+            address,
+            record_len,
+            #[cfg(feature = "nfkd")]
+            accents: Cell::new(None),
+            index: PhantomData,
+        })
+    }
+
+    pub fn record_len(&self) -> usize {
+        self.record_len
+    }
+
+    /// The device back, e.g. to write to it.
+    pub fn into_inner(self) -> S {
+        self.storage.into_inner()
+    }
+
+    fn read_record(&self, bits: I, record: &mut RawWord) -> Result<(), ErrorMnemonic> {
+        let offset = storage_offset(self.address, raw_record_offset(bits, self.record_len))?;
+        self.storage
+            .try_borrow_mut()
+            .map_err(|_| ErrorMnemonic::WordListBusy)?
+            .read(offset, record.buffer(self.record_len))
+            .map_err(|_| ErrorMnemonic::DamagedWord)?;
+        record.settle(self.record_len)
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<S, I: WordIndex> Debug for StorageWordList<S, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "StorageWordList {{ address: {:#x}, record_len: {} }}",
+            self.address, self.record_len
+        )
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<S: embedded_storage::ReadStorage, I: WordIndex> AsWordList<I> for StorageWordList<S, I> {
+    type Word = RawWord;
+
+    fn get_word(&self, bits: I) -> Result<Self::Word, ErrorMnemonic> {
+        let mut word = RawWord::new();
+        self.read_record(bits, &mut word)?;
+        Ok(word)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElement<Self, I>>, ErrorMnemonic> {
+        raw_words_by_prefix(prefix, |bits11, record| self.read_record(bits11, record))
+    }
+
+    fn bits11_for_word(&self, word: &str) -> Result<I, ErrorMnemonic> {
+        raw_bits11_for_word(word, self.record_len, |bits11, record| {
+            self.read_record(bits11, record)
+        })
+    }
+
+    #[cfg(feature = "nfkd")]
+    fn has_accents(&self) -> bool {
+        has_accents_cached(self, &self.accents)
+    }
+}
+
+/// Blob word list on an async [`embedded_storage_async::ReadStorage`]
+/// device, e.g. an embassy flash driver:
+///
+/// ```ignore
+/// let wordlist = StorageWordListAsync::new(flash, WORDLIST_ADDRESS).await?;
+/// word_set.add_word_async(word, &wordlist).await?;
+/// ```
+///
+/// Device errors are `DamagedWord`.
+#[cfg(feature = "embedded-storage-async")]
+pub struct StorageWordListAsync<S> {
+    storage: RefCell<S>,
+    address: u32,
+    record_len: usize,
+}
+
+#[cfg(feature = "embedded-storage-async")]
+impl<S: embedded_storage_async::ReadStorage> StorageWordListAsync<S> {
+    /// Reads and checks the blob header at `address`; `WordListFormat` if it
+    /// does not match.
+    pub async fn new(mut storage: S, address: u32) -> Result<Self, ErrorMnemonic> {
+        let mut header = [0u8; RAW_HEADER_LEN];
+        storage
+            .read(address, &mut header)
+            .await
+            .map_err(|_| ErrorMnemonic::DamagedWord)?;
+        let record_len = raw_record_len(&header)?;
+        Ok(Self {
+            storage: RefCell::new(storage),
+            address,
+            record_len,
+        })
+    }
+
+    pub fn record_len(&self) -> usize {
+        self.record_len
+    }
+
+    /// The device back, e.g. to write to it.
+    pub fn into_inner(self) -> S {
+        self.storage.into_inner()
+    }
+
+    // held across the read on purpose: the device is in use until the read
+    // ends, and other lookups get `WordListBusy` from `try_borrow_mut`
+    // meanwhile rather than a panic
+    #[allow(clippy::await_holding_refcell_ref)]
+    async fn read_record(&self, bits: Bits11, record: &mut RawWord) -> Result<(), ErrorMnemonic> {
+        let offset = storage_offset(self.address, raw_record_offset(bits, self.record_len))?;
+        let mut storage = self
+            .storage
+            .try_borrow_mut()
+            .map_err(|_| ErrorMnemonic::WordListBusy)?;
+        storage
+            .read(offset, record.buffer(self.record_len))
+            .await
+            .map_err(|_| ErrorMnemonic::DamagedWord)?;
+        record.settle(self.record_len)
+    }
+}
+
+#[cfg(feature = "embedded-storage-async")]
+impl<S> Debug for StorageWordListAsync<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "StorageWordListAsync {{ address: {:#x}, record_len: {} }}",
+            self.address, self.record_len
+        )
+    }
+}
+
+#[cfg(feature = "embedded-storage-async")]
+impl<S: embedded_storage_async::ReadStorage> AsWordListAsync for StorageWordListAsync<S> {
+    type Word = RawWord;
+
+    async fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        let mut word = RawWord::new();
+        self.read_record(bits, &mut word).await?;
+        Ok(word)
+    }
+
+    async fn get_words_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<WordListElementAsync<Self>>, ErrorMnemonic> {
+        raw_words_by_prefix_async(prefix, async |bits11, record| {
+            self.read_record(bits11, record).await
+        })
+        .await
+    }
+
+    async fn bits11_for_word(&self, word: &str) -> Result<Bits11, ErrorMnemonic> {
+        raw_bits11_for_word_async(word, self.record_len, async |bits11, record| {
+            self.read_record(bits11, record).await
+        })
+        .await
+    }
+}
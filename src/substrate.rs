@@ -0,0 +1,49 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use crate::error::ErrorMnemonic;
+use crate::seed::{pbkdf2_hmac_sha512, SALT_PREFIX, SEED_LEN};
+use crate::WordSet;
+
+pub const MINI_SECRET_LEN: usize = 32;
+
+/// Substrate mini-secret, as `substrate-bip39` derives it.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct MiniSecret([u8; MINI_SECRET_LEN]);
+
+impl MiniSecret {
+    pub fn as_bytes(&self) -> &[u8; MINI_SECRET_LEN] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for MiniSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for MiniSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "MiniSecret(..)")
+    }
+}
+
+impl WordSet {
+    /// Substrate mini-secret: PBKDF2-HMAC-SHA512 over the entropy rather than
+    /// the phrase, salted with `"mnemonic"` and the passphrase, truncated to
+    /// 32 bytes. The passphrase is used as is, without NFKD, to match
+    /// `substrate-bip39`.
+    pub fn to_mini_secret(&self, passphrase: &str) -> Result<MiniSecret, ErrorMnemonic> {
+        let entropy = self.to_entropy_secret()?;
+
+        let salt = Zeroizing::new([SALT_PREFIX.as_bytes(), passphrase.as_bytes()].concat());
+        let mut seed = Zeroizing::new([0u8; SEED_LEN]);
+        pbkdf2_hmac_sha512(&entropy, &salt, &mut seed)?;
+
+        let mut mini_secret = MiniSecret([0u8; MINI_SECRET_LEN]);
+        mini_secret.0.copy_from_slice(&seed[..MINI_SECRET_LEN]);
+        Ok(mini_secret)
+    }
+}
@@ -0,0 +1,224 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "test-utils")]
+use proptest::{collection::vec, prelude::*, sample::select};
+
+use crate::error::ErrorMnemonic;
+use crate::weak::BIP39_VECTOR_ENTROPY;
+use crate::MnemonicType;
+
+#[cfg(feature = "test-utils")]
+use crate::WordSet;
+
+/// Entropy of the length required by `mnemonic_type`, holding `value` as a
+/// big-endian number zero-padded on the left. For table-driven tests only.
+pub fn entropy_from_u128(
+    value: u128,
+    mnemonic_type: MnemonicType,
+) -> Result<Vec<u8>, ErrorMnemonic> {
+    let entropy_len = mnemonic_type.entropy_bytes();
+    let value_bytes = value.to_be_bytes();
+
+    // shortest entropy is 16 bytes, so `u128` always fits
+    let mut entropy: Vec<u8> = Vec::with_capacity(entropy_len);
+    entropy.resize(entropy_len - value_bytes.len(), 0);
+    entropy.extend_from_slice(&value_bytes);
+    Ok(entropy)
+}
+
+/// Official BIP-39 test vector for the English list, all hex lowercase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bip39Vector {
+    pub entropy: &'static str,
+    pub phrase: &'static str,
+    /// Seed with the passphrase [`BIP39_VECTOR_PASSPHRASE`].
+    pub seed: &'static str,
+}
+
+pub const BIP39_VECTOR_PASSPHRASE: &str = "TREZOR";
+
+/// The English vectors published with BIP-39, in their original order.
+pub const BIP39_ENGLISH_VECTORS: &[Bip39Vector] = &[
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[0],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        seed: "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[1],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        seed: "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[2],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        seed: "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[3],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        seed: "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[4],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        seed: "035895f2f481b1b0f01fcf8c289c794660b289981a78f8106447707fdd9666ca06da5a9a565181599b79f53b844d8a71dd9f439c52a3d7b3e8a79c906ac845fa",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[5],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+        seed: "f2b94508732bcbacbcc020faefecfc89feafa6649a5491b8c952cede496c214a0c7b3c392d168748f2d4a612bada0753b52a1c7ac53c1e93abd5c6320b9e95dd",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[6],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+        seed: "107d7c02a5aa6f38c58083ff74f04c607c2d2c0ecc55501dadd72d025b751bc27fe913ffb796f841c49b1d33b610cf0e91d3aa239027f5e99fe4ce9e5088cd65",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[7],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+        seed: "0cd6e5d827bb62eb8fc1e262254223817fd068a74b5b449cc2f667c3f1f985a76379b43348d952e2265b4cd129090758b3e3c2c49103b5051aac2eaeb890a528",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[8],
+        phrase: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        seed: "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[9],
+        phrase: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        seed: "bc09fca1804f7e69da93c2f2028eb238c227f2e9dda30cd63699232578480a4021b146ad717fbb7e451ce9eb835f43620bf5c514db0f8add49f5d121449d3e87",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[10],
+        phrase: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        seed: "c0c519bd0e91a2ed54357d9d1ebef6f5af218a153624cf4f2da911a0ed8f7a09e2ef61af0aca007096df430022f7a2b6fb91661a9589097069720d015e4e982f",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[11],
+        phrase: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        seed: "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[12],
+        phrase: "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+        seed: "274ddc525802f7c828d8ef7ddbcdc5304e87ac3535913611fbbfa986d0c9e5476c91689f9c8a54fd55bd38606aa6a8595ad213d4c9c9f9aca3fb217069a41028",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[13],
+        phrase: "gravity machine north sort system female filter attitude volume fold club stay feature office ecology stable narrow fog",
+        seed: "628c3827a8823298ee685db84f55caa34b5cc195a778e52d45f59bcf75aba68e4d7590e101dc414bc1bbd5737666fbbef35d1f1903953b66624f910feef245ac",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[14],
+        phrase: "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        seed: "64c87cde7e12ecf6704ab95bb1408bef047c22db4cc7491c4271d170a1b213d20b385bc1588d9c7b38f1b39d415665b8a9030c9ec653d75e65f847d8fc1fc440",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[15],
+        phrase: "scheme spot photo card baby mountain device kick cradle pact join borrow",
+        seed: "ea725895aaae8d4c1cf682c1bfd2d358d52ed9f0f0591131b559e2724bb234fca05aa9c02c57407e04ee9dc3b454aa63fbff483a8b11de949624b9f1831a9612",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[16],
+        phrase: "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap uncle crack brave",
+        seed: "fd579828af3da1d32544ce4db5c73d53fc8acc4ddb1e3b251a31179cdb71e853c56d2fcb11aed39898ce6c34b10b5382772db8796e52837b54468aeb312cfc3d",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[17],
+        phrase: "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+        seed: "72be8e052fc4919d2adf28d5306b5474b0069df35b02303de8c1729c9538dbb6fc2d731d5f832193cd9fb6aeecbc469594a70e3dd50811b5067f3b88b28c3e8d",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[18],
+        phrase: "cat swing flag economy stadium alone churn speed unique patch report train",
+        seed: "deb5f45449e615feff5640f2e49f933ff51895de3b4381832b3139941c57b59205a42480c52175b6efcffaa58a2503887c1e8b363a707256bdd2b587b46541f5",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[19],
+        phrase: "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart sustain crack supply proud access",
+        seed: "4cbdff1ca2db800fd61cae72a57475fdc6bab03e441fd63f96dabd1f183ef5b782925f00105f318309a7e9c3ea6967c7801e46c8a58082674c860a37b93eda02",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[20],
+        phrase: "all hour make first leader extend hole alien behind guard gospel lava path output census museum junior mass reopen famous sing advance salt reform",
+        seed: "26e975ec644423f4a4c4f4215ef09b4bd7ef924e85d1d17c4cf3f136c2863cf6df0a475045652c57eb5fb41513ca2a2d67722b77e954b4b3fc11f7590449191d",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[21],
+        phrase: "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+        seed: "2aaa9242daafcee6aa9d7269f17d4efe271e1b9a529178d7dc139cd18747090bf9d60295d0ce74309a78852a9caadf0af48aae1c6253839624076224374bc63f",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[22],
+        phrase: "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little brisk hair mango congress clump",
+        seed: "7b4a10be9d98e6cba265566db7f136718e1398c71cb581e1b2f464cac1ceedf4f3e274dc270003c670ad8d02c4558b2f8e39edea2775c9e232c7cb798b069e88",
+    },
+    Bip39Vector {
+        entropy: BIP39_VECTOR_ENTROPY[23],
+        phrase: "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+        seed: "01f5bced59dec48e362f2c45b5de68b9fd6c92c6634f44d6d40aab69056506f0e35524a518034ddc1192e1dacd32c1ed3eaa3c3b131c88ed8e7e54c49a5d0998",
+    },
+];
+
+// Proptest strategies for fuzzing code built on the crate.
+
+#[cfg(feature = "test-utils")]
+pub fn arb_mnemonic_type() -> impl Strategy<Value = MnemonicType> {
+    select(&MnemonicType::ALL[..])
+}
+
+/// Entropy of any valid length.
+#[cfg(feature = "test-utils")]
+pub fn arb_entropy() -> impl Strategy<Value = Vec<u8>> {
+    arb_mnemonic_type()
+        .prop_flat_map(|mnemonic_type| vec(any::<u8>(), mnemonic_type.entropy_bytes()))
+}
+
+/// Complete set with a valid checksum.
+#[cfg(feature = "test-utils")]
+pub fn arb_word_set() -> impl Strategy<Value = WordSet> {
+    arb_entropy()
+        .prop_map(|entropy| WordSet::from_entropy(&entropy).expect("entropy of a valid length"))
+}
+
+/// Complete set with a wrong checksum: the checksum bits of the last word
+/// are changed, so every other word and the entropy before them stay valid.
+#[cfg(feature = "test-utils")]
+pub fn arb_bad_checksum() -> impl Strategy<Value = WordSet> {
+    arb_word_set().prop_flat_map(|word_set| {
+        let checksum_bits = word_set
+            .mnemonic_type()
+            .expect("complete set")
+            .checksum_bits();
+        (Just(word_set), 1..1u16 << checksum_bits).prop_map(|(word_set, flip)| {
+            let mut indices = word_set.to_indices();
+            *indices.last_mut().expect("complete set") ^= flip;
+            WordSet::from_indices(&indices).expect("indices in range")
+        })
+    })
+}
+
+/// Valid set with two different words swapped, keeping only the swaps that
+/// break the checksum, as a checksum exists to catch.
+#[cfg(feature = "test-utils")]
+pub fn arb_swapped_words() -> impl Strategy<Value = WordSet> {
+    arb_word_set()
+        .prop_flat_map(|word_set| {
+            let len = word_set.len();
+            (Just(word_set), 0..len, 0..len)
+        })
+        .prop_filter_map("swap must break the checksum", |(word_set, i, j)| {
+            let mut indices = word_set.to_indices();
+            if indices[i] == indices[j] {
+                return None;
+            }
+            indices.swap(i, j);
+            let swapped = WordSet::from_indices(&indices).ok()?;
+            swapped.validate().is_err().then_some(swapped)
+        })
+}
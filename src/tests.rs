@@ -1,16 +1,43 @@
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
 
 #[cfg(feature = "std")]
-use std::{string::String, vec::Vec};
+use std::{format, string::String, vec::Vec};
 
-use crate::error::ErrorMnemonic;
+#[cfg(not(feature = "serde"))]
+use serde_json as _;
 
-#[cfg(feature = "sufficient-memory")]
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::confirm::ConfirmSession;
+use crate::ct::{ct_eq_bytes, ct_eq_u16};
+use crate::custom::{audit_wordlist, CustomWordList, SliceWordList, StaticWordList};
+
+#[cfg(feature = "std")]
+use crate::custom::PrecomputedWordList;
+use crate::distance::bounded_edit_distance;
+use crate::error::{ErrorMnemonic, WordlistAudit};
+use crate::fixed::WordSetFixed;
+use crate::raw::{
+    raw_wordlist_blob, RawWordList, RAW_FORMAT_VERSION, RAW_HEADER_LEN, RAW_MAX_RECORD_LEN,
+};
+
+#[cfg(feature = "electrum")]
+use crate::electrum::ElectrumSeedType;
+#[cfg(feature = "slip39")]
+use crate::error::ErrorShare;
+
+#[cfg(feature = "english-wordlist")]
 use crate::regular::InternalWordList;
 
+use crate::testing::{entropy_from_u128, BIP39_ENGLISH_VECTORS, BIP39_VECTOR_PASSPHRASE};
 use crate::wordlist::WORDLIST_ENGLISH;
-use crate::{AsWordList, Bits11, WordListElement, WordSet, TOTAL_WORDS, WORD_MAX_LEN};
+use crate::{
+    canonical_word, capabilities, check_phrase, phrase_from_entropy_not_seed, resolve_word,
+    validate_opaque, AsWordList, Bits11, ByteOrder, ChecksumHasher, Entropy, EntryProgress,
+    MnemonicType, NextChars, ResolveMode, Sha2Hasher, WordListElement, WordSet, BITS_IN_BYTE,
+    BITS_IN_U11, MAX_ENTROPY_LEN, MAX_SEED_LEN, SEPARATOR_LEN, TOTAL_WORDS, WORD_MAX_LEN,
+};
 
 static mut FLASH_MOCK: [u8; TOTAL_WORDS * WORD_MAX_LEN] = [255u8; TOTAL_WORDS * WORD_MAX_LEN];
 
@@ -167,6 +194,31 @@ static KNOWN: &[[&str; 2]] = &[
     ]
 ];
 
+// only the first 8 words are readable
+struct ShortWordList;
+
+impl AsWordList for ShortWordList {
+    type Word = &'static str;
+
+    fn get_word(&self, bits: Bits11) -> Result<Self::Word, ErrorMnemonic> {
+        WORDLIST_ENGLISH[..8]
+            .get(bits.bits() as usize)
+            .copied()
+            .ok_or(ErrorMnemonic::DamagedWord)
+    }
+
+    fn get_words_by_prefix(
+        &self,
+        _prefix: &str,
+    ) -> Result<Vec<WordListElement<Self>>, ErrorMnemonic> {
+        Ok(Vec::new())
+    }
+
+    fn bits11_for_word(&self, _word: &str) -> Result<Bits11, ErrorMnemonic> {
+        Err(ErrorMnemonic::NoWord)
+    }
+}
+
 #[test]
 fn flash_mock_entropy_to_phrase() {
     fill_flash_mock();
@@ -178,7 +230,7 @@ fn flash_mock_entropy_to_phrase() {
     }
 }
 
-#[cfg(feature = "sufficient-memory")]
+#[cfg(feature = "english-wordlist")]
 #[test]
 fn internal_entropy_to_phrase() {
     let internal_word_list = InternalWordList;
@@ -204,7 +256,7 @@ fn flash_mock_phrase_to_entropy() {
     }
 }
 
-#[cfg(feature = "sufficient-memory")]
+#[cfg(feature = "english-wordlist")]
 #[test]
 fn internal_phrase_to_entropy() {
     let internal_word_list = InternalWordList;
@@ -248,3 +300,4360 @@ fn flash_mock_get_word() {
             .unwrap()
     );
 }
+
+#[cfg(all(feature = "bip32", feature = "english-wordlist"))]
+#[test]
+fn master_fingerprint_matches() {
+    let internal_word_list = InternalWordList;
+    let mut word_set = WordSet::new();
+    for word in KNOWN[0][0].split(' ') {
+        word_set.add_word(word, &internal_word_list).unwrap();
+    }
+    let fingerprint: [u8; 4] = hex::decode("73c5da0a").unwrap().try_into().unwrap();
+    assert!(word_set
+        .matches_fingerprint(&internal_word_list, "", fingerprint)
+        .unwrap());
+    assert!(!word_set
+        .matches_fingerprint(&internal_word_list, "TREZOR", fingerprint)
+        .unwrap());
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn bip32_serializes_master_and_children() {
+    use crate::bip32::{ExtendedPrivateKey, Network};
+
+    // BIP-32 test vector 1, and m/0'/1' of it
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let master = ExtendedPrivateKey::from_seed(&seed).unwrap();
+    assert_eq!(master.depth(), 0);
+    assert_eq!(
+        master.to_xprv(Network::Mainnet).as_str(),
+        "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+    );
+    assert_eq!(
+        master.to_xprv(Network::Testnet).as_str(),
+        "tprv8ZgxMBicQKsPeDgjzdC36fs6bMjGApWDNLR9erAXMs5skhMv36j9MV5ecvfavji5khqjWaWSFhN3YcCUUdiKH6isR4Pwy3U5y5egddBr16m"
+    );
+    let child = master.derive_hardened(0).unwrap();
+    let xprv = "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7";
+    assert_eq!(child.to_xprv(Network::Mainnet).as_str(), xprv);
+    let parsed = ExtendedPrivateKey::from_xprv(xprv).unwrap();
+    assert_eq!(parsed.depth(), 1);
+    assert_eq!(parsed.to_xprv(Network::Mainnet).as_str(), xprv);
+    assert_eq!(
+        parsed
+            .derive_hardened(1)
+            .unwrap()
+            .to_xprv(Network::Mainnet)
+            .as_str(),
+        master
+            .derive_hardened_path(&[0, 1])
+            .unwrap()
+            .to_xprv(Network::Mainnet)
+            .as_str()
+    );
+    assert_eq!(
+        master.derive_hardened_path(&[0, 1]).unwrap().to_xprv(Network::Mainnet).as_str(),
+        "xprv9wTYmMFmpgaLEfuwVtZ6qznJ89VoDvarNWxAxMfWGXkJc8BewDSaZK6CiDSuSkuwp4YsTHxuKY1JywkBbiTPZsZ7963ZXv8yFTTwwbbLWkK"
+    );
+}
+
+#[cfg(all(feature = "bip32", feature = "english-wordlist"))]
+#[test]
+fn word_set_to_master_key() {
+    use crate::bip32::Network;
+
+    let word_set = WordSet::from_phrase(KNOWN[0][0], &InternalWordList).unwrap();
+    let master = word_set.to_master_key(&InternalWordList, "TREZOR").unwrap();
+    assert_eq!(
+        master.to_xprv(Network::Mainnet).as_str(),
+        "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF"
+    );
+}
+
+#[test]
+fn flash_mock_words_by_prefix_range() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let page = flash_mock_word_list
+        .get_words_by_prefix_range("ab", 2, 3)
+        .unwrap();
+    let words: Vec<String> = page.into_iter().map(|element| element.word).collect();
+    assert_eq!(words, ["able", "about", "above"]);
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_words_by_prefix_range() {
+    let internal_word_list = InternalWordList;
+    for prefix in ["", "a", "ab", "zo", "zoo", "zz"] {
+        let all = internal_word_list.get_words_by_prefix(prefix).unwrap();
+        for (skip, take) in [(0, 0), (0, 5), (2, 3), (10, 100), (5000, 1)] {
+            let page = internal_word_list
+                .get_words_by_prefix_range(prefix, skip, take)
+                .unwrap();
+            let expected: Vec<&str> = all.iter().skip(skip).take(take).map(|e| e.word).collect();
+            let calculated: Vec<&str> = page.iter().map(|e| e.word).collect();
+            assert_eq!(calculated, expected);
+        }
+    }
+}
+
+#[test]
+fn entropy_from_u128_padding() {
+    let entropy = entropy_from_u128(0, MnemonicType::Words12).unwrap();
+    assert_eq!(entropy, hex::decode(KNOWN[0][1]).unwrap());
+    let entropy = entropy_from_u128(u128::MAX, MnemonicType::Words12).unwrap();
+    assert_eq!(entropy, hex::decode(KNOWN[3][1]).unwrap());
+    let entropy = entropy_from_u128(0x0102, MnemonicType::Words24).unwrap();
+    assert_eq!(entropy.len(), 32);
+    assert_eq!(entropy[30..], [0x01, 0x02]);
+    assert!(entropy[..30].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn capabilities_reflect_features() {
+    let capabilities = capabilities();
+    assert_eq!(capabilities.std, cfg!(feature = "std"));
+    assert_eq!(capabilities.english, cfg!(feature = "english-wordlist"));
+    assert_eq!(capabilities.aezeed, cfg!(feature = "aezeed"));
+    assert_eq!(capabilities.defmt, cfg!(feature = "defmt"));
+    assert_eq!(
+        capabilities.embedded_storage,
+        cfg!(feature = "embedded-storage")
+    );
+    assert_eq!(
+        capabilities.embedded_storage_async,
+        cfg!(feature = "embedded-storage-async")
+    );
+    assert_eq!(capabilities.front_coded, cfg!(feature = "front-coded"));
+    assert_eq!(capabilities.ffi, cfg!(feature = "ffi"));
+    assert_eq!(capabilities.wasm, cfg!(feature = "wasm"));
+    assert_eq!(capabilities.uniffi, cfg!(feature = "uniffi"));
+    assert_eq!(
+        capabilities.async_wordlist,
+        cfg!(feature = "async-wordlist")
+    );
+    assert_eq!(
+        capabilities.chinese_simplified,
+        cfg!(feature = "chinese-simplified")
+    );
+    assert_eq!(
+        capabilities.chinese_traditional,
+        cfg!(feature = "chinese-traditional")
+    );
+    assert_eq!(capabilities.czech, cfg!(feature = "czech"));
+    assert_eq!(capabilities.french, cfg!(feature = "french"));
+    assert_eq!(capabilities.italian, cfg!(feature = "italian"));
+    assert_eq!(capabilities.japanese, cfg!(feature = "japanese"));
+    assert_eq!(capabilities.korean, cfg!(feature = "korean"));
+    assert_eq!(capabilities.portuguese, cfg!(feature = "portuguese"));
+    assert_eq!(capabilities.spanish, cfg!(feature = "spanish"));
+    assert_eq!(capabilities.generate, cfg!(feature = "generate"));
+    assert_eq!(capabilities.os_rng, cfg!(feature = "os-rng"));
+    assert_eq!(capabilities.nfkd, cfg!(feature = "nfkd"));
+    assert_eq!(capabilities.seed, cfg!(feature = "seed"));
+    assert_eq!(capabilities.serde, cfg!(feature = "serde"));
+    assert_eq!(capabilities.redact_debug, cfg!(feature = "redact-debug"));
+    assert_eq!(capabilities.substrate, cfg!(feature = "substrate"));
+    assert_eq!(capabilities.bip32, cfg!(feature = "bip32"));
+    assert_eq!(capabilities.bip85, cfg!(feature = "bip85"));
+    assert_eq!(capabilities.cardano, cfg!(feature = "cardano"));
+    assert_eq!(capabilities.electrum, cfg!(feature = "electrum"));
+    assert_eq!(capabilities.monero, cfg!(feature = "monero"));
+    assert_eq!(capabilities.slip39, cfg!(feature = "slip39"));
+    assert_eq!(capabilities.testing, cfg!(feature = "testing"));
+    assert_eq!(capabilities.test_utils, cfg!(feature = "test-utils"));
+    assert_eq!(capabilities.cli, cfg!(feature = "cli"));
+}
+
+#[test]
+fn truncate_entropy_to_shorter_type() {
+    let entropy = hex::decode(KNOWN[20][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    assert_eq!(
+        word_set.truncate_entropy(MnemonicType::Words12).unwrap(),
+        entropy[..16]
+    );
+    assert_eq!(
+        word_set.truncate_entropy(MnemonicType::Words24).unwrap(),
+        entropy
+    );
+
+    let entropy = hex::decode(KNOWN[19][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    assert!(matches!(
+        word_set.truncate_entropy(MnemonicType::Words24),
+        Err(ErrorMnemonic::TruncationTarget)
+    ));
+}
+
+#[test]
+fn slice_word_list_rejects_empty_words() {
+    assert!(SliceWordList::new(&WORDLIST_ENGLISH).is_ok());
+
+    let mut words = WORDLIST_ENGLISH;
+    words[5] = " \t";
+    assert!(matches!(
+        SliceWordList::new(&words),
+        Err(WordlistAudit::EmptyWord { index: 5 })
+    ));
+    words[5] = "";
+    assert!(matches!(
+        audit_wordlist(&words),
+        Err(WordlistAudit::EmptyWord { index: 5 })
+    ));
+    assert!(matches!(
+        audit_wordlist(&WORDLIST_ENGLISH[1..]),
+        Err(WordlistAudit::WrongLength { len: 2047 })
+    ));
+}
+
+#[test]
+fn slice_word_list_round_trip() {
+    let slice_word_list = SliceWordList::new(&WORDLIST_ENGLISH).unwrap();
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        assert_eq!(word_set.to_phrase(&slice_word_list).unwrap(), known[0]);
+    }
+}
+
+#[test]
+fn custom_word_list_round_trip() {
+    let owned: Vec<String> = WORDLIST_ENGLISH
+        .iter()
+        .map(|word| String::from(*word))
+        .collect();
+    let custom_word_list = CustomWordList::from_vec(owned)
+        .unwrap()
+        .with_unique_prefixes()
+        .unwrap();
+    assert_eq!(
+        custom_word_list,
+        CustomWordList::new(&WORDLIST_ENGLISH).unwrap()
+    );
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_phrase(known[0], &custom_word_list).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        assert_eq!(word_set.to_phrase(&custom_word_list).unwrap(), known[0]);
+    }
+    for (bits_u16, word) in WORDLIST_ENGLISH.iter().enumerate() {
+        assert_eq!(
+            custom_word_list.bits11_for_word(word).unwrap().bits() as usize,
+            bits_u16
+        );
+    }
+    assert!(matches!(
+        custom_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    let act = custom_word_list.get_words_by_prefix("act").unwrap();
+    assert_eq!(act.len(), 5);
+    assert_eq!(act[0].bits11.bits(), 19);
+    assert_eq!(custom_word_list.count_by_prefix("zoo").unwrap(), 1);
+    assert_eq!(custom_word_list.count_by_prefix("zz").unwrap(), 0);
+}
+
+#[test]
+fn custom_word_list_rejects_bad_lists() {
+    let mut words = WORDLIST_ENGLISH;
+    words.swap(10, 11);
+    assert!(matches!(
+        CustomWordList::new(&words),
+        Err(WordlistAudit::NotSorted { index: 11 })
+    ));
+    let mut words = WORDLIST_ENGLISH;
+    words[11] = words[10];
+    assert!(matches!(
+        CustomWordList::new(&words),
+        Err(WordlistAudit::DuplicateWord { index: 11 })
+    ));
+    let mut words = WORDLIST_ENGLISH;
+    words[0] = "";
+    assert!(matches!(
+        CustomWordList::new(&words),
+        Err(WordlistAudit::EmptyWord { index: 0 })
+    ));
+    assert!(matches!(
+        CustomWordList::new(&WORDLIST_ENGLISH[1..]),
+        Err(WordlistAudit::WrongLength { len: 2047 })
+    ));
+
+    // "abandon" and "abandoned" are fine until entry by prefix is asked for
+    let mut words = WORDLIST_ENGLISH;
+    words[1] = "abandoned";
+    let custom_word_list = CustomWordList::new(&words).unwrap();
+    assert!(matches!(
+        custom_word_list.with_unique_prefixes(),
+        Err(WordlistAudit::SharedPrefix { index: 1 })
+    ));
+}
+
+#[test]
+fn raw_word_list_round_trip() {
+    let blob = raw_wordlist_blob(&WORDLIST_ENGLISH).unwrap();
+    assert_eq!(blob.len(), RAW_HEADER_LEN + TOTAL_WORDS * WORD_MAX_LEN);
+    let read = |offset: usize, buffer: &mut [u8]| -> Result<(), ()> {
+        let source = blob.get(offset..offset + buffer.len()).ok_or(())?;
+        buffer.copy_from_slice(source);
+        Ok(())
+    };
+    let raw_word_list = RawWordList::new(read).unwrap();
+    assert_eq!(raw_word_list.record_len(), WORD_MAX_LEN);
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_phrase(known[0], &raw_word_list).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        assert_eq!(word_set.to_phrase(&raw_word_list).unwrap(), known[0]);
+    }
+    assert!(matches!(
+        raw_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(raw_word_list.count_by_prefix("act").unwrap(), 5);
+}
+
+#[test]
+fn raw_word_list_rejects_bad_blobs() {
+    let mut blob = raw_wordlist_blob(&WORDLIST_ENGLISH).unwrap();
+    blob[4] = RAW_FORMAT_VERSION + 1;
+    assert!(matches!(
+        RawWordList::new(|offset: usize, buffer: &mut [u8]| -> Result<(), ()> {
+            buffer.copy_from_slice(&blob[offset..offset + buffer.len()]);
+            Ok(())
+        }),
+        Err(ErrorMnemonic::WordListFormat)
+    ));
+    blob[4] = RAW_FORMAT_VERSION;
+
+    // truncated blob: the last record can not be read
+    let truncated = &blob[..blob.len() - 1];
+    let raw_word_list = RawWordList::new(|offset: usize, buffer: &mut [u8]| -> Result<(), ()> {
+        let source = truncated.get(offset..offset + buffer.len()).ok_or(())?;
+        buffer.copy_from_slice(source);
+        Ok(())
+    })
+    .unwrap();
+    assert!(matches!(
+        raw_word_list.get_word(Bits11::from(2047).unwrap()),
+        Err(ErrorMnemonic::DamagedWord)
+    ));
+
+    let mut words = WORDLIST_ENGLISH;
+    let long = "a".repeat(RAW_MAX_RECORD_LEN + 1);
+    words[7] = &long;
+    assert!(matches!(
+        raw_wordlist_blob(&words),
+        Err(WordlistAudit::WordTooLong { index: 7 })
+    ));
+}
+
+#[cfg(feature = "front-coded")]
+static ENGLISH_FRONT_CODED: [u8; crate::front_coded::front_coded_len(&WORDLIST_ENGLISH)] =
+    crate::front_coded::front_coded_blob(&WORDLIST_ENGLISH);
+
+#[cfg(feature = "front-coded")]
+#[test]
+fn front_coded_word_list_round_trip() {
+    use crate::front_coded::FrontCodedWordList;
+
+    let text_len: usize = WORDLIST_ENGLISH.iter().map(|word| word.len()).sum();
+    assert!(ENGLISH_FRONT_CODED.len() * 10 < text_len * 7);
+
+    let front_coded_word_list = FrontCodedWordList::new(&ENGLISH_FRONT_CODED).unwrap();
+    for (i, word) in WORDLIST_ENGLISH.iter().enumerate() {
+        let bits11 = Bits11::from(i as u16).unwrap();
+        assert_eq!(
+            front_coded_word_list.get_word(bits11).unwrap().as_ref(),
+            *word
+        );
+    }
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_phrase(known[0], &front_coded_word_list).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        assert_eq!(
+            word_set.to_phrase(&front_coded_word_list).unwrap(),
+            known[0]
+        );
+    }
+    assert!(matches!(
+        front_coded_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(
+        front_coded_word_list.bits11_for_word("zoo").unwrap().bits(),
+        2047
+    );
+    assert_eq!(front_coded_word_list.count_by_prefix("act").unwrap(), 5);
+    let words = front_coded_word_list.get_words_by_prefix("zo").unwrap();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[1].word.as_ref(), "zoo");
+    assert!(front_coded_word_list.is_sorted());
+    assert_eq!(front_coded_word_list.count_by_prefix("").unwrap(), 2048);
+    assert_eq!(front_coded_word_list.count_by_prefix("ab").unwrap(), 10);
+    assert_eq!(front_coded_word_list.count_by_prefix("zz").unwrap(), 0);
+    assert_eq!(
+        front_coded_word_list
+            .bits11_for_word("abandon")
+            .unwrap()
+            .bits(),
+        0
+    );
+    assert!(matches!(
+        front_coded_word_list.bits11_for_word("zzz"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+}
+
+#[cfg(feature = "front-coded")]
+#[test]
+fn front_coded_word_list_rejects_bad_blobs() {
+    use crate::front_coded::{FrontCodedWordList, FRONT_CODED_FORMAT_VERSION};
+
+    let mut blob = ENGLISH_FRONT_CODED.to_vec();
+    blob[4] = FRONT_CODED_FORMAT_VERSION + 1;
+    assert!(matches!(
+        FrontCodedWordList::new(&blob),
+        Err(ErrorMnemonic::WordListFormat)
+    ));
+    blob[4] = FRONT_CODED_FORMAT_VERSION;
+    assert!(matches!(
+        FrontCodedWordList::new(&blob[..20]),
+        Err(ErrorMnemonic::WordListFormat)
+    ));
+
+    // truncated blob: the last bucket ends early
+    let front_coded_word_list = FrontCodedWordList::new(&blob[..blob.len() - 1]).unwrap();
+    assert!(matches!(
+        front_coded_word_list.get_word(Bits11::from(2047).unwrap()),
+        Err(ErrorMnemonic::DamagedWord)
+    ));
+    assert!(front_coded_word_list
+        .get_word(Bits11::from(0).unwrap())
+        .is_ok());
+}
+
+#[cfg(all(feature = "front-coded", feature = "sufficient-memory"))]
+#[test]
+fn front_coded_built_in_lists() {
+    use crate::front_coded::*;
+    use crate::wordlist::*;
+
+    for (blob, list) in [
+        (&WORDLIST_ENGLISH_FRONT_CODED[..], &WORDLIST_ENGLISH),
+        (
+            &WORDLIST_CHINESE_SIMPLIFIED_FRONT_CODED[..],
+            &WORDLIST_CHINESE_SIMPLIFIED,
+        ),
+        (
+            &WORDLIST_CHINESE_TRADITIONAL_FRONT_CODED[..],
+            &WORDLIST_CHINESE_TRADITIONAL,
+        ),
+        (&WORDLIST_CZECH_FRONT_CODED[..], &WORDLIST_CZECH),
+        (&WORDLIST_FRENCH_FRONT_CODED[..], &WORDLIST_FRENCH),
+        (&WORDLIST_ITALIAN_FRONT_CODED[..], &WORDLIST_ITALIAN),
+        (&WORDLIST_JAPANESE_FRONT_CODED[..], &WORDLIST_JAPANESE),
+        (&WORDLIST_KOREAN_FRONT_CODED[..], &WORDLIST_KOREAN),
+        (&WORDLIST_PORTUGUESE_FRONT_CODED[..], &WORDLIST_PORTUGUESE),
+        (&WORDLIST_SPANISH_FRONT_CODED[..], &WORDLIST_SPANISH),
+    ] {
+        let front_coded_word_list = FrontCodedWordList::new(blob).unwrap();
+        for (i, word) in list.iter().enumerate() {
+            let bits11 = Bits11::from(i as u16).unwrap();
+            assert_eq!(
+                front_coded_word_list.get_word(bits11).unwrap().as_ref(),
+                *word
+            );
+        }
+        assert_eq!(
+            front_coded_word_list.is_sorted(),
+            list.windows(2).all(|pair| pair[0] < pair[1])
+        );
+        for (i, word) in list.iter().enumerate().step_by(97) {
+            let bits11 = front_coded_word_list.bits11_for_word(word).unwrap();
+            assert_eq!(bits11.bits() as usize, i);
+            assert!(front_coded_word_list
+                .get_words_by_prefix(word)
+                .unwrap()
+                .iter()
+                .any(|element| element.bits11 == bits11));
+        }
+        let last = front_coded_word_list.bits11_for_word(list[2047]).unwrap();
+        assert_eq!(last.bits(), 2047);
+    }
+}
+
+#[test]
+fn cached_word_list_reads_each_word_once() {
+    use crate::cache::CachedWordList;
+    use core::cell::Cell;
+
+    let blob = raw_wordlist_blob(&WORDLIST_ENGLISH).unwrap();
+    let reads = Cell::new(0usize);
+    let read = |offset: usize, buffer: &mut [u8]| -> Result<(), ()> {
+        reads.set(reads.get() + 1);
+        let source = blob.get(offset..offset + buffer.len()).ok_or(())?;
+        buffer.copy_from_slice(source);
+        Ok(())
+    };
+    let mut cached_word_list =
+        CachedWordList::<_, MAX_SEED_LEN>::new(RawWordList::new(read).unwrap());
+
+    let word_set = WordSet::from_phrase(KNOWN[20][0], &cached_word_list).unwrap();
+    assert_eq!(cached_word_list.cached(), 24);
+    let after_entry = reads.get();
+    assert_eq!(word_set.to_phrase(&cached_word_list).unwrap(), KNOWN[20][0]);
+    assert_eq!(
+        WordSet::from_phrase(KNOWN[20][0], &cached_word_list).unwrap(),
+        word_set
+    );
+    assert_eq!(reads.get(), after_entry);
+
+    // the least recently used words make room
+    let other = WordSet::from_phrase(KNOWN[1][0], &cached_word_list).unwrap();
+    assert_eq!(cached_word_list.cached(), 24);
+    let after_other = reads.get();
+    assert_eq!(other.to_phrase(&cached_word_list).unwrap(), KNOWN[1][0]);
+    assert_eq!(reads.get(), after_other);
+    word_set.to_phrase(&cached_word_list).unwrap();
+    assert!(reads.get() > after_other);
+
+    assert!(matches!(
+        cached_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    cached_word_list.flush();
+    assert_eq!(cached_word_list.cached(), 0);
+    let after_flush = reads.get();
+    cached_word_list.get_word(Bits11::from(7).unwrap()).unwrap();
+    assert_eq!(reads.get(), after_flush + 1);
+
+    let uncached = CachedWordList::<_, 0>::new(CustomWordList::new(&WORDLIST_ENGLISH).unwrap());
+    assert_eq!(
+        uncached.get_word(Bits11::from(2047).unwrap()).unwrap(),
+        "zoo"
+    );
+    assert_eq!(uncached.cached(), 0);
+}
+
+// Flash stand-in for the `embedded-storage` adapters: reads past the end
+// fail, as on a device.
+#[cfg(any(feature = "embedded-storage", feature = "embedded-storage-async"))]
+struct MockFlash(Vec<u8>);
+
+#[cfg(any(feature = "embedded-storage", feature = "embedded-storage-async"))]
+impl MockFlash {
+    fn read_at(&self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+        let offset = offset as usize;
+        let source = self.0.get(offset..offset + bytes.len()).ok_or(())?;
+        bytes.copy_from_slice(source);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl embedded_storage::ReadStorage for MockFlash {
+    type Error = ();
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+        self.read_at(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(feature = "embedded-storage-async")]
+impl embedded_storage_async::ReadStorage for MockFlash {
+    type Error = ();
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+        self.read_at(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+// English blob at `address`, after erased flash.
+#[cfg(any(feature = "embedded-storage", feature = "embedded-storage-async"))]
+fn mock_flash(address: u32) -> MockFlash {
+    let mut flash = vec![0xFF; address as usize];
+    flash.extend(raw_wordlist_blob(&WORDLIST_ENGLISH).unwrap());
+    MockFlash(flash)
+}
+
+#[cfg(feature = "embedded-storage")]
+#[test]
+fn storage_word_list_round_trip() {
+    use crate::storage::StorageWordList;
+
+    let storage_word_list = StorageWordList::new(mock_flash(0x1000), 0x1000).unwrap();
+    assert_eq!(storage_word_list.record_len(), WORD_MAX_LEN);
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_phrase(known[0], &storage_word_list).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        assert_eq!(word_set.to_phrase(&storage_word_list).unwrap(), known[0]);
+    }
+    assert!(matches!(
+        storage_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(storage_word_list.count_by_prefix("act").unwrap(), 5);
+
+    // wrong address: erased flash is no header
+    assert!(matches!(
+        StorageWordList::new(storage_word_list.into_inner(), 0),
+        Err(ErrorMnemonic::WordListFormat)
+    ));
+    // the last record is cut off
+    let mut flash = mock_flash(0);
+    flash.0.pop();
+    let storage_word_list = StorageWordList::new(flash, 0).unwrap();
+    assert!(matches!(
+        storage_word_list.get_word(Bits11::from(2047).unwrap()),
+        Err(ErrorMnemonic::DamagedWord)
+    ));
+}
+
+#[cfg(feature = "embedded-storage-async")]
+#[test]
+fn storage_word_list_async_round_trip() {
+    use crate::async_wordlist::AsWordListAsync;
+    use crate::storage::StorageWordListAsync;
+
+    let storage_word_list = block_on(StorageWordListAsync::new(mock_flash(0x200), 0x200)).unwrap();
+    for known in KNOWN {
+        let mut word_set = WordSet::new();
+        for word in known[0].split(' ') {
+            block_on(word_set.add_word_async(word, &storage_word_list)).unwrap();
+        }
+        assert_eq!(
+            word_set.to_entropy().unwrap(),
+            hex::decode(known[1]).unwrap()
+        );
+        assert_eq!(
+            block_on(word_set.to_phrase_async(&storage_word_list)).unwrap(),
+            known[0]
+        );
+    }
+    let words = block_on(storage_word_list.get_words_by_prefix("zo")).unwrap();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0].word.as_ref(), "zone");
+    assert!(matches!(
+        block_on(StorageWordListAsync::new(storage_word_list.into_inner(), 0)),
+        Err(ErrorMnemonic::WordListFormat)
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn precomputed_word_list_round_trip() {
+    let precomputed_word_list = PrecomputedWordList::new(&WORDLIST_ENGLISH).unwrap();
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_phrase(known[0], &precomputed_word_list).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        assert_eq!(
+            word_set.to_phrase(&precomputed_word_list).unwrap(),
+            known[0]
+        );
+    }
+    assert!(matches!(
+        precomputed_word_list.bits11_for_word("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(precomputed_word_list.count_by_prefix("act").unwrap(), 5);
+    assert!(matches!(
+        PrecomputedWordList::new(&WORDLIST_ENGLISH[1..]),
+        Err(WordlistAudit::WrongLength { len: 2047 })
+    ));
+}
+
+#[test]
+fn diagnostics_report_checksum() {
+    let entropy = hex::decode(KNOWN[1][1]).unwrap();
+    let mut word_set = WordSet::from_entropy(&entropy).unwrap();
+    let diagnostics = word_set.diagnostics().unwrap();
+    assert_eq!(diagnostics.mnemonic_type, MnemonicType::Words12);
+    assert_eq!(diagnostics.entropy_len, 16);
+    assert_eq!(diagnostics.stored_checksum, diagnostics.computed_checksum);
+    assert!(diagnostics.checksum_valid);
+
+    // "yellow" -> "you", flipping the lowest checksum bit
+    word_set.bits11_set[11] = Bits11::from(2041).unwrap();
+    let diagnostics = word_set.diagnostics().unwrap();
+    assert_eq!(
+        diagnostics.stored_checksum ^ diagnostics.computed_checksum,
+        1
+    );
+    assert!(!diagnostics.checksum_valid);
+    assert!(matches!(
+        word_set.to_entropy(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[test]
+fn flash_mock_canonical_word() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for (typed, canonical) in [
+        ("ABAN", Some("abandon")),
+        ("Abandon", Some("abandon")),
+        ("act", Some("act")),
+        ("ACTR", Some("actress")),
+        ("ab", None),
+        ("qwerty", None),
+        ("", None),
+    ] {
+        assert_eq!(
+            canonical_word(typed, &flash_mock_word_list)
+                .unwrap()
+                .as_deref(),
+            canonical
+        );
+    }
+}
+
+// Plain optimal string alignment distance over the whole matrix.
+fn full_edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut cell = substitution.min(d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cell = cell.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = cell;
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[test]
+fn bounded_edit_distance_matches_full() {
+    for typed in [
+        "abandom",
+        "zo",
+        "",
+        "xylophone",
+        "acress",
+        "wrld",
+        "abnadon",
+        "ab",
+        "ba",
+        "caot",
+    ] {
+        let typed_chars: Vec<char> = typed.chars().collect();
+        for word in WORDLIST_ENGLISH.iter() {
+            let full = full_edit_distance(typed, word);
+            for max in 0..4 {
+                assert_eq!(
+                    bounded_edit_distance(&typed_chars, word, max),
+                    (full <= max).then_some(full)
+                );
+            }
+        }
+    }
+    assert_eq!(bounded_edit_distance(&['a', 'b'], "ba", 1), Some(1));
+}
+
+#[test]
+fn flash_mock_suggest_within() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let suggestions = flash_mock_word_list
+        .suggest_within("abandom", 2, 3)
+        .unwrap();
+    assert_eq!(suggestions[0].word, "abandon");
+    assert!(suggestions.len() <= 3);
+    // swapped letters are a single edit
+    let suggestions = flash_mock_word_list
+        .suggest_within("abnadon", 1, 3)
+        .unwrap();
+    assert_eq!(suggestions[0].word, "abandon");
+    assert!(flash_mock_word_list
+        .suggest_within("abandom", 2, 0)
+        .unwrap()
+        .is_empty());
+    assert!(flash_mock_word_list
+        .suggest_within("qqqqqqqq", 1, 5)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn flash_mock_validate_opaque() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN {
+        assert!(validate_opaque(known[0], &flash_mock_word_list));
+    }
+    assert!(!validate_opaque(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+        &flash_mock_word_list
+    ));
+    assert!(!validate_opaque(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandom",
+        &flash_mock_word_list
+    ));
+    assert!(!validate_opaque("abandon about", &flash_mock_word_list));
+    assert!(!validate_opaque("", &flash_mock_word_list));
+}
+
+static INCLUDED_WORD_LIST: StaticWordList =
+    StaticWordList::from_newline_str(include_str!("../for_tests/english.txt"));
+
+#[test]
+fn static_word_list_from_included_str() {
+    assert_eq!(INCLUDED_WORD_LIST.words(), WORDLIST_ENGLISH);
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        assert_eq!(word_set.to_phrase(&INCLUDED_WORD_LIST).unwrap(), known[0]);
+    }
+
+    let crlf: &'static str = WORDLIST_ENGLISH.join("\r\n").leak();
+    let crlf_word_list = StaticWordList::from_newline_str(crlf);
+    assert_eq!(crlf_word_list.words(), WORDLIST_ENGLISH);
+}
+
+#[test]
+#[should_panic]
+fn static_word_list_rejects_blank_line() {
+    let blank: &'static str = WORDLIST_ENGLISH[..2047].join("\n\n").leak();
+    let _ = StaticWordList::from_newline_str(blank);
+}
+
+#[test]
+fn flash_mock_ambiguous_positions() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    assert_eq!(flash_mock_word_list.count_by_prefix("act").unwrap(), 5);
+    let mut word_set = WordSet::new();
+    for word in ["act", "abandon", "add", "zoo", "art"] {
+        word_set.add_word(word, &flash_mock_word_list).unwrap();
+    }
+    assert_eq!(
+        word_set.ambiguous_positions(&flash_mock_word_list).unwrap(),
+        [0, 2, 4]
+    );
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_count_by_prefix() {
+    let internal_word_list = InternalWordList;
+    for prefix in ["", "a", "act", "zoo", "zz"] {
+        assert_eq!(
+            internal_word_list.count_by_prefix(prefix).unwrap(),
+            internal_word_list
+                .get_words_by_prefix(prefix)
+                .unwrap()
+                .len()
+        );
+    }
+}
+
+#[cfg(feature = "seed")]
+#[test]
+fn passphrase_feedback_hints() {
+    use crate::seed::{passphrase_feedback, PassphraseStrength};
+
+    let feedback = passphrase_feedback("");
+    assert_eq!(feedback.strength, PassphraseStrength::Empty);
+
+    let feedback = passphrase_feedback("TREZOR");
+    assert_eq!(feedback.strength, PassphraseStrength::Weak);
+    assert!(!feedback.changed_by_normalization);
+
+    let feedback = passphrase_feedback("\u{FF34}\u{FF32}\u{FF25}\u{FF3A}\u{FF2F}\u{FF32} ");
+    assert_eq!(feedback.normalized.as_str(), "TREZOR ");
+    assert!(feedback.changed_by_normalization);
+    assert!(feedback.surrounding_whitespace);
+
+    let feedback = passphrase_feedback("correct horse battery staple 42!");
+    assert_eq!(feedback.strength, PassphraseStrength::Strong);
+}
+
+#[test]
+fn flash_mock_phrase_from_entropy() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN {
+        let entropy_bytes = hex::decode(known[1]).unwrap();
+        assert_eq!(
+            phrase_from_entropy_not_seed(&entropy_bytes, &flash_mock_word_list).unwrap(),
+            known[0]
+        );
+        let entropy = Entropy::new(&entropy_bytes).unwrap();
+        assert_eq!(entropy.to_phrase(&flash_mock_word_list).unwrap(), known[0]);
+        let word_set = entropy.to_word_set().unwrap();
+        assert_eq!(
+            Entropy::from_word_set(&word_set).unwrap().as_bytes(),
+            entropy_bytes
+        );
+    }
+    assert!(matches!(
+        Entropy::new(&[0u8; 17]),
+        Err(ErrorMnemonic::EntropyNotMultipleOfFour { len: 17 })
+    ));
+}
+
+#[test]
+fn flash_mock_from_phrase_any_whitespace() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let words: Vec<&str> = known[0].split(' ').collect();
+        let mixed: String = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| ([" ", "\t", "\n", "\r\n", "  \t\n"][i % 5], word))
+            .map(|(separator, word)| String::from(separator) + word)
+            .collect();
+        for phrase in [
+            String::from(known[0]),
+            words.join("\n"),
+            words.join("\t"),
+            words.join("\r\n") + "\n",
+            mixed,
+        ] {
+            let word_set = WordSet::from_phrase(&phrase, &flash_mock_word_list).unwrap();
+            assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        }
+    }
+    assert!(matches!(
+        WordSet::from_phrase("abandon\nabandon", &flash_mock_word_list),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[test]
+fn distinct_word_count() {
+    for (known, distinct) in [
+        (&KNOWN[0], 2),
+        (&KNOWN[1], 9),
+        (&KNOWN[3], 2),
+        (&KNOWN[12], 12),
+    ] {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        assert_eq!(word_set.distinct_word_count(), distinct);
+    }
+    assert_eq!(WordSet::new().distinct_word_count(), 0);
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_words_of() {
+    use crate::regular::words_of;
+
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        assert_eq!(words_of(&word_set).unwrap().join(" "), known[0]);
+    }
+}
+
+#[test]
+fn ct_eq_u16_values() {
+    for (a, b) in [
+        (0, 0),
+        (2047, 2047),
+        (0, 1),
+        (1, 0),
+        (0, 2047),
+        (1024, 1025),
+    ] {
+        assert_eq!(ct_eq_u16(a, b), u8::from(a == b));
+    }
+}
+
+#[test]
+fn ct_eq_bytes_values() {
+    for (a, b) in [
+        ("", ""),
+        ("abandon", "abandon"),
+        ("abandon", "ability"),
+        ("able", "abl"),
+        ("abl", "able"),
+        ("zoo", ""),
+    ] {
+        assert_eq!(ct_eq_bytes(a.as_bytes(), b.as_bytes()), u8::from(a == b));
+    }
+}
+
+#[test]
+fn constant_time_lookup_matches_search() {
+    fill_flash_mock();
+    for (index, word) in WORDLIST_ENGLISH.iter().enumerate().step_by(97) {
+        assert_eq!(
+            FlashMockWordList.bits11_for_word_ct(word).unwrap().bits(),
+            index as u16
+        );
+    }
+    assert_eq!(
+        FlashMockWordList.bits11_for_word_ct("zoo").unwrap().bits(),
+        2047
+    );
+    assert!(matches!(
+        FlashMockWordList.bits11_for_word_ct("abandom"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert!(matches!(
+        FlashMockWordList.bits11_for_word_ct(""),
+        Err(ErrorMnemonic::NoWord)
+    ));
+}
+
+#[test]
+fn constant_time_lookup_rejects_duplicated_words() {
+    // indices 3 and 5 would merge into 7 if matches were only flagged
+    let mut words = WORDLIST_ENGLISH;
+    words[5] = words[3];
+    let slice_word_list = SliceWordList::new(&words).unwrap();
+    assert!(matches!(
+        slice_word_list.bits11_for_word_ct(words[3]),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(
+        slice_word_list.bits11_for_word_ct(words[4]).unwrap().bits(),
+        4
+    );
+}
+
+#[test]
+fn flash_mock_confirm_session() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let entropy = hex::decode(KNOWN[1][1]).unwrap();
+
+    let mut session = ConfirmSession::new(WordSet::from_entropy(&entropy).unwrap());
+    for word in KNOWN[1][0].split(' ') {
+        assert!(session.verify_next(word, &flash_mock_word_list).unwrap());
+    }
+    assert!(session.is_confirmed());
+    assert!(matches!(
+        session.verify_next("legal", &flash_mock_word_list),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+
+    let mut session = ConfirmSession::new(WordSet::from_entropy(&entropy).unwrap());
+    for (i, word) in KNOWN[1][0].split(' ').enumerate() {
+        if i == 3 {
+            assert!(matches!(
+                session.verify_next("yaer", &flash_mock_word_list),
+                Err(ErrorMnemonic::NoWord)
+            ));
+            assert!(!session.verify_next("wave", &flash_mock_word_list).unwrap());
+        } else {
+            session.verify_next(word, &flash_mock_word_list).unwrap();
+        }
+        assert!(!session.is_confirmed());
+    }
+    assert!(session.is_complete());
+    assert_eq!(session.failed_positions(), [3]);
+}
+
+#[test]
+fn entropy_byte_order() {
+    let entropy = hex::decode(KNOWN[12][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    assert_eq!(
+        word_set
+            .to_entropy_with_byte_order(ByteOrder::default())
+            .unwrap(),
+        entropy
+    );
+    let mut reversed = entropy.clone();
+    reversed.reverse();
+    assert_eq!(
+        word_set
+            .to_entropy_with_byte_order(ByteOrder::Reversed)
+            .unwrap(),
+        reversed
+    );
+}
+
+#[test]
+fn flash_mock_export_rows() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let entropy = hex::decode(KNOWN[0][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    let rows = word_set.to_export_rows(&flash_mock_word_list).unwrap();
+    assert_eq!(rows.len(), 13);
+    assert_eq!(rows[0], "number,word");
+    assert_eq!(rows[3], "3,abandon");
+    assert_eq!(rows[12], "12,about");
+}
+
+#[test]
+fn one_from_valid_lengths() {
+    let mut word_set = WordSet::new();
+    let mut one_from_valid: Vec<usize> = Vec::new();
+    for len in 0..=MAX_SEED_LEN {
+        if word_set.is_one_from_valid() {
+            one_from_valid.push(len);
+        }
+        word_set.bits11_set.push(Bits11::from(0).unwrap());
+    }
+    assert_eq!(one_from_valid, [11, 14, 17, 20, 23]);
+}
+
+#[test]
+fn flash_mock_resolve_word_modes() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let resolve = |input: &str, mode: ResolveMode| {
+        resolve_word(input, &flash_mock_word_list, mode).map(|bits11| bits11.bits())
+    };
+    assert_eq!(resolve("abandon", ResolveMode::Strict).unwrap(), 0);
+    assert!(resolve("Abandon", ResolveMode::Strict).is_err());
+    assert_eq!(resolve("Abandon", ResolveMode::CaseInsensitive).unwrap(), 0);
+    assert!(resolve(" Abandon ", ResolveMode::CaseInsensitive).is_err());
+    assert_eq!(resolve(" ZOO\t", ResolveMode::Lenient).unwrap(), 2047);
+    assert!(resolve("abandom", ResolveMode::Lenient).is_err());
+    #[cfg(feature = "nfkd")]
+    assert_eq!(
+        resolve("\u{FF3A}\u{FF2F}\u{FF2F}", ResolveMode::Nfkd).unwrap(),
+        2047
+    );
+
+    // typed phrases are case-folded unless asked for exact matches
+    let phrase = KNOWN[0][0].to_uppercase();
+    let word_set = WordSet::from_phrase(&phrase, &flash_mock_word_list).unwrap();
+    assert_eq!(word_set.to_entropy().unwrap(), [0; 16]);
+    assert!(matches!(
+        WordSet::from_phrase_with_mode(&phrase, &flash_mock_word_list, ResolveMode::Strict),
+        Err(ErrorMnemonic::NoWordAt { position: 0 })
+    ));
+    let mut word_set = WordSet::new();
+    word_set.add_word("Zoo", &flash_mock_word_list).unwrap();
+    assert_eq!(word_set.remove_last_word().unwrap().bits(), 2047);
+}
+
+// Official BIP-39 vectors: phrase, entropy, seed with the passphrase "TREZOR".
+#[cfg(all(feature = "seed", feature = "english-wordlist"))]
+static KNOWN_SEEDS: &[[&str; 3]] = &[
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "00000000000000000000000000000000",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        "80808080808080808080808080808080",
+        "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        "ffffffffffffffffffffffffffffffff",
+        "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    ],
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        "000000000000000000000000000000000000000000000000",
+        "035895f2f481b1b0f01fcf8c289c794660b289981a78f8106447707fdd9666ca06da5a9a565181599b79f53b844d8a71dd9f439c52a3d7b3e8a79c906ac845fa",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "f2b94508732bcbacbcc020faefecfc89feafa6649a5491b8c952cede496c214a0c7b3c392d168748f2d4a612bada0753b52a1c7ac53c1e93abd5c6320b9e95dd",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+        "808080808080808080808080808080808080808080808080",
+        "107d7c02a5aa6f38c58083ff74f04c607c2d2c0ecc55501dadd72d025b751bc27fe913ffb796f841c49b1d33b610cf0e91d3aa239027f5e99fe4ce9e5088cd65",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+        "ffffffffffffffffffffffffffffffffffffffffffffffff",
+        "0cd6e5d827bb62eb8fc1e262254223817fd068a74b5b449cc2f667c3f1f985a76379b43348d952e2265b4cd129090758b3e3c2c49103b5051aac2eaeb890a528",
+    ],
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "bc09fca1804f7e69da93c2f2028eb238c227f2e9dda30cd63699232578480a4021b146ad717fbb7e451ce9eb835f43620bf5c514db0f8add49f5d121449d3e87",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        "8080808080808080808080808080808080808080808080808080808080808080",
+        "c0c519bd0e91a2ed54357d9d1ebef6f5af218a153624cf4f2da911a0ed8f7a09e2ef61af0aca007096df430022f7a2b6fb91661a9589097069720d015e4e982f",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+    ],
+    [
+        "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+        "9e885d952ad362caeb4efe34a8e91bd2",
+        "274ddc525802f7c828d8ef7ddbcdc5304e87ac3535913611fbbfa986d0c9e5476c91689f9c8a54fd55bd38606aa6a8595ad213d4c9c9f9aca3fb217069a41028",
+    ],
+    [
+        "gravity machine north sort system female filter attitude volume fold club stay feature office ecology stable narrow fog",
+        "6610b25967cdcca9d59875f5cb50b0ea75433311869e930b",
+        "628c3827a8823298ee685db84f55caa34b5cc195a778e52d45f59bcf75aba68e4d7590e101dc414bc1bbd5737666fbbef35d1f1903953b66624f910feef245ac",
+    ],
+    [
+        "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+        "64c87cde7e12ecf6704ab95bb1408bef047c22db4cc7491c4271d170a1b213d20b385bc1588d9c7b38f1b39d415665b8a9030c9ec653d75e65f847d8fc1fc440",
+    ],
+    [
+        "scheme spot photo card baby mountain device kick cradle pact join borrow",
+        "c0ba5a8e914111210f2bd131f3d5e08d",
+        "ea725895aaae8d4c1cf682c1bfd2d358d52ed9f0f0591131b559e2724bb234fca05aa9c02c57407e04ee9dc3b454aa63fbff483a8b11de949624b9f1831a9612",
+    ],
+    [
+        "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap uncle crack brave",
+        "6d9be1ee6ebd27a258115aad99b7317b9c8d28b6d76431c3",
+        "fd579828af3da1d32544ce4db5c73d53fc8acc4ddb1e3b251a31179cdb71e853c56d2fcb11aed39898ce6c34b10b5382772db8796e52837b54468aeb312cfc3d",
+    ],
+    [
+        "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+        "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+        "72be8e052fc4919d2adf28d5306b5474b0069df35b02303de8c1729c9538dbb6fc2d731d5f832193cd9fb6aeecbc469594a70e3dd50811b5067f3b88b28c3e8d",
+    ],
+    [
+        "cat swing flag economy stadium alone churn speed unique patch report train",
+        "23db8160a31d3e0dca3688ed941adbf3",
+        "deb5f45449e615feff5640f2e49f933ff51895de3b4381832b3139941c57b59205a42480c52175b6efcffaa58a2503887c1e8b363a707256bdd2b587b46541f5",
+    ],
+    [
+        "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart sustain crack supply proud access",
+        "8197a4a47f0425faeaa69deebc05ca29c0a5b5cc76ceacc0",
+        "4cbdff1ca2db800fd61cae72a57475fdc6bab03e441fd63f96dabd1f183ef5b782925f00105f318309a7e9c3ea6967c7801e46c8a58082674c860a37b93eda02",
+    ],
+    [
+        "all hour make first leader extend hole alien behind guard gospel lava path output census museum junior mass reopen famous sing advance salt reform",
+        "066dca1a2bb7e8a1db2832148ce9933eea0f3ac9548d793112d9a95c9407efad",
+        "26e975ec644423f4a4c4f4215ef09b4bd7ef924e85d1d17c4cf3f136c2863cf6df0a475045652c57eb5fb41513ca2a2d67722b77e954b4b3fc11f7590449191d",
+    ],
+    [
+        "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+        "f30f8c1da665478f49b001d94c5fc452",
+        "2aaa9242daafcee6aa9d7269f17d4efe271e1b9a529178d7dc139cd18747090bf9d60295d0ce74309a78852a9caadf0af48aae1c6253839624076224374bc63f",
+    ],
+    [
+        "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little brisk hair mango congress clump",
+        "c10ec20dc3cd9f652c7fac2f1230f7a3c828389a14392f05",
+        "7b4a10be9d98e6cba265566db7f136718e1398c71cb581e1b2f464cac1ceedf4f3e274dc270003c670ad8d02c4558b2f8e39edea2775c9e232c7cb798b069e88",
+    ],
+    [
+        "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+        "f585c11aec520db57dd353c69554b21a89b20fb0650966fa0a9d6f74fd989d8f",
+        "01f5bced59dec48e362f2c45b5de68b9fd6c92c6634f44d6d40aab69056506f0e35524a518034ddc1192e1dacd32c1ed3eaa3c3b131c88ed8e7e54c49a5d0998",
+    ],
+];
+
+#[cfg(all(feature = "seed", feature = "english-wordlist"))]
+#[test]
+fn internal_to_seed() {
+    let internal_word_list = InternalWordList;
+    let word_set = WordSet::from_phrase(KNOWN[0][0], &internal_word_list).unwrap();
+    let seed = word_set.to_seed(&internal_word_list, "TREZOR").unwrap();
+    assert_eq!(
+        hex::encode(seed.as_bytes()),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+
+    let mut word_set = word_set.clone();
+    word_set.bits11_set[11] = Bits11::from(0).unwrap();
+    assert!(matches!(
+        word_set.to_seed(&internal_word_list, "TREZOR"),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[cfg(all(feature = "seed", feature = "english-wordlist"))]
+#[test]
+fn internal_to_seed_official_vectors() {
+    let internal_word_list = InternalWordList;
+    for [phrase, entropy, seed] in KNOWN_SEEDS {
+        let word_set = WordSet::from_phrase(phrase, &internal_word_list).unwrap();
+        assert_eq!(hex::encode(word_set.to_entropy().unwrap()), *entropy);
+        let derived = word_set.to_seed(&internal_word_list, "TREZOR").unwrap();
+        assert_eq!(hex::encode(derived.as_bytes()), *seed);
+    }
+}
+
+#[test]
+fn flash_mock_bip39_english_vectors() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    assert_eq!(BIP39_ENGLISH_VECTORS.len(), 24);
+    for vector in BIP39_ENGLISH_VECTORS {
+        let entropy = hex::decode(vector.entropy).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        assert_eq!(
+            word_set.to_phrase(&flash_mock_word_list).unwrap(),
+            vector.phrase
+        );
+        #[cfg(feature = "seed")]
+        {
+            let seed = word_set
+                .to_seed(&flash_mock_word_list, BIP39_VECTOR_PASSPHRASE)
+                .unwrap();
+            assert_eq!(hex::encode(seed.as_bytes()), vector.seed);
+        }
+    }
+    assert_eq!(BIP39_VECTOR_PASSPHRASE, "TREZOR");
+}
+
+#[cfg(feature = "test-utils")]
+mod strategies {
+    use proptest::prelude::*;
+
+    use crate::error::ErrorMnemonic;
+    use crate::testing::{arb_bad_checksum, arb_entropy, arb_swapped_words, arb_word_set};
+    use crate::WordSet;
+
+    proptest! {
+        #[test]
+        fn entropy_round_trips(entropy in arb_entropy()) {
+            let word_set = WordSet::from_entropy(&entropy).unwrap();
+            prop_assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        }
+
+        #[test]
+        fn word_sets_are_valid(word_set in arb_word_set()) {
+            prop_assert!(word_set.validate().is_ok());
+        }
+
+        #[test]
+        fn bad_checksums_are_caught(word_set in arb_bad_checksum()) {
+            prop_assert!(matches!(
+                word_set.validate(),
+                Err(ErrorMnemonic::InvalidChecksum)
+            ));
+        }
+
+        #[test]
+        fn swaps_are_caught(word_set in arb_swapped_words()) {
+            prop_assert!(word_set.validate().is_err());
+        }
+    }
+}
+
+// `substrate-bip39` vectors: phrase, mini-secret with the passphrase
+// "Substrate".
+#[cfg(all(feature = "substrate", feature = "english-wordlist"))]
+static KNOWN_MINI_SECRETS: &[[&str; 2]] = &[
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "44e9d125f037ac1d51f0a7d3649689d422c2af8b1ec8e00d71db4d7bf6d127e3",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "4313249608fe8ac10fd5886c92c4579007272cb77c21551ee5b8d60b78041685",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        "27f3eb595928c60d5bc91a4d747da40ed236328183046892ed6cd5aa9ae38122",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        "227d6256fd4f9ccaf06c45eaa4b2345969640462bbb00c5f51f43cb43418c7a7",
+    ],
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        "44e9d125f037ac1d51f0a7d3649689d422c2af8b1ec8e00d71db4d7bf6d127e3",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+        "cb1d50e14101024a88905a098feb1553d4306d072d7460e167a60ccb3439a681",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+        "9ddecf32ce6bee77f867f3c4bb842d1f0151826a145cb4489598fe71ac29e355",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+        "8971cb290e7117c64b63379c97ed3b5c6da488841bd9f95cdc2a5651ac89571e",
+    ],
+    [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        "44e9d125f037ac1d51f0a7d3649689d422c2af8b1ec8e00d71db4d7bf6d127e3",
+    ],
+    [
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        "3037276a5d05fcd7edf51869eb841bdde27c574dae01ac8cfb1ea476f6bea6ef",
+    ],
+    [
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        "2c9c6144a06ae5a855453d98c3dea470e2a8ffb78179c2e9eb15208ccca7d831",
+    ],
+    [
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        "047e89ef7739cbfe30da0ad32eb1720d8f62441dd4f139b981b8e2d0bd412ed4",
+    ],
+    [
+        "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+        "f4956be6960bc145cdab782e649a5056598fd07cd3f32ceb73421c3da2783324",
+    ],
+    [
+        "gravity machine north sort system female filter attitude volume fold club stay feature office ecology stable narrow fog",
+        "fbcc5229ade0c0ff018cb7a329c5459f91876e4dde2a97ddf03c832eab7f2612",
+    ],
+    [
+        "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        "7c60c555126c297deddddd59f8cdcdc9e3608944455824dd604897984b5cc369",
+    ],
+    [
+        "scheme spot photo card baby mountain device kick cradle pact join borrow",
+        "c12157bf2506526c4bd1b79a056453b071361538e9e2c19c28ba2cfa39b5f230",
+    ],
+    [
+        "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap uncle crack brave",
+        "23766723e970e6b79dec4d5e4fdd627fd27d1ee026eb898feb9f653af01ad220",
+    ],
+    [
+        "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+        "f4c83c86617cb014d35cd87d38b5ef1c5d5c3d58a73ab779114438a7b358f457",
+    ],
+    [
+        "cat swing flag economy stadium alone churn speed unique patch report train",
+        "719d4d4de0638a1705bf5237262458983da76933e718b2d64eb592c470f3c5d2",
+    ],
+    [
+        "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart sustain crack supply proud access",
+        "7ae1291db32d16457c248567f2b101e62c5549d2a64cd2b7605d503ec876d587",
+    ],
+    [
+        "all hour make first leader extend hole alien behind guard gospel lava path output census museum junior mass reopen famous sing advance salt reform",
+        "a911a5f4db0940b17ecb79c4dcf9392bf47dd18acaebdd4ef48799909ebb4967",
+    ],
+    [
+        "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+        "4e2314ca7d9eebac6fe5a05a5a8d3546bc891785414d82207ac987926380411e",
+    ],
+    [
+        "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little brisk hair mango congress clump",
+        "7a83851102849edc5d2a3ca9d8044d0d4f00e5c4a292753ed3952e4080859325",
+    ],
+    [
+        "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+        "938ba18c3f521f19bd4a399c8425b02c716844325b1a65106b9d1593fbafe5e0",
+    ],
+];
+
+#[cfg(all(feature = "substrate", feature = "english-wordlist"))]
+#[test]
+fn internal_to_mini_secret() {
+    let internal_word_list = InternalWordList;
+    for [phrase, mini_secret] in KNOWN_MINI_SECRETS {
+        let word_set = WordSet::from_phrase(phrase, &internal_word_list).unwrap();
+        let derived = word_set.to_mini_secret("Substrate").unwrap();
+        assert_eq!(hex::encode(derived.as_bytes()), *mini_secret);
+    }
+
+    let mut word_set = WordSet::from_phrase(KNOWN_MINI_SECRETS[0][0], &internal_word_list).unwrap();
+    word_set.bits11_set[11] = Bits11::from(0).unwrap();
+    assert!(matches!(
+        word_set.to_mini_secret("Substrate"),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_parse_phrase() {
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let padded = String::from("  ") + &known[0].replace(' ', "   ") + " ";
+        let word_set: WordSet = padded.parse().unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+    }
+
+    let too_long = String::from(KNOWN[8][0]) + " abandon";
+    assert!(matches!(
+        too_long.parse::<WordSet>(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        "abandon abandon abandon".parse::<WordSet>(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandom"
+            .parse::<WordSet>(),
+        Err(ErrorMnemonic::NoWordAt { position: 11 })
+    ));
+}
+
+#[test]
+fn flash_mock_add_word_when_full() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let mut word_set = WordSet::from_phrase(KNOWN[8][0], &flash_mock_word_list).unwrap();
+    assert_eq!(word_set.remaining_capacity(), 0);
+    assert!(matches!(
+        word_set.add_word("abandon", &flash_mock_word_list),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+    assert!(matches!(
+        word_set.add_word("abandom", &flash_mock_word_list),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(word_set.bits11_set.len(), MAX_SEED_LEN);
+}
+
+#[test]
+fn flash_mock_remove_last_word() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let mut word_set = WordSet::from_phrase(KNOWN[8][0], &flash_mock_word_list).unwrap();
+    let capacity = word_set.bits11_set.capacity();
+    let last = *word_set.bits11_set.last().unwrap();
+
+    assert_eq!(word_set.remove_last_word().unwrap().bits(), last.bits());
+    assert_eq!(word_set.bits11_set.len(), MAX_SEED_LEN - 1);
+    assert!(word_set.is_one_from_valid());
+    word_set
+        .add_word(
+            flash_mock_word_list.get_word(last).unwrap().as_ref(),
+            &flash_mock_word_list,
+        )
+        .unwrap();
+    assert!(word_set.to_entropy().is_ok());
+
+    word_set.clear();
+    assert!(word_set.bits11_set.is_empty());
+    assert_eq!(word_set.bits11_set.capacity(), capacity);
+    assert!(word_set.remove_last_word().is_none());
+}
+
+#[test]
+fn flash_mock_edit_words() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let original = WordSet::from_phrase(KNOWN[1][0], &flash_mock_word_list).unwrap();
+    let mut word_set = original.clone();
+
+    // mistyped third word, fixed in place
+    word_set
+        .replace_word(2, "abandon", &flash_mock_word_list)
+        .unwrap();
+    assert!(word_set.validate().is_err());
+    word_set
+        .replace_word(2, "thank", &flash_mock_word_list)
+        .unwrap();
+    assert_eq!(word_set, original);
+    assert!(matches!(
+        word_set.replace_word(12, "thank", &flash_mock_word_list),
+        Err(ErrorMnemonic::PositionOutOfRange { position: 12 })
+    ));
+    assert!(matches!(
+        word_set.replace_word(0, "thanks", &flash_mock_word_list),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert_eq!(word_set, original);
+
+    // removed from the middle, later words move up
+    let removed = word_set.remove_word(1).unwrap();
+    assert_eq!(removed, original.bits11_set[1]);
+    assert_eq!(word_set.len(), 11);
+    assert_eq!(word_set.bits11_set[1..], original.bits11_set[2..]);
+    assert!(word_set.remove_word(11).is_none());
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn internal_japanese_separator() {
+    use crate::regular::InternalWordListJapanese;
+
+    let internal_word_list = InternalWordListJapanese;
+    let entropy = hex::decode(KNOWN[0][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    let phrase = word_set.to_phrase(&internal_word_list).unwrap();
+    let secret = word_set.to_phrase_secret(&internal_word_list).unwrap();
+    assert_eq!(*secret, phrase);
+    assert_eq!(secret.capacity(), phrase.len());
+    let words: Vec<&str> = phrase.split('\u{3000}').collect();
+    assert_eq!(words.len(), 12);
+    assert_eq!(words[0], "あいこくしん");
+    assert_eq!(words[11], "あおそ\u{3099}ら");
+    assert!(!phrase.contains(' '));
+
+    let parsed = WordSet::from_phrase(&phrase, &internal_word_list).unwrap();
+    assert_eq!(parsed.to_entropy().unwrap(), entropy);
+}
+
+#[cfg(all(feature = "spanish", feature = "nfkd"))]
+#[test]
+fn internal_spanish_nfkd_lookup() {
+    use crate::regular::InternalWordListSpanish;
+
+    let internal_word_list = InternalWordListSpanish;
+    // precomposed "á", while the list stores "a" with a combining accent
+    assert_eq!(
+        internal_word_list
+            .bits11_for_word("\u{E1}baco")
+            .unwrap()
+            .bits(),
+        0
+    );
+    assert_eq!(
+        internal_word_list
+            .get_words_by_prefix("\u{E1}")
+            .unwrap()
+            .len(),
+        internal_word_list
+            .get_words_by_prefix("a\u{301}")
+            .unwrap()
+            .len()
+    );
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_lookup_matches_linear_scan() {
+    let internal_word_list = InternalWordList;
+    for (i, word) in WORDLIST_ENGLISH.iter().enumerate() {
+        assert_eq!(
+            internal_word_list.bits11_for_word(word).unwrap().bits() as usize,
+            i
+        );
+    }
+    for word in ["", "abandom", "aaa", "zzz", "zoos", "ab"] {
+        assert!(matches!(
+            internal_word_list.bits11_for_word(word),
+            Err(ErrorMnemonic::NoWord)
+        ));
+    }
+    for prefix in ["", "a", "ab", "act", "q", "zo", "zoo", "zzz", "xylophone"] {
+        let expected: Vec<usize> = WORDLIST_ENGLISH
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| word.starts_with(prefix))
+            .map(|(i, _)| i)
+            .collect();
+        let calculated: Vec<usize> = internal_word_list
+            .get_words_by_prefix(prefix)
+            .unwrap()
+            .iter()
+            .map(|element| element.bits11.bits() as usize)
+            .collect();
+        assert_eq!(calculated, expected);
+    }
+}
+
+#[cfg(feature = "generate")]
+struct CounterRng(u8);
+
+#[cfg(feature = "generate")]
+impl rand_core::RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest.iter_mut() {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "generate")]
+impl rand_core::CryptoRng for CounterRng {}
+
+#[cfg(feature = "generate")]
+#[test]
+fn generate_from_rng() {
+    for (mnemonic_type, len) in [
+        (MnemonicType::Words12, 16),
+        (MnemonicType::Words15, 20),
+        (MnemonicType::Words18, 24),
+        (MnemonicType::Words21, 28),
+        (MnemonicType::Words24, 32),
+    ] {
+        let word_set = WordSet::generate(mnemonic_type, &mut CounterRng(0));
+        assert!(word_set.is_finalizable());
+        let expected: Vec<u8> = (0..len).collect();
+        assert_eq!(word_set.to_entropy().unwrap(), expected);
+    }
+
+    // a TRNG driver behind a trait object
+    let rng: &mut dyn rand_core::CryptoRngCore = &mut CounterRng(7);
+    let word_set = WordSet::generate(MnemonicType::Words24, rng);
+    let expected: Vec<u8> = (7..39).collect();
+    assert_eq!(word_set.to_entropy().unwrap(), expected);
+}
+
+#[cfg(feature = "os-rng")]
+#[test]
+fn generate_from_os() {
+    let first = WordSet::generate_os(MnemonicType::Words24).unwrap();
+    let second = WordSet::generate_os(MnemonicType::Words24).unwrap();
+    assert_eq!(first.to_entropy().unwrap().len(), 32);
+    assert_ne!(first, second);
+    let short = WordSet::generate_os(MnemonicType::Words12).unwrap();
+    assert_eq!(short.mnemonic_type().unwrap(), MnemonicType::Words12);
+    assert!(short.validate().is_ok());
+}
+
+#[test]
+fn mnemonic_type_all_in_order() {
+    let word_counts: Vec<usize> = MnemonicType::ALL
+        .iter()
+        .map(MnemonicType::word_count)
+        .collect();
+    assert_eq!(word_counts, [12, 15, 18, 21, 24]);
+    for mnemonic_type in MnemonicType::ALL {
+        assert_eq!(
+            MnemonicType::try_from(mnemonic_type.word_count()).unwrap(),
+            mnemonic_type
+        );
+    }
+    assert!(matches!(
+        MnemonicType::try_from(13),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[test]
+fn mnemonic_type_of_word_set() {
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        let mnemonic_type = word_set.mnemonic_type().unwrap();
+        assert_eq!(
+            MnemonicType::from_entropy_len(entropy.len()).unwrap(),
+            mnemonic_type
+        );
+        assert_eq!(mnemonic_type.entropy_bits(), entropy.len() * 8);
+        assert_eq!(mnemonic_type.total_bits(), known[0].split(' ').count() * 11);
+        assert_eq!(mnemonic_type.entropy_bytes(), entropy.len());
+        assert_eq!(mnemonic_type.word_count(), known[0].split(' ').count());
+    }
+    assert!(matches!(
+        WordSet::new().mnemonic_type(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        MnemonicType::from_entropy_len(17),
+        Err(ErrorMnemonic::EntropyNotMultipleOfFour { len: 17 })
+    ));
+    assert!(matches!(
+        MnemonicType::from_entropy_len(12),
+        Err(ErrorMnemonic::EntropyTooShort { len: 12 })
+    ));
+    assert!(matches!(
+        WordSet::from_entropy(&[0u8; 36]),
+        Err(ErrorMnemonic::EntropyTooLong { len: 36 })
+    ));
+    assert_eq!(
+        format!("{}", ErrorMnemonic::EntropyTooShort { len: 0 }),
+        "Unable to calculate the mnemonic from entropy: length 0 bytes is below the minimum of 16."
+    );
+}
+
+#[test]
+fn error_codes_and_messages() {
+    let error = ErrorMnemonic::NoWordAt { position: 3 };
+    assert_eq!(error.code(), 18);
+    assert_eq!(
+        error.message(),
+        "A word of the phrase is not in the word list."
+    );
+    assert_eq!(
+        format!("{error}"),
+        "Word 4 of the phrase is not in the word list."
+    );
+    assert_eq!(
+        format!("{}", ErrorMnemonic::NoWordAt { position: 0 }),
+        "Word 1 of the phrase is not in the word list."
+    );
+    assert_eq!(
+        format!("{}", ErrorMnemonic::DamagedWordAt { position: 12 }),
+        "Unable to extract word 13 of the set from the word list."
+    );
+    assert_eq!(
+        format!("{}", ErrorMnemonic::PositionOutOfRange { position: 24 }),
+        "The set has no word 25."
+    );
+    // without details the message is the whole text
+    assert_eq!(
+        format!("{}", ErrorMnemonic::NoWord),
+        ErrorMnemonic::NoWord.message()
+    );
+    assert_eq!(ErrorMnemonic::XorShares.code(), 32);
+
+    let audit = WordlistAudit::WrongLength { len: 2047 };
+    assert_eq!(audit.code(), 206);
+    assert_eq!(
+        format!("{audit}"),
+        "Word list has 2047 entries instead of 2048."
+    );
+
+    let error: &dyn core::error::Error = &ErrorMnemonic::InvalidChecksum;
+    assert!(error.source().is_none());
+    #[cfg(feature = "slip39")]
+    {
+        let share_error = ErrorShare::Word(ErrorMnemonic::InvalidChecksum);
+        assert_eq!(share_error.code(), ErrorMnemonic::InvalidChecksum.code());
+        assert_eq!(ErrorShare::Checksum.code(), 101);
+        let source = core::error::Error::source(&share_error).unwrap();
+        assert_eq!(format!("{source}"), format!("{share_error}"));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    for known in [KNOWN[0], KNOWN[11], KNOWN[12], KNOWN[20]] {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        let serialized = serde_json::to_string(&word_set).unwrap();
+        let deserialized: WordSet = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.to_entropy().unwrap(), entropy);
+    }
+
+    let partial: WordSet = serde_json::from_str("[0,2047,3]").unwrap();
+    assert_eq!(partial.bits11_set.len(), 3);
+    assert_eq!(serde_json::to_string(&partial).unwrap(), "[0,2047,3]");
+
+    assert!(serde_json::from_str::<WordSet>("[0,2048]").is_err());
+    let too_long = [[0u16; MAX_SEED_LEN].as_slice(), &[0]].concat();
+    let too_long = serde_json::to_vec(&too_long).unwrap();
+    assert!(serde_json::from_slice::<WordSet>(&too_long).is_err());
+    // refused at the 25th index, before the invalid one after it is read
+    let too_long = format!("[{}0,2048]", "0,".repeat(MAX_SEED_LEN));
+    let error = serde_json::from_str::<WordSet>(&too_long).unwrap_err();
+    assert!(format!("{error}").starts_with(ErrorMnemonic::TooManyWords.message()));
+
+    for mnemonic_type in [MnemonicType::Words12, MnemonicType::Words24] {
+        let serialized = serde_json::to_string(&mnemonic_type).unwrap();
+        assert_eq!(serialized, format!("{}", mnemonic_type.word_count()));
+        assert_eq!(
+            serde_json::from_str::<MnemonicType>(&serialized).unwrap(),
+            mnemonic_type
+        );
+    }
+    assert!(serde_json::from_str::<MnemonicType>("13").is_err());
+}
+
+#[test]
+fn flash_mock_words_iterator() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let word_set = WordSet::from_phrase(KNOWN[4][0], &flash_mock_word_list).unwrap();
+    assert_eq!(word_set.len(), 18);
+    assert!(!word_set.is_empty());
+    assert!(WordSet::new().is_empty());
+    assert_eq!(word_set.remaining_capacity(), MAX_SEED_LEN - 18);
+    assert_eq!(WordSet::default().remaining_capacity(), MAX_SEED_LEN);
+
+    let expected: Vec<&str> = KNOWN[4][0].split(' ').collect();
+    let mut count = 0;
+    for (i, element) in word_set.words(&flash_mock_word_list).enumerate() {
+        let element = element.unwrap();
+        assert_eq!(element.word, expected[i]);
+        assert_eq!(element.bits11.bits(), word_set.bits11_set[i].bits());
+        count += 1;
+    }
+    assert_eq!(count, 18);
+
+    let mut damaged = WordSet::new();
+    for bits in [1, 9, 2] {
+        damaged.bits11_set.push(Bits11::from(bits).unwrap());
+    }
+    let mut words = damaged.words(&ShortWordList);
+    assert_eq!(words.next().unwrap().unwrap().word, "ability");
+    assert!(matches!(
+        words.next(),
+        Some(Err(ErrorMnemonic::DamagedWord))
+    ));
+    assert!(words.next().is_none());
+}
+
+#[test]
+fn flash_mock_elements_sort_and_zeroize() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    // neither `Clone` nor `Debug` is needed of the list itself
+    let mut elements = flash_mock_word_list.get_words_by_prefix("zo").unwrap();
+    elements.extend(flash_mock_word_list.get_words_by_prefix("ab").unwrap());
+    elements.push(elements[0].clone());
+    elements.sort();
+    elements.dedup();
+    let words: Vec<&str> = elements
+        .iter()
+        .map(|element| element.word.as_str())
+        .collect();
+    assert_eq!(
+        words,
+        [
+            "abandon", "ability", "able", "about", "above", "absent", "absorb", "abstract",
+            "absurd", "abuse", "zone", "zoo"
+        ]
+    );
+    assert!(elements[0] < elements[1]);
+
+    let mut element = elements.pop().unwrap();
+    #[cfg(not(feature = "redact-debug"))]
+    assert_eq!(
+        format!("{element:?}"),
+        "WordListElement { word: \"zoo\", bits11: Bits11(2047) }"
+    );
+    #[cfg(feature = "redact-debug")]
+    assert_eq!(format!("{element:?}"), "WordListElement(..)");
+    element.zeroize();
+    assert!(element.word.is_empty());
+    assert_eq!(element.bits11.bits(), 0);
+
+    let mut indices: Vec<Bits11> = [5u16, 2, 5, 0]
+        .iter()
+        .map(|index| Bits11::from(*index).unwrap())
+        .collect();
+    indices.sort();
+    indices.dedup();
+    assert_eq!(
+        indices
+            .iter()
+            .map(|bits11| bits11.bits())
+            .collect::<Vec<_>>(),
+        [0, 2, 5]
+    );
+}
+
+#[test]
+fn flash_mock_keypad_candidates() {
+    use crate::keypad::{keypad_candidates, keypad_digit};
+
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let words = |digits: &str| -> Vec<String> {
+        keypad_candidates(digits, &flash_mock_word_list)
+            .unwrap()
+            .into_iter()
+            .map(|element| element.word)
+            .collect()
+    };
+    assert_eq!(
+        words("4663"),
+        ["good", "home", "hood", "honey", "inner", "immense"]
+    );
+    assert_eq!(words("7283"), ["pave", "rate", "save", "raven"]);
+    assert_eq!(words("96")[..3], ["you", "zoo", "wolf"]);
+    assert_eq!(words("").len(), TOTAL_WORDS);
+    assert!(words("99999").is_empty());
+
+    let zoo = keypad_candidates("966", &flash_mock_word_list).unwrap();
+    assert_eq!(zoo[0].bits11.bits(), 2047);
+    for digits in ["2a", "1", "20", "4663 "] {
+        assert!(matches!(
+            keypad_candidates(digits, &flash_mock_word_list),
+            Err(ErrorMnemonic::KeypadDigits)
+        ));
+    }
+    assert_eq!(keypad_digit('S'), Some(7));
+    assert_eq!(keypad_digit('\u{E9}'), None);
+}
+
+#[test]
+fn flash_mock_recover_one_word() {
+    use crate::recover::recover_phrase;
+
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let phrase = BIP39_ENGLISH_VECTORS[12].phrase;
+    let word_set = WordSet::from_phrase(phrase, &flash_mock_word_list).unwrap();
+
+    // "grab" unreadable
+    let mut words: Vec<&str> = phrase.split(' ').collect();
+    words[2] = "?";
+    let candidates = recover_phrase(&words.join(" "), &flash_mock_word_list).unwrap();
+    assert!(candidates.iter().all(|(position, _)| *position == 2));
+    assert!(candidates.iter().any(|(_, element)| element.word == "grab"));
+    for (_, element) in candidates.iter() {
+        let mut candidate = word_set.clone();
+        candidate.bits11_set[2] = element.bits11;
+        assert!(candidate.validate().is_ok());
+    }
+
+    // at the last position these are exactly the valid last words
+    let last: Vec<u16> = word_set
+        .recover_word_at(11, &flash_mock_word_list)
+        .unwrap()
+        .iter()
+        .map(|element| element.bits11.bits())
+        .collect();
+    let mut short = word_set.clone();
+    short.remove_last_word();
+    let expected: Vec<u16> = short
+        .valid_last_words(&flash_mock_word_list)
+        .unwrap()
+        .iter()
+        .map(|element| element.bits11.bits())
+        .collect();
+    assert_eq!(last, expected);
+
+    // "grab" written down as "grape", position unknown
+    words[2] = "grape";
+    let wrong = words.join(" ");
+    assert!(matches!(
+        WordSet::from_phrase(&wrong, &flash_mock_word_list)
+            .unwrap()
+            .validate(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+    let candidates = recover_phrase(&wrong, &flash_mock_word_list).unwrap();
+    assert!(candidates
+        .iter()
+        .any(|(position, element)| *position == 2 && element.word == "grab"));
+    assert!(!candidates
+        .iter()
+        .any(|(position, element)| *position == 2 && element.word == "grape"));
+    assert!(candidates.iter().any(|(position, _)| *position != 2));
+
+    words[3] = "?";
+    assert!(matches!(
+        recover_phrase(
+            &words.join(" ").replace("grape", "?"),
+            &flash_mock_word_list
+        ),
+        Err(ErrorMnemonic::UnknownWords)
+    ));
+    assert!(matches!(
+        recover_phrase("? abandon", &flash_mock_word_list),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        word_set.recover_word_at(12, &flash_mock_word_list),
+        Err(ErrorMnemonic::PositionOutOfRange { position: 12 })
+    ));
+}
+
+#[test]
+fn audit_flags_known_phrases() {
+    use crate::weak::Weakness;
+
+    let audit = |entropy: &str| WordSet::from_hex_entropy(entropy).unwrap().audit().unwrap();
+    // "abandon" eleven times then "about"
+    assert_eq!(
+        audit(BIP39_ENGLISH_VECTORS[0].entropy),
+        [
+            Weakness::RepeatedWord,
+            Weakness::TestVector,
+            Weakness::UniformEntropy
+        ]
+    );
+    // "zoo" twenty-three times then "vote"
+    assert_eq!(
+        audit(BIP39_ENGLISH_VECTORS[11].entropy),
+        [
+            Weakness::RepeatedWord,
+            Weakness::TestVector,
+            Weakness::UniformEntropy
+        ]
+    );
+    for vector in BIP39_ENGLISH_VECTORS {
+        assert!(audit(vector.entropy).contains(&Weakness::TestVector));
+    }
+
+    // eleven words in list order, up or down, then any valid last word
+    fill_flash_mock();
+    for leading in [
+        (0..11).collect::<Vec<u16>>(),
+        (2037..2048).rev().collect::<Vec<u16>>(),
+    ] {
+        let mut indices = leading.clone();
+        let partial = WordSet::from_indices(&leading).unwrap();
+        let last = partial.valid_last_words(&FlashMockWordList).unwrap();
+        indices.push(last[1].bits11.bits());
+        let word_set = WordSet::from_indices(&indices).unwrap();
+        assert_eq!(word_set.audit().unwrap(), [Weakness::SequentialWords]);
+    }
+
+    let random = WordSet::from_hex_entropy("3c1e5a7d0b94f2e86a4d1c7b9e03f5a2").unwrap();
+    assert!(random.audit().unwrap().is_empty());
+    assert!(matches!(
+        WordSet::from_indices(&[0; 12]).unwrap().audit(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[test]
+fn single_bits_land_in_place() {
+    for mnemonic_type in MnemonicType::ALL {
+        for position in 0..mnemonic_type.entropy_bits() {
+            let mut entropy = vec![0u8; mnemonic_type.entropy_bytes()];
+            entropy[position / BITS_IN_BYTE] = 0x80 >> (position % BITS_IN_BYTE);
+            let word_set = WordSet::from_entropy(&entropy).unwrap();
+            let indices = word_set.to_indices();
+            let (word, bit) = (
+                position / BITS_IN_U11,
+                1 << (BITS_IN_U11 - 1 - position % BITS_IN_U11),
+            );
+            // the last word carries the checksum as well
+            if word == indices.len() - 1 {
+                assert_ne!(indices[word] & bit, 0);
+            } else {
+                assert_eq!(indices[word], bit);
+            }
+            assert_eq!(word_set.to_entropy().unwrap(), entropy);
+        }
+    }
+}
+
+#[test]
+fn indices_round_trip() {
+    for known in KNOWN.iter() {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy(&entropy).unwrap();
+        let indices = word_set.to_indices();
+        let restored = WordSet::from_indices(&indices).unwrap();
+        assert_eq!(restored.to_indices(), indices);
+        assert_eq!(restored.to_entropy().unwrap(), entropy);
+    }
+
+    assert_eq!(WordSet::from_indices(&[0, 2047]).unwrap().len(), 2);
+    assert!(matches!(
+        WordSet::from_indices(&[0, 2048]),
+        Err(ErrorMnemonic::InvalidWordNumber)
+    ));
+    assert!(matches!(
+        WordSet::from_indices(&[0; MAX_SEED_LEN + 1]),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+}
+
+#[test]
+fn flash_mock_lenient_lookup() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for input in ["abandon", "Abandon", " abandon ", "\tABANDON\n"] {
+        assert_eq!(
+            flash_mock_word_list
+                .bits11_for_word_lenient(input)
+                .unwrap()
+                .bits(),
+            0
+        );
+    }
+    assert!(matches!(
+        flash_mock_word_list.bits11_for_word("Abandon"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+    assert!(matches!(
+        flash_mock_word_list.bits11_for_word_lenient(" abandom "),
+        Err(ErrorMnemonic::NoWord)
+    ));
+}
+
+#[test]
+fn flash_mock_next_chars() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+
+    let first = flash_mock_word_list.next_chars("").unwrap();
+    assert_eq!(first.chars.len(), 25);
+    assert!(!first.chars.contains(&'x'));
+    assert!(!first.is_word);
+
+    assert_eq!(
+        flash_mock_word_list.next_chars("zo").unwrap(),
+        NextChars {
+            chars: vec!['n', 'o'],
+            is_word: false,
+        }
+    );
+    let act = flash_mock_word_list.next_chars("act").unwrap();
+    assert_eq!(act.chars, vec!['i', 'o', 'r', 'u']);
+    assert!(act.is_word);
+    assert_eq!(
+        flash_mock_word_list.next_chars("zoo").unwrap(),
+        NextChars {
+            chars: Vec::new(),
+            is_word: true,
+        }
+    );
+    assert_eq!(
+        flash_mock_word_list.next_chars("qz").unwrap(),
+        NextChars::default()
+    );
+}
+
+#[test]
+fn flash_mock_validate() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN.iter() {
+        let word_set = WordSet::from_phrase(known[0], &flash_mock_word_list).unwrap();
+        assert!(word_set.validate().is_ok());
+    }
+
+    let mut word_set = WordSet::from_phrase(KNOWN[0][0], &flash_mock_word_list).unwrap();
+    word_set.bits11_set[11] = Bits11::from(word_set.bits11_set[11].bits() ^ 1).unwrap();
+    assert!(matches!(
+        word_set.validate(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+    word_set.remove_last_word();
+    assert!(matches!(
+        word_set.validate(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[test]
+fn flash_mock_error_position() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let mut words: Vec<&str> = KNOWN[8][0].split(' ').collect();
+    words[12] = "abandom";
+    let phrase = words.join(" ");
+    let error = WordSet::from_phrase(&phrase, &flash_mock_word_list).unwrap_err();
+    assert!(matches!(error, ErrorMnemonic::NoWordAt { position: 12 }));
+    assert_eq!(
+        format!("{error}"),
+        "Word 13 of the phrase is not in the word list."
+    );
+
+    let word_set = WordSet::from_indices(&[1, 2, 9, 3]).unwrap();
+    assert!(matches!(
+        word_set.to_phrase(&ShortWordList),
+        Err(ErrorMnemonic::DamagedWordAt { position: 2 })
+    ));
+}
+
+#[test]
+fn flash_mock_write_phrase() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let word_set = WordSet::from_phrase(KNOWN[0][0], &flash_mock_word_list).unwrap();
+
+    let mut out = String::new();
+    word_set
+        .write_phrase(&flash_mock_word_list, '\n', &mut out)
+        .unwrap();
+    assert_eq!(out, KNOWN[0][0].replace(' ', "\n"));
+
+    // fixed buffer with no room for the whole phrase
+    struct Fixed {
+        buf: [u8; 16],
+        len: usize,
+    }
+
+    impl core::fmt::Write for Fixed {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buf.len() {
+                return Err(core::fmt::Error);
+            }
+            self.buf[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut fixed = Fixed {
+        buf: [0; 16],
+        len: 0,
+    };
+    assert!(matches!(
+        word_set.write_phrase(&flash_mock_word_list, ' ', &mut fixed),
+        Err(ErrorMnemonic::PhraseWrite)
+    ));
+    let mut fixed = Fixed {
+        buf: [0; 16],
+        len: 0,
+    };
+    WordSet::from_indices(&[0, 1])
+        .unwrap()
+        .write_phrase(&flash_mock_word_list, '-', &mut fixed)
+        .unwrap();
+    assert_eq!(&fixed.buf[..fixed.len], b"abandon-ability");
+}
+
+#[test]
+fn flash_mock_valid_last_words() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for (known, expected_len) in [(KNOWN[0], 128), (KNOWN[4], 32), (KNOWN[8], 8)] {
+        let mut word_set = WordSet::from_phrase(known[0], &flash_mock_word_list).unwrap();
+        let last = word_set.remove_last_word().unwrap();
+        let valid = word_set.valid_last_words(&flash_mock_word_list).unwrap();
+        assert_eq!(valid.len(), expected_len);
+        assert!(valid
+            .iter()
+            .any(|element| element.bits11.bits() == last.bits()));
+        for element in valid.iter() {
+            let mut completed = word_set.clone();
+            completed
+                .add_word(&element.word, &flash_mock_word_list)
+                .unwrap();
+            assert!(completed.validate().is_ok());
+        }
+    }
+
+    let word_set = WordSet::from_phrase(KNOWN[0][0], &flash_mock_word_list).unwrap();
+    assert!(matches!(
+        word_set.valid_last_words(&flash_mock_word_list),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[test]
+fn word_set_equality() {
+    assert_eq!(Bits11::from(5).unwrap(), Bits11::from(5).unwrap());
+    assert_ne!(Bits11::from(5).unwrap(), Bits11::from(6).unwrap());
+
+    let entropy = hex::decode(KNOWN[8][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    assert_eq!(word_set, WordSet::from_entropy(&entropy).unwrap());
+    assert_eq!(WordSet::new(), WordSet::default());
+
+    for position in [0, 11, 23] {
+        let mut other = word_set.clone();
+        other.bits11_set[position] =
+            Bits11::from(other.bits11_set[position].bits() ^ 0x400).unwrap();
+        assert_ne!(word_set, other);
+    }
+
+    let mut shorter = word_set.clone();
+    shorter.remove_last_word();
+    assert_ne!(word_set, shorter);
+    assert!(!word_set.ct_eq(&shorter));
+    assert!(!shorter.ct_eq(&word_set));
+    assert!(word_set.ct_eq(&word_set.clone()));
+
+    let fixed = WordSetFixed::<MAX_SEED_LEN>::from_entropy(&entropy).unwrap();
+    assert!(fixed.ct_eq(&WordSetFixed::from_entropy(&entropy).unwrap()));
+    assert!(!fixed.ct_eq(&WordSetFixed::new()));
+
+    let secret = Entropy::new(&entropy).unwrap();
+    assert!(secret.ct_eq(&entropy));
+    assert!(!secret.ct_eq(&entropy[..16]));
+    let mut flipped = entropy.clone();
+    flipped[31] ^= 1;
+    assert!(!secret.ct_eq(&flipped));
+    assert_eq!(secret, Entropy::from_word_set(&word_set).unwrap());
+    assert_ne!(secret, Entropy::new(&flipped).unwrap());
+}
+
+#[test]
+fn could_be_valid_prefix_by_length() {
+    let mut word_set = WordSet::new();
+    assert!(word_set.could_be_valid_prefix());
+    for _ in 0..MAX_SEED_LEN {
+        word_set.bits11_set.push(Bits11::from(0).unwrap());
+        assert!(word_set.could_be_valid_prefix());
+    }
+    word_set.bits11_set.push(Bits11::from(0).unwrap());
+    assert!(!word_set.could_be_valid_prefix());
+}
+
+#[test]
+fn flash_mock_entropy_secret_and_unchecked() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let entropy = hex::decode(KNOWN[1][1]).unwrap();
+    let mut word_set = WordSet::from_phrase(KNOWN[1][0], &flash_mock_word_list).unwrap();
+    assert_eq!(*word_set.to_entropy_secret().unwrap(), entropy);
+    assert_eq!(
+        word_set.to_entropy_unchecked().unwrap(),
+        (Zeroizing::new(entropy.clone()), true)
+    );
+    let phrase = word_set.to_phrase_secret(&flash_mock_word_list).unwrap();
+    assert_eq!(phrase.as_str(), KNOWN[1][0]);
+    assert_eq!(phrase.capacity(), phrase.len());
+
+    // checksum bits of the last word changed, entropy bits kept
+    word_set.bits11_set[11] = Bits11::from(word_set.bits11_set[11].bits() ^ 1).unwrap();
+    assert!(matches!(
+        word_set.to_entropy_secret(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+    assert_eq!(
+        word_set.to_entropy_unchecked().unwrap(),
+        (Zeroizing::new(entropy), false)
+    );
+
+    word_set.remove_last_word();
+    assert!(matches!(
+        word_set.to_entropy_unchecked(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[test]
+fn flash_mock_words_by_prefix_limited() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let (words, more) = flash_mock_word_list
+        .get_words_by_prefix_limited("ab", 2)
+        .unwrap();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[1].word, "ability");
+    assert!(more);
+
+    let (words, more) = flash_mock_word_list
+        .get_words_by_prefix_limited("act", 5)
+        .unwrap();
+    assert_eq!(words.len(), 5);
+    assert!(!more);
+
+    let (words, more) = flash_mock_word_list
+        .get_words_by_prefix_limited("a", 0)
+        .unwrap();
+    assert!(words.is_empty());
+    assert!(more);
+
+    let (words, more) = flash_mock_word_list
+        .get_words_by_prefix_limited("qz", 0)
+        .unwrap();
+    assert!(words.is_empty());
+    assert!(!more);
+}
+
+#[test]
+fn flash_mock_iter_words_by_prefix() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let first: Vec<String> = flash_mock_word_list
+        .iter_words_by_prefix("ab")
+        .take(3)
+        .map(|element| element.unwrap().word)
+        .collect();
+    assert_eq!(first, ["abandon", "ability", "able"]);
+    assert_eq!(
+        flash_mock_word_list.iter_words_by_prefix("act").count(),
+        flash_mock_word_list.count_by_prefix("act").unwrap()
+    );
+    assert_eq!(flash_mock_word_list.iter_words_by_prefix("qz").count(), 0);
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_iter_words_by_prefix() {
+    for prefix in ["", "a", "ab", "act", "zoo", "qz"] {
+        let collected = InternalWordList.get_words_by_prefix(prefix).unwrap();
+        let iterated: Vec<_> = InternalWordList
+            .iter_words_by_prefix(prefix)
+            .map(|element| element.unwrap())
+            .collect();
+        assert_eq!(collected.len(), iterated.len());
+        for (a, b) in collected.iter().zip(iterated.iter()) {
+            assert_eq!(a.word, b.word);
+            assert_eq!(a.bits11, b.bits11);
+        }
+    }
+    #[cfg(feature = "french")]
+    {
+        use crate::regular::InternalWordListFrench;
+        let collected = InternalWordListFrench.get_words_by_prefix("ab").unwrap();
+        assert_eq!(
+            InternalWordListFrench.iter_words_by_prefix("ab").count(),
+            collected.len()
+        );
+    }
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_words_by_prefix_limited() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let internal_word_list = InternalWordList;
+    for prefix in ["", "a", "ab", "act", "zoo", "qz"] {
+        for max in [0, 1, 3, 5, 2048] {
+            let (internal, internal_more) = internal_word_list
+                .get_words_by_prefix_limited(prefix, max)
+                .unwrap();
+            let (mock, mock_more) = flash_mock_word_list
+                .get_words_by_prefix_limited(prefix, max)
+                .unwrap();
+            assert_eq!(internal_more, mock_more);
+            assert_eq!(internal.len(), mock.len());
+            for (a, b) in internal.iter().zip(mock.iter()) {
+                assert_eq!(a.word, b.word);
+                assert_eq!(a.bits11, b.bits11);
+            }
+        }
+    }
+}
+
+// SHA-256 of the word list files in the bips repository
+fn wordlist_digest(words: &[&str]) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    for word in words {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+#[test]
+fn wordlists_match_reference() {
+    assert_eq!(
+        wordlist_digest(&WORDLIST_ENGLISH),
+        "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda"
+    );
+    #[cfg(feature = "chinese-simplified")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_CHINESE_SIMPLIFIED),
+        "5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726"
+    );
+    #[cfg(feature = "chinese-traditional")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_CHINESE_TRADITIONAL),
+        "417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f"
+    );
+    #[cfg(feature = "czech")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_CZECH),
+        "7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc"
+    );
+    #[cfg(feature = "french")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_FRENCH),
+        "ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59"
+    );
+    #[cfg(feature = "italian")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_ITALIAN),
+        "d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2"
+    );
+    #[cfg(feature = "japanese")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_JAPANESE),
+        "2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd"
+    );
+    #[cfg(feature = "korean")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_KOREAN),
+        "9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60"
+    );
+    #[cfg(feature = "portuguese")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_PORTUGUESE),
+        "2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f"
+    );
+    #[cfg(feature = "spanish")]
+    assert_eq!(
+        wordlist_digest(&crate::wordlist::WORDLIST_SPANISH),
+        "46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b"
+    );
+}
+
+#[test]
+fn flash_mock_verify_wordlist() {
+    use crate::verify::*;
+
+    fill_flash_mock();
+    assert_eq!(
+        wordlist_sha256(&FlashMockWordList).unwrap(),
+        WORDLIST_SHA256_ENGLISH
+    );
+    verify_wordlist(&FlashMockWordList).unwrap();
+    assert!(matches!(
+        verify_wordlist_sha256(&FlashMockWordList, &WORDLIST_SHA256_FRENCH),
+        Err(ErrorMnemonic::WordListDigest)
+    ));
+
+    let mut words = WORDLIST_ENGLISH;
+    words.swap(0, 1);
+    let swapped = crate::custom::SliceWordList::new(&words).unwrap();
+    assert!(matches!(
+        verify_wordlist(&swapped),
+        Err(ErrorMnemonic::WordListDigest)
+    ));
+}
+
+#[test]
+fn wordlist_sha256_constants() {
+    use crate::verify::*;
+
+    for (digest, reference) in [
+        (
+            WORDLIST_SHA256_ENGLISH,
+            "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda",
+        ),
+        (
+            WORDLIST_SHA256_CHINESE_SIMPLIFIED,
+            "5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726",
+        ),
+        (
+            WORDLIST_SHA256_CHINESE_TRADITIONAL,
+            "417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f",
+        ),
+        (
+            WORDLIST_SHA256_CZECH,
+            "7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc",
+        ),
+        (
+            WORDLIST_SHA256_FRENCH,
+            "ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59",
+        ),
+        (
+            WORDLIST_SHA256_ITALIAN,
+            "d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2",
+        ),
+        (
+            WORDLIST_SHA256_JAPANESE,
+            "2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd",
+        ),
+        (
+            WORDLIST_SHA256_KOREAN,
+            "9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60",
+        ),
+        (
+            WORDLIST_SHA256_PORTUGUESE,
+            "2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f",
+        ),
+        (
+            WORDLIST_SHA256_SPANISH,
+            "46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b",
+        ),
+    ] {
+        assert_eq!(hex::encode(digest), reference);
+    }
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn internal_japanese_verifies() {
+    use crate::regular::{InternalWordListJapanese, Language};
+    use crate::verify::verify_wordlist_sha256;
+
+    verify_wordlist_sha256(
+        &InternalWordListJapanese,
+        &Language::Japanese.wordlist_sha256(),
+    )
+    .unwrap();
+}
+
+// Binary search is only right for lists sorted by byte value; every word
+// must come back at its own index, and prefix queries must match a scan.
+#[cfg(feature = "english-wordlist")]
+fn assert_sorted_lookup<L: AsWordList>(wordlist: &L, words: &[&str]) {
+    assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+    let slice_word_list = SliceWordList::new(words).unwrap();
+    for (i, word) in words.iter().enumerate() {
+        assert_eq!(wordlist.bits11_for_word(word).unwrap().bits(), i as u16);
+        let prefix: String = word.chars().take(2).collect();
+        let fast: Vec<u16> = wordlist
+            .get_words_by_prefix(&prefix)
+            .unwrap()
+            .iter()
+            .map(|element| element.bits11.bits())
+            .collect();
+        let scan: Vec<u16> = slice_word_list
+            .get_words_by_prefix(&prefix)
+            .unwrap()
+            .iter()
+            .map(|element| element.bits11.bits())
+            .collect();
+        assert_eq!(fast, scan);
+    }
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn sorted_lists_binary_search() {
+    assert_sorted_lookup(&InternalWordList, &WORDLIST_ENGLISH);
+    #[cfg(feature = "italian")]
+    assert_sorted_lookup(
+        &crate::regular::InternalWordListItalian,
+        &crate::wordlist::WORDLIST_ITALIAN,
+    );
+    #[cfg(feature = "korean")]
+    assert_sorted_lookup(
+        &crate::regular::InternalWordListKorean,
+        &crate::wordlist::WORDLIST_KOREAN,
+    );
+    #[cfg(feature = "portuguese")]
+    assert_sorted_lookup(
+        &crate::regular::InternalWordListPortuguese,
+        &crate::wordlist::WORDLIST_PORTUGUESE,
+    );
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn internal_detect_language() {
+    use crate::regular::{detect_language, Language};
+
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        assert!(detect_language(known[0]).contains(&Language::English));
+        let (word_set, language) = WordSet::from_phrase_any_language(known[0]).unwrap();
+        assert_eq!(language, Language::English);
+        assert_eq!(word_set.to_entropy().unwrap(), entropy);
+    }
+
+    assert!(detect_language("").is_empty());
+    assert!(detect_language("abandon qwerty").is_empty());
+    assert!(matches!(
+        WordSet::from_phrase_any_language("abandon qwerty"),
+        Err(ErrorMnemonic::UnknownLanguage)
+    ));
+    assert!(matches!(
+        WordSet::from_phrase_any_language(&KNOWN[0][0].replace("about", "abandon")),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn internal_detect_japanese() {
+    use crate::regular::{detect_language, InternalWordListJapanese, Language};
+
+    let entropy = hex::decode(KNOWN[8][1]).unwrap();
+    let phrase = WordSet::from_entropy(&entropy)
+        .unwrap()
+        .to_phrase(&InternalWordListJapanese)
+        .unwrap();
+    assert_eq!(detect_language(&phrase), vec![Language::Japanese]);
+    let (word_set, language) = WordSet::from_phrase_any_language(&phrase).unwrap();
+    assert_eq!(language, Language::Japanese);
+    assert_eq!(word_set.to_entropy().unwrap(), entropy);
+}
+
+#[cfg(feature = "french")]
+#[test]
+fn internal_detect_shared_words() {
+    use crate::regular::{detect_language, InternalWordListFrench, Language};
+
+    // in both the English and the French list
+    let shared = [
+        "abandon", "amateur", "angle", "animal", "aspect", "badge", "bicycle", "bonus", "brave",
+        "canal", "capable",
+    ];
+    let mut word_set = WordSet::new();
+    for word in shared {
+        word_set.add_word(word, &InternalWordListFrench).unwrap();
+    }
+    // a last word valid for French only settles the ambiguity
+    let last = word_set
+        .valid_last_words(&InternalWordListFrench)
+        .unwrap()
+        .into_iter()
+        .map(|element| element.word)
+        .find(|word| {
+            let phrase = shared.join(" ") + " " + word;
+            matches!(
+                WordSet::from_phrase(&phrase, &InternalWordList).map(|set| set.validate()),
+                Ok(Err(ErrorMnemonic::InvalidChecksum))
+            )
+        })
+        .unwrap();
+    let phrase = shared.join(" ") + " " + last;
+    assert_eq!(
+        detect_language(&phrase),
+        vec![Language::English, Language::French]
+    );
+    let (_, language) = WordSet::from_phrase_any_language(&phrase).unwrap();
+    assert_eq!(language, Language::French);
+}
+
+#[cfg(all(feature = "spanish", feature = "nfkd"))]
+#[test]
+fn from_phrase_normalizes_for_any_list() {
+    use crate::regular::InternalWordListSpanish;
+    use crate::wordlist::WORDLIST_SPANISH;
+
+    // the slice list compares bytes, normalization is up to `from_phrase`
+    let slice_word_list = SliceWordList::new(&WORDLIST_SPANISH).unwrap();
+    let entropy = hex::decode(KNOWN[0][1]).unwrap();
+    let word_set = WordSet::from_entropy(&entropy).unwrap();
+    let phrase = word_set.to_phrase(&InternalWordListSpanish).unwrap();
+    assert!(phrase.starts_with("a\u{301}baco"));
+
+    let composed = phrase.replace("a\u{301}", "\u{E1}");
+    assert_ne!(composed, phrase);
+    let parsed = WordSet::from_phrase(&composed, &slice_word_list).unwrap();
+    assert_eq!(parsed.to_entropy().unwrap(), entropy);
+
+    let spaced = composed.replace(' ', "\u{3000}");
+    let parsed = WordSet::from_phrase(&spaced, &slice_word_list).unwrap();
+    assert_eq!(parsed.to_entropy().unwrap(), entropy);
+}
+
+#[cfg(feature = "slip39")]
+#[test]
+fn slip39_official_vectors() {
+    use crate::slip39::{combine_shares, InternalShareWordList, Share};
+
+    let vectors: serde_json::Value =
+        serde_json::from_str(include_str!("../for_tests/slip39_vectors.json")).unwrap();
+    for vector in vectors.as_array().unwrap() {
+        let description = vector[0].as_str().unwrap();
+        let master_secret = vector[2].as_str().unwrap();
+        let combined = vector[1]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|phrase| Share::from_phrase(phrase.as_str().unwrap(), &InternalShareWordList))
+            .collect::<Result<Vec<Share>, _>>()
+            .and_then(|shares| combine_shares(&shares, "TREZOR"));
+        if master_secret.is_empty() {
+            assert!(combined.is_err(), "{description}");
+        } else {
+            assert_eq!(
+                hex::encode(combined.unwrap().as_slice()),
+                master_secret,
+                "{description}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "slip39")]
+#[test]
+fn slip39_share_word_by_word() {
+    use crate::slip39::{AsShareWordList, InternalShareWordList, Share, ShareWordSet};
+
+    let phrase = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+    let mut word_set = ShareWordSet::new();
+    for word in phrase.split(' ') {
+        // four letters are enough to tell SLIP-0039 words apart
+        let candidates = InternalShareWordList
+            .get_words_by_prefix(&word[..4.min(word.len())])
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        word_set
+            .add_word(candidates[0].word, &InternalShareWordList)
+            .unwrap();
+    }
+    assert!(matches!(
+        word_set.add_word("zoo", &InternalShareWordList),
+        Err(ErrorMnemonic::NoWord)
+    ));
+
+    let share = word_set.to_share().unwrap();
+    assert_eq!(share.member_threshold(), 1);
+    assert_eq!(share.group_count(), 1);
+    assert_eq!(share.to_phrase(&InternalShareWordList).unwrap(), phrase);
+    assert_eq!(
+        Share::from_phrase(phrase, &InternalShareWordList)
+            .unwrap()
+            .to_word_set()
+            .to_indices(),
+        word_set.to_indices()
+    );
+
+    word_set.remove_last_word();
+    assert!(matches!(word_set.to_share(), Err(ErrorShare::Length)));
+}
+
+#[cfg(all(feature = "slip39", feature = "generate"))]
+#[test]
+fn slip39_split_and_combine() {
+    use crate::slip39::{
+        combine_shares, split_master_secret, GroupSpec, InternalShareWordList, Share,
+        SplitParameters,
+    };
+
+    let master_secret: Vec<u8> = (0..16).collect();
+    let groups = [
+        GroupSpec {
+            member_threshold: 1,
+            member_count: 1,
+        },
+        GroupSpec {
+            member_threshold: 2,
+            member_count: 3,
+        },
+        GroupSpec {
+            member_threshold: 3,
+            member_count: 5,
+        },
+    ];
+    for extendable in [false, true] {
+        let parameters = SplitParameters {
+            group_threshold: 2,
+            groups: &groups,
+            iteration_exponent: 0,
+            extendable,
+        };
+        let shares =
+            split_master_secret(&master_secret, "TREZOR", &parameters, &mut CounterRng(0)).unwrap();
+        assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), [1, 3, 5]);
+
+        // through the phrases, as on paper
+        let parsed: Vec<Share> = shares
+            .iter()
+            .flatten()
+            .map(|share| {
+                let phrase = share.to_phrase(&InternalShareWordList).unwrap();
+                Share::from_phrase(&phrase, &InternalShareWordList).unwrap()
+            })
+            .collect();
+        let pick = |indices: &[usize]| -> Vec<Share> {
+            indices.iter().map(|i| parsed[*i].clone()).collect()
+        };
+
+        let recovered = combine_shares(&pick(&[0, 2, 3]), "TREZOR").unwrap();
+        assert_eq!(recovered.as_slice(), master_secret.as_slice());
+        let recovered = combine_shares(&pick(&[8, 5, 6, 1, 3]), "TREZOR").unwrap();
+        assert_eq!(recovered.as_slice(), master_secret.as_slice());
+        // repeated shares are ignored
+        let recovered = combine_shares(&pick(&[0, 0, 1, 2]), "TREZOR").unwrap();
+        assert_eq!(recovered.as_slice(), master_secret.as_slice());
+
+        // any passphrase decrypts, to a different secret
+        let other = combine_shares(&pick(&[0, 2, 3]), "").unwrap();
+        assert_ne!(other.as_slice(), master_secret.as_slice());
+
+        assert!(matches!(
+            combine_shares(&pick(&[0, 2]), "TREZOR"),
+            Err(ErrorShare::ShareCount)
+        ));
+        assert!(matches!(
+            combine_shares(&pick(&[0]), "TREZOR"),
+            Err(ErrorShare::ShareCount)
+        ));
+        assert!(matches!(
+            combine_shares(&pick(&[0, 1, 2, 4, 5, 6]), "TREZOR"),
+            Err(ErrorShare::ShareCount)
+        ));
+        assert!(matches!(
+            combine_shares(&[], "TREZOR"),
+            Err(ErrorShare::NoShares)
+        ));
+    }
+
+    let invalid = [
+        GroupSpec {
+            member_threshold: 1,
+            member_count: 2,
+        },
+        GroupSpec {
+            member_threshold: 3,
+            member_count: 2,
+        },
+        GroupSpec {
+            member_threshold: 2,
+            member_count: 17,
+        },
+    ];
+    for group in invalid {
+        let parameters = SplitParameters {
+            group_threshold: 1,
+            groups: &[group],
+            iteration_exponent: 0,
+            extendable: false,
+        };
+        assert!(matches!(
+            split_master_secret(&master_secret, "", &parameters, &mut CounterRng(0)),
+            Err(ErrorShare::MemberThreshold)
+        ));
+    }
+    let parameters = SplitParameters {
+        group_threshold: 4,
+        groups: &groups,
+        iteration_exponent: 0,
+        extendable: false,
+    };
+    assert!(matches!(
+        split_master_secret(&master_secret, "", &parameters, &mut CounterRng(0)),
+        Err(ErrorShare::GroupThreshold)
+    ));
+    let parameters = SplitParameters {
+        group_threshold: 1,
+        groups: &groups[..1],
+        iteration_exponent: 0,
+        extendable: false,
+    };
+    assert!(matches!(
+        split_master_secret(&master_secret[..15], "", &parameters, &mut CounterRng(0)),
+        Err(ErrorShare::SecretLength)
+    ));
+}
+
+#[cfg(feature = "electrum")]
+static KNOWN_ELECTRUM: &[(&str, ElectrumSeedType)] = &[
+    (
+        "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+        ElectrumSeedType::Standard,
+    ),
+    (
+        "bitter grass shiver impose acquire brush forget axis eager alone wine silver",
+        ElectrumSeedType::Segwit,
+    ),
+    (
+        "kiss live scene rude gate step hip quarter bunker oxygen motor glove",
+        ElectrumSeedType::TwoFactor,
+    ),
+    (
+        "universe topic remind silver february ranch shine worth innocent cattle enhance wise",
+        ElectrumSeedType::TwoFactorSegwit,
+    ),
+];
+
+#[cfg(feature = "electrum")]
+#[test]
+fn electrum_seed_types() {
+    for (phrase, seed_type) in KNOWN_ELECTRUM.iter() {
+        let (word_set, found) = WordSet::from_electrum_phrase(phrase).unwrap();
+        assert_eq!(found, *seed_type);
+        // no BIP-39 checksum to rely on
+        assert!(word_set.validate().is_err());
+
+        let entropy = word_set.to_electrum_entropy();
+        assert_eq!(entropy.len(), 17);
+        let round_trip = WordSet::from_electrum_entropy(&entropy).unwrap();
+        assert_eq!(round_trip, word_set);
+    }
+
+    let (word_set, _) = WordSet::from_electrum_phrase(
+        " Cycle rocket west magnet parrot shuffle\nfoot correct salt library feed song",
+    )
+    .unwrap();
+    assert_eq!(
+        word_set.electrum_seed_type().unwrap(),
+        Some(ElectrumSeedType::Standard)
+    );
+
+    let bip39 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    assert!(matches!(
+        WordSet::from_electrum_phrase(bip39),
+        Err(ErrorMnemonic::ElectrumVersion)
+    ));
+    assert!(matches!(
+        WordSet::from_electrum_phrase("cycle rocket westt"),
+        Err(ErrorMnemonic::NoWordAt { position: 2 })
+    ));
+}
+
+#[cfg(feature = "electrum")]
+#[test]
+fn electrum_entropy_words() {
+    // least significant word first
+    let word_set = WordSet::from_electrum_entropy(&[0x08, 0x01]).unwrap();
+    assert_eq!(word_set.to_indices(), vec![1, 1]);
+    assert_eq!(
+        word_set.to_electrum_entropy().as_slice(),
+        &[0x00, 0x08, 0x01]
+    );
+    // leading zero bytes make no words
+    let word_set = WordSet::from_electrum_entropy(&[0, 0, 0x05]).unwrap();
+    assert_eq!(word_set.to_indices(), vec![5]);
+
+    assert!(matches!(
+        WordSet::from_electrum_entropy(&[0, 0]),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(WordSet::from_electrum_entropy(&[0xFF; 33]).is_ok());
+    let mut too_long = [0u8; 34];
+    too_long[0] = 1;
+    assert!(matches!(
+        WordSet::from_electrum_entropy(&too_long),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+}
+
+#[cfg(all(feature = "electrum", feature = "generate"))]
+#[test]
+fn electrum_generate() {
+    for seed_type in ElectrumSeedType::ALL.iter() {
+        let word_set = WordSet::generate_electrum(*seed_type, &mut CounterRng(0));
+        assert_eq!(word_set.len(), 12);
+        assert_eq!(word_set.electrum_seed_type().unwrap(), Some(*seed_type));
+        assert!(word_set.validate().is_err());
+    }
+}
+
+#[cfg(all(feature = "electrum", feature = "seed"))]
+#[test]
+fn electrum_seed() {
+    use crate::electrum::normalize_electrum_text;
+
+    let (word_set, seed_type) = WordSet::from_electrum_phrase(
+        "wild father tree among universe such mobile favorite target dynamic credit identify",
+    )
+    .unwrap();
+    assert_eq!(seed_type, ElectrumSeedType::Segwit);
+    assert_eq!(
+        hex::encode(word_set.to_electrum_seed("").unwrap().as_bytes()),
+        "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756"
+    );
+    assert_eq!(
+        hex::encode(
+            word_set
+                .to_electrum_seed("Did you ever hear the tragedy of Darth Plagueis the Wise?")
+                .unwrap()
+                .as_bytes()
+        ),
+        "4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f"
+    );
+
+    assert_eq!(
+        normalize_electrum_text("  H\u{E9}llo \t W\u{F6}rld ").as_str(),
+        "hello world"
+    );
+    assert_eq!(
+        normalize_electrum_text("\u{4E2D} \u{6587}").as_str(),
+        "\u{4E2D}\u{6587}"
+    );
+    assert_eq!(
+        normalize_electrum_text("a \u{4E2D} b").as_str(),
+        "a \u{4E2D} b"
+    );
+
+    let bip39 = WordSet::from_phrase(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        &InternalWordList,
+    )
+    .unwrap();
+    assert!(matches!(
+        bip39.to_electrum_seed(""),
+        Err(ErrorMnemonic::ElectrumVersion)
+    ));
+}
+
+#[cfg(feature = "monero")]
+static KNOWN_MONERO: &[[&str; 2]] = &[
+    [
+        "82a13b87b69555ba976601302e2498aed4875185c87b9133bf8d214f16e9eb0b",
+        "reruns today hookup itself thorn nirvana symptoms jukebox patio unquoted sushi long diode digit rewind hacksaw obvious soothe nightly return agile hobby algebra awesome nirvana",
+    ],
+    [
+        "31e28ef4feca46915bdbf7b192af866e154cb7dbc704e9a39b6ce24ac89c1102",
+        "cafe aided wounded lumber hounded water yoyo gasp aerial merger ungainly gaze ruby yacht tell playful smash issued sifting whole erase anxiety dash deity sifting",
+    ],
+    [
+        "ea111187a598d5ab5fdabf8adb27df79005a106c7e3dc11797d77c4c48bace0b",
+        "fight hoisting uptight nibs womanly pepper does plotting dolphin fugitive popular chlorine turnip organs ambush people hospital ledge puppy anybody gourmet cuddled because candy womanly",
+    ],
+    [
+        "a6e0194a91f45a4f08633efc405e63d7c509d926759e7a9b7b945f235a8d300e",
+        "roped waist elapse cider reruns aggravate jetting bested azure omnibus hull economics depth reheat tobacco exit under locker money actress certain cupcake drinks examine reheat",
+    ],
+    [
+        "09ec1221eee3d94452d688e8894c0917b73d14dbcda3ef673b038a0874e5ee02",
+        "pigment mice pitched examine damp jobs going viewpoint terminal ultimate asylum cogs saved wayside stylishly asylum opposite after ghetto malady mural uphill maps metro pigment",
+    ],
+];
+
+#[cfg(feature = "monero")]
+#[test]
+fn monero_phrase_to_spend_key() {
+    use crate::monero::{InternalMoneroWordList, MoneroSpendKey};
+
+    for [spend_key, phrase] in KNOWN_MONERO.iter() {
+        let from_phrase = MoneroSpendKey::from_phrase(phrase, &InternalMoneroWordList).unwrap();
+        assert_eq!(hex::encode(from_phrase.as_bytes()), *spend_key);
+
+        let bytes: [u8; 32] = hex::decode(spend_key).unwrap().try_into().unwrap();
+        let to_phrase = MoneroSpendKey::from_bytes(&bytes)
+            .to_phrase(&InternalMoneroWordList)
+            .unwrap();
+        assert_eq!(to_phrase, *phrase);
+    }
+}
+
+#[cfg(feature = "monero")]
+#[test]
+fn monero_word_set_checks() {
+    use crate::monero::{InternalMoneroWordList, MoneroWordSet, MONERO_KEY_WORDS};
+
+    let phrase = KNOWN_MONERO[0][1];
+    let mut word_set = MoneroWordSet::new();
+    for word in phrase.split(' ') {
+        word_set.add_word(word, &InternalMoneroWordList).unwrap();
+    }
+    assert!(matches!(
+        word_set.add_word("abbey", &InternalMoneroWordList),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+    assert!(word_set.to_spend_key(&InternalMoneroWordList).is_ok());
+
+    // a checksum word with the wrong prefix
+    word_set.remove_last_word();
+    word_set.add_word("abbey", &InternalMoneroWordList).unwrap();
+    assert!(matches!(
+        word_set.to_spend_key(&InternalMoneroWordList),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+    word_set.remove_last_word();
+    assert!(matches!(
+        word_set.to_spend_key(&InternalMoneroWordList),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+
+    // word steps of 1625 and 1625 encode 1625 * 1626 * 1627, above `u32::MAX`
+    let mut word_set = MoneroWordSet::from_phrase(phrase, &InternalMoneroWordList).unwrap();
+    for (position, word) in ["abbey", "zoom", "zones"].iter().enumerate() {
+        word_set.indices[position] = InternalMoneroWordList.bits11_for_word(word).unwrap();
+    }
+    let checksum = word_set.checksum_word(&InternalMoneroWordList).unwrap();
+    word_set.indices[MONERO_KEY_WORDS] = checksum;
+    assert!(matches!(
+        word_set.to_spend_key(&InternalMoneroWordList),
+        Err(ErrorMnemonic::WordTriple)
+    ));
+
+    assert!(matches!(
+        MoneroWordSet::from_phrase("reruns today hookup", &InternalMoneroWordList),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        MoneroWordSet::from_phrase("reruns todayy", &InternalMoneroWordList),
+        Err(ErrorMnemonic::NoWordAt { position: 1 })
+    ));
+}
+
+#[cfg(feature = "monero")]
+#[test]
+fn monero_word_list_backends() {
+    use crate::cache::CachedWordList;
+    use crate::monero::{MoneroSpendKey, MoneroWordIndex, WORDLIST_MONERO_ENGLISH};
+
+    let custom_word_list =
+        CustomWordList::<MoneroWordIndex>::new_indexed(&WORDLIST_MONERO_ENGLISH).unwrap();
+    let cached_word_list = CachedWordList::<_, 25, MoneroWordIndex>::new(custom_word_list.clone());
+    for [spend_key, phrase] in KNOWN_MONERO.iter() {
+        let from_custom = MoneroSpendKey::from_phrase(phrase, &custom_word_list).unwrap();
+        assert_eq!(hex::encode(from_custom.as_bytes()), *spend_key);
+        let from_cached = MoneroSpendKey::from_phrase(phrase, &cached_word_list).unwrap();
+        assert_eq!(from_cached.to_phrase(&cached_word_list).unwrap(), *phrase);
+    }
+    assert_eq!(cached_word_list.cached(), 25);
+    assert_eq!(
+        custom_word_list
+            .get_word(MoneroWordIndex::from(1625).unwrap())
+            .unwrap(),
+        "zoom"
+    );
+    assert!(matches!(
+        CustomWordList::<MoneroWordIndex>::new_indexed(&WORDLIST_ENGLISH),
+        Err(WordlistAudit::WrongLength { len: 2048 })
+    ));
+
+    #[cfg(feature = "embedded-storage")]
+    {
+        use crate::raw::raw_wordlist_blob_indexed;
+        use crate::storage::StorageWordList;
+
+        let blob = raw_wordlist_blob_indexed::<MoneroWordIndex>(&WORDLIST_MONERO_ENGLISH).unwrap();
+        let storage_word_list =
+            StorageWordList::<_, MoneroWordIndex>::new_indexed(MockFlash(blob), 0).unwrap();
+        for [spend_key, phrase] in KNOWN_MONERO.iter() {
+            let from_storage = MoneroSpendKey::from_phrase(phrase, &storage_word_list).unwrap();
+            assert_eq!(hex::encode(from_storage.as_bytes()), *spend_key);
+        }
+        assert_eq!(storage_word_list.count_by_prefix("zo").unwrap(), 4);
+    }
+}
+
+#[cfg(feature = "aezeed")]
+#[test]
+fn aez_tiny_vectors() {
+    use crate::aezeed::aez::Aez;
+
+    let vectors: serde_json::Value =
+        serde_json::from_str(include_str!("../for_tests/aez_tiny_vectors.json")).unwrap();
+    for vector in vectors.as_array().unwrap() {
+        let key = hex::decode(vector["k"].as_str().unwrap()).unwrap();
+        let nonce = hex::decode(vector["nonce"].as_str().unwrap()).unwrap();
+        let data: Vec<Vec<u8>> = vector["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|element| hex::decode(element.as_str().unwrap()).unwrap())
+            .collect();
+        let ad: Vec<&[u8]> = data.iter().map(|element| &element[..]).collect();
+        let tau = vector["tau"].as_u64().unwrap() as usize;
+        let message = hex::decode(vector["m"].as_str().unwrap()).unwrap();
+        let ciphertext = vector["c"].as_str().unwrap();
+
+        let aez = Aez::new(&key);
+        let mut buffer = message.clone();
+        buffer.resize(message.len() + tau, 0);
+        aez.encrypt(&nonce, &ad, tau, &mut buffer);
+        assert_eq!(hex::encode(&buffer), ciphertext);
+
+        assert!(aez.decrypt(&nonce, &ad, tau, &mut buffer));
+        assert_eq!(buffer[..message.len()], message[..]);
+    }
+}
+
+// lnd aezeed test vectors, with entropy 81b637d86359e6960de795e41e0b4cfd and
+// salt "salt1": birthday, passphrase, phrase. lnd makes them with a scrypt N
+// of 16, log 4.
+#[cfg(feature = "aezeed")]
+const KNOWN_AEZEED: &[(u16, &str, &str)] = &[
+    (
+        0,
+        "",
+        "ability liquid travel stem barely drastic pact cupboard apple thrive morning oak feature tissue couch old math inform success suggest drink motion know royal",
+    ),
+    (
+        3365,
+        "!very_safe_55345_password*",
+        "able tree stool crush transfer cloud cross three profit outside hen citizen plate ride require leg siren drum success suggest drink require fiscal upgrade",
+    ),
+];
+
+#[cfg(feature = "aezeed")]
+const AEZEED_TEST_LOG_N: u8 = 4;
+
+#[cfg(feature = "aezeed")]
+#[test]
+fn aezeed_known_phrases() {
+    use crate::aezeed::{enciphered_from_word_set, word_set_from_enciphered, CipherSeed};
+
+    let entropy: [u8; 16] = hex::decode("81b637d86359e6960de795e41e0b4cfd")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    for (birthday, passphrase, phrase) in KNOWN_AEZEED.iter() {
+        let cipher_seed = CipherSeed::new(*birthday, &entropy, b"salt1");
+        let enciphered = cipher_seed
+            .encipher_with_cost(passphrase, AEZEED_TEST_LOG_N)
+            .unwrap();
+        let word_set = word_set_from_enciphered(&enciphered).unwrap();
+        assert_eq!(word_set.to_phrase(&InternalWordList).unwrap(), *phrase);
+
+        let word_set = WordSet::from_phrase(phrase, &InternalWordList).unwrap();
+        let enciphered = enciphered_from_word_set(&word_set).unwrap();
+        let decoded =
+            CipherSeed::decipher_with_cost(&enciphered, passphrase, AEZEED_TEST_LOG_N).unwrap();
+        assert_eq!(decoded.birthday(), *birthday);
+        assert_eq!(decoded.entropy(), &entropy);
+        assert_eq!(decoded.salt(), b"salt1");
+    }
+    // 2018-03-23 10:02 UTC
+    assert_eq!(CipherSeed::birthday_from_unix_time(1521799345), 3365);
+    assert_eq!(CipherSeed::birthday_from_unix_time(0), 0);
+}
+
+#[cfg(feature = "aezeed")]
+#[test]
+fn aezeed_phrase_round_trip() {
+    use crate::aezeed::CipherSeed;
+
+    let cipher_seed = CipherSeed::new(6000, &[0x5A; 16], b"salt2");
+    let phrase = cipher_seed.to_phrase("passphrase").unwrap();
+    let decoded = CipherSeed::from_phrase(&phrase, "passphrase").unwrap();
+    assert_eq!(decoded.birthday(), 6000);
+    assert_eq!(decoded.entropy(), &[0x5A; 16]);
+    assert_eq!(decoded.salt(), b"salt2");
+}
+
+#[cfg(feature = "aezeed")]
+#[test]
+fn aezeed_rejects_wrong_input() {
+    use crate::aezeed::{enciphered_from_word_set, CipherSeed};
+
+    let (_, passphrase, phrase) = KNOWN_AEZEED[1];
+    let mut word_set = WordSet::from_phrase(phrase, &InternalWordList).unwrap();
+    let enciphered = enciphered_from_word_set(&word_set).unwrap();
+    assert!(matches!(
+        CipherSeed::decipher_with_cost(&enciphered, "wrong password", AEZEED_TEST_LOG_N),
+        Err(ErrorMnemonic::AezeedDecryption)
+    ));
+
+    word_set.bits11_set[5] = Bits11::from(word_set.bits11_set[5].bits() ^ 1).unwrap();
+    assert!(matches!(
+        CipherSeed::from_word_set(&word_set, passphrase),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+
+    word_set.remove_last_word();
+    assert!(matches!(
+        CipherSeed::from_word_set(&word_set, passphrase),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+
+    let mut enciphered = CipherSeed::new(7, &[0xAB; 16], b"salt2")
+        .encipher_with_cost(passphrase, AEZEED_TEST_LOG_N)
+        .unwrap();
+    enciphered[0] = 1;
+    assert!(matches!(
+        CipherSeed::decipher(&enciphered, passphrase),
+        Err(ErrorMnemonic::AezeedVersion)
+    ));
+}
+
+#[cfg(all(feature = "aezeed", feature = "generate"))]
+#[test]
+fn aezeed_generate() {
+    use crate::aezeed::CipherSeed;
+
+    let cipher_seed = CipherSeed::generate(42, &mut CounterRng(0));
+    assert_eq!(cipher_seed.birthday(), 42);
+    assert_eq!(cipher_seed.entropy()[..], (0..16).collect::<Vec<u8>>()[..]);
+    assert_eq!(cipher_seed.salt(), &[16, 17, 18, 19, 20]);
+}
+
+// SeedQR examples from the SeedSigner spec: phrase, SeedQR digits,
+// CompactSeedQR bytes.
+#[cfg(feature = "english-wordlist")]
+const KNOWN_SEEDQR: &[[&str; 3]] = &[
+    [
+        "forum undo fragile fade shy sign arrest garment culture tube off merit",
+        "073318950739065415961602009907670428187212261116",
+        "5bbd9d71a8ec7990831aff359d426545",
+    ],
+    [
+        "attack pizza motion avocado network gather crop fresh patrol unusual wild holiday candy pony ranch winter theme error hybrid van cereal salon goddess expire",
+        "011513251154012711900771041507421289190620080870026613431420201617920614089619290300152408010643",
+        "0e74b64107f94cc0ccfae6a13dcbec3662154fec67e0e00999c07892597d190a",
+    ],
+];
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn seedqr_known() {
+    for [phrase, digits, compact] in KNOWN_SEEDQR.iter() {
+        let word_set = WordSet::from_phrase(phrase, &InternalWordList).unwrap();
+        assert_eq!(*word_set.to_seedqr().unwrap(), **digits);
+        assert_eq!(
+            hex::encode(&word_set.to_compact_seedqr().unwrap()[..]),
+            *compact
+        );
+
+        assert_eq!(WordSet::from_seedqr(digits).unwrap(), word_set);
+        assert_eq!(
+            WordSet::from_compact_seedqr(&hex::decode(compact).unwrap()).unwrap(),
+            word_set
+        );
+    }
+}
+
+#[test]
+fn seedqr_rejects_malformed() {
+    let digits = "073318950739065415961602009907670428187212261116";
+    assert!(matches!(
+        WordSet::from_seedqr(&digits[..47]),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        WordSet::from_seedqr(&digits[..44]),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    assert!(matches!(
+        WordSet::from_seedqr(&digits.replace("0733", "07a3")),
+        Err(ErrorMnemonic::SeedQrDigits)
+    ));
+    assert!(matches!(
+        WordSet::from_seedqr(&digits.replace("0733", "2048")),
+        Err(ErrorMnemonic::InvalidWordNumber)
+    ));
+    assert!(matches!(
+        WordSet::from_compact_seedqr(&[0u8; 15]),
+        Err(ErrorMnemonic::EntropyTooShort { len: 15 })
+    ));
+    assert!(matches!(
+        WordSet::new().to_seedqr(),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[cfg(feature = "bip85")]
+const BIP85_ROOT: &str = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+
+#[cfg(feature = "bip85")]
+#[test]
+fn bip85_entropy() {
+    use crate::bip32::ExtendedPrivateKey;
+    use crate::bip85::derive_entropy;
+
+    let root = ExtendedPrivateKey::from_xprv(BIP85_ROOT).unwrap();
+    for (path, key, entropy) in [
+        (
+            [0, 0],
+            "cca20ccb0e9a90feb0912870c3323b24874b0ca3d8018c4b96d0b97c0e82ded0",
+            "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7",
+        ),
+        (
+            [0, 1],
+            "503776919131758bb7de7beb6c0ae24894f4ec042c26032890c29359216e21ba",
+            "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e",
+        ),
+    ] {
+        let derived = root
+            .derive_hardened(crate::bip85::BIP85_PURPOSE)
+            .unwrap()
+            .derive_hardened_path(&path)
+            .unwrap();
+        assert_eq!(hex::encode(derived.secret_bytes()), key);
+        assert_eq!(hex::encode(&derive_entropy(&root, &path).unwrap()[..]), entropy);
+    }
+}
+
+#[cfg(feature = "bip85")]
+#[test]
+fn bip85_child_mnemonics() {
+    use crate::bip32::ExtendedPrivateKey;
+    use crate::regular::Language;
+
+    let root = ExtendedPrivateKey::from_xprv(BIP85_ROOT).unwrap();
+    for (mnemonic_type, phrase) in [
+        (
+            MnemonicType::Words12,
+            "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+        ),
+        (
+            MnemonicType::Words18,
+            "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token",
+        ),
+        (
+            MnemonicType::Words24,
+            "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+        ),
+    ] {
+        let word_set = WordSet::from_bip85(&root, Language::English, mnemonic_type, 0).unwrap();
+        assert_eq!(word_set.to_phrase(&InternalWordList).unwrap(), phrase);
+    }
+}
+
+#[cfg(feature = "bip85")]
+#[test]
+fn bip85_rejects_bad_root() {
+    use crate::bip32::{ExtendedPrivateKey, HARDENED};
+
+    // last character changed: the checksum fails
+    let mut damaged = String::from(&BIP85_ROOT[..BIP85_ROOT.len() - 1]);
+    damaged.push('c');
+    assert!(matches!(
+        ExtendedPrivateKey::from_xprv(&damaged),
+        Err(ErrorMnemonic::InvalidExtendedKey)
+    ));
+    assert!(matches!(
+        ExtendedPrivateKey::from_xprv("xprv0"),
+        Err(ErrorMnemonic::InvalidExtendedKey)
+    ));
+
+    let root = ExtendedPrivateKey::from_xprv(BIP85_ROOT).unwrap();
+    assert!(matches!(
+        root.derive_hardened(HARDENED),
+        Err(ErrorMnemonic::InvalidExtendedKey)
+    ));
+}
+
+#[test]
+fn fixed_word_set_matches_word_set() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let fixed = WordSetFixed::<MAX_SEED_LEN>::from_entropy(&entropy).unwrap();
+        assert_eq!(
+            fixed.to_word_set(),
+            WordSet::from_entropy(&entropy).unwrap()
+        );
+
+        let mut buffer = [0u8; MAX_SEED_LEN * (WORD_MAX_LEN + SEPARATOR_LEN)];
+        assert_eq!(
+            fixed
+                .phrase_into(&flash_mock_word_list, &mut buffer)
+                .unwrap(),
+            known[0]
+        );
+
+        let mut entered = WordSetFixed::<MAX_SEED_LEN>::new();
+        for word in known[0].split(' ') {
+            entered.add_word(word, &flash_mock_word_list).unwrap();
+        }
+        assert_eq!(entered, fixed);
+        entered.validate().unwrap();
+        let mut out = [0u8; MAX_ENTROPY_LEN];
+        let len = entered.to_entropy_into(&mut out).unwrap();
+        assert_eq!(&out[..len], &entropy[..]);
+    }
+}
+
+#[test]
+fn fixed_word_set_limits() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let mut short = WordSetFixed::<12>::new();
+    for word in KNOWN[0][0].split(' ') {
+        short.add_word(word, &flash_mock_word_list).unwrap();
+    }
+    assert!(matches!(
+        short.add_word("abandon", &flash_mock_word_list),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+    assert!(matches!(
+        WordSetFixed::<12>::from_entropy(&[0u8; 32]),
+        Err(ErrorMnemonic::TooManyWords)
+    ));
+
+    let mut buffer = [0u8; 16];
+    assert!(matches!(
+        short.phrase_into(&flash_mock_word_list, &mut buffer),
+        Err(ErrorMnemonic::PhraseWrite)
+    ));
+    assert_eq!(buffer, [0u8; 16]);
+
+    // "about" swapped for "abandon"
+    short.remove_last_word().unwrap();
+    short.add_word("abandon", &flash_mock_word_list).unwrap();
+    assert!(matches!(
+        short.validate(),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+    short.remove_last_word().unwrap();
+    assert!(matches!(short.validate(), Err(ErrorMnemonic::WordsNumber)));
+    short.clear();
+    assert!(short.is_empty());
+}
+
+// Every future here is ready on first poll, no executor is needed.
+#[cfg(feature = "async-wordlist")]
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    use core::task::{Context, Poll, Waker};
+
+    let mut future = core::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[cfg(feature = "async-wordlist")]
+#[test]
+fn async_word_list_matches_sync() {
+    use crate::async_wordlist::AsWordListAsync;
+
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    for known in KNOWN {
+        let mut word_set = WordSet::new();
+        for word in known[0].split(' ') {
+            block_on(word_set.add_word_async(word, &flash_mock_word_list)).unwrap();
+        }
+        assert_eq!(
+            word_set,
+            WordSet::from_phrase(known[0], &flash_mock_word_list).unwrap()
+        );
+        assert_eq!(
+            block_on(word_set.to_phrase_async(&flash_mock_word_list)).unwrap(),
+            known[0]
+        );
+    }
+
+    let words = block_on(AsWordListAsync::get_words_by_prefix(
+        &flash_mock_word_list,
+        "zo",
+    ))
+    .unwrap();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0].word, "zone");
+    assert!(matches!(
+        block_on(AsWordListAsync::bits11_for_word(
+            &flash_mock_word_list,
+            "zzz"
+        )),
+        Err(ErrorMnemonic::NoWord)
+    ));
+}
+
+#[test]
+fn checksum_hasher_is_used() {
+    // software SHA-256 that counts its calls, in place of a peripheral
+    struct CountingHasher(usize);
+
+    impl ChecksumHasher for CountingHasher {
+        fn sha256_first_byte(&mut self, input: &[u8]) -> u8 {
+            self.0 += 1;
+            Sha2Hasher.sha256_first_byte(input)
+        }
+    }
+
+    let mut hasher = CountingHasher(0);
+    for known in KNOWN {
+        let entropy = hex::decode(known[1]).unwrap();
+        let word_set = WordSet::from_entropy_with_hasher(&entropy, &mut hasher).unwrap();
+        assert_eq!(word_set, WordSet::from_entropy(&entropy).unwrap());
+        assert_eq!(
+            word_set.to_entropy_with_hasher(&mut hasher).unwrap(),
+            entropy
+        );
+        word_set.validate_with_hasher(&mut hasher).unwrap();
+    }
+    assert!(hasher.0 >= 3 * KNOWN.len());
+
+    // a hasher that disagrees with SHA-256 rejects every valid phrase
+    struct WrongHasher;
+
+    impl ChecksumHasher for WrongHasher {
+        fn sha256_first_byte(&mut self, input: &[u8]) -> u8 {
+            !Sha2Hasher.sha256_first_byte(input)
+        }
+    }
+
+    let word_set = WordSet::from_entropy(&hex::decode(KNOWN[0][1]).unwrap()).unwrap();
+    assert!(matches!(
+        word_set.validate_with_hasher(&mut WrongHasher),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+}
+
+#[test]
+fn flash_mock_unique_prefixes() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let original = WordSet::from_phrase(KNOWN[8][0], &flash_mock_word_list).unwrap();
+
+    // every word cut to its first four letters
+    let mut word_set = WordSet::new();
+    for word in KNOWN[8][0].split(' ') {
+        let prefix: String = word.chars().take(4).collect();
+        word_set
+            .add_word_prefix(&prefix, &flash_mock_word_list)
+            .unwrap();
+    }
+    assert_eq!(word_set, original);
+
+    // a whole word wins over the longer words it starts
+    assert_eq!(
+        flash_mock_word_list
+            .bits11_for_unique_prefix("act")
+            .unwrap(),
+        flash_mock_word_list.bits11_for_word("act").unwrap()
+    );
+    assert!(matches!(
+        flash_mock_word_list.bits11_for_unique_prefix("ab"),
+        Err(ErrorMnemonic::AmbiguousPrefix)
+    ));
+    assert!(matches!(
+        flash_mock_word_list.bits11_for_unique_prefix("qz"),
+        Err(ErrorMnemonic::NoWord)
+    ));
+}
+
+#[test]
+fn flash_mock_check_phrase() {
+    fill_flash_mock();
+    let flash_mock_word_list = FlashMockWordList;
+    let check = check_phrase(KNOWN[8][0], &flash_mock_word_list).unwrap();
+    assert!(check.is_valid());
+    assert_eq!(check.word_count, 24);
+    assert!(check.unknown_positions.is_empty());
+
+    let mut words: Vec<&str> = KNOWN[8][0].split(' ').collect();
+    words[3] = "abandom";
+    words[17] = "qqq";
+    let check = check_phrase(&words.join(" "), &flash_mock_word_list).unwrap();
+    assert_eq!(check.unknown_positions, [3, 17]);
+    assert_eq!(check.word_count, 24);
+    assert_eq!(check.checksum_valid, None);
+
+    let check = check_phrase(
+        &KNOWN[0][0].replace("about", "abandon"),
+        &flash_mock_word_list,
+    )
+    .unwrap();
+    assert_eq!(check.checksum_valid, Some(false));
+    let check = check_phrase("abandon abandon", &flash_mock_word_list).unwrap();
+    assert_eq!(check.checksum_valid, None);
+    assert!(!check.is_valid());
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_word_set_lifecycle() {
+    use crate::ffi::*;
+    use core::ffi::CStr;
+
+    unsafe {
+        let set = mnemonic_wordset_new();
+        for word in KNOWN[8][0].split(' ') {
+            let word = format!("{word}\0");
+            let word = CStr::from_bytes_with_nul(word.as_bytes()).unwrap();
+            assert_eq!(
+                mnemonic_wordset_add_word(set, word.as_ptr()),
+                MnemonicStatus::Ok
+            );
+        }
+        assert_eq!(mnemonic_wordset_len(set), 24);
+        assert!(mnemonic_wordset_is_finalizable(set));
+        assert_eq!(mnemonic_wordset_validate(set), MnemonicStatus::Ok);
+
+        let mut out = [0u8; 32];
+        let mut written = 0usize;
+        assert_eq!(
+            mnemonic_wordset_to_entropy(set, out.as_mut_ptr(), 31, &mut written),
+            MnemonicStatus::BufferTooSmall
+        );
+        assert_eq!(
+            mnemonic_wordset_to_entropy(set, out.as_mut_ptr(), out.len(), &mut written),
+            MnemonicStatus::Ok
+        );
+        assert_eq!(out[..written], hex::decode(KNOWN[8][1]).unwrap()[..]);
+
+        let typo = c"abandom";
+        assert_eq!(
+            mnemonic_wordset_add_word(set, typo.as_ptr()),
+            MnemonicStatus::NoWord
+        );
+        assert!(mnemonic_wordset_remove_last_word(set));
+        assert_eq!(mnemonic_wordset_validate(set), MnemonicStatus::WordsNumber);
+        mnemonic_wordset_free(set);
+
+        assert_eq!(
+            mnemonic_wordset_add_word(core::ptr::null_mut(), typo.as_ptr()),
+            MnemonicStatus::NullPointer
+        );
+        mnemonic_wordset_free(core::ptr::null_mut());
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_words_by_prefix() {
+    use crate::ffi::*;
+    use core::ffi::c_void;
+
+    unsafe extern "C" fn collect(
+        user_data: *mut c_void,
+        index: u16,
+        word: *const u8,
+        word_len: usize,
+    ) -> bool {
+        let found = &mut *(user_data as *mut Vec<(u16, String)>);
+        let word = core::slice::from_raw_parts(word, word_len);
+        found.push((index, String::from_utf8(word.to_vec()).unwrap()));
+        found.len() < 3
+    }
+
+    let mut found: Vec<(u16, String)> = Vec::new();
+    let prefix = c"ab";
+    let status = unsafe {
+        mnemonic_words_by_prefix(
+            prefix.as_ptr(),
+            Some(collect),
+            &mut found as *mut Vec<(u16, String)> as *mut c_void,
+        )
+    };
+    assert_eq!(status, MnemonicStatus::Ok);
+    assert_eq!(
+        found,
+        [
+            (0, String::from("abandon")),
+            (1, String::from("ability")),
+            (2, String::from("able"))
+        ]
+    );
+}
+
+// error paths build a JS `Error` and only run on wasm32
+#[cfg(feature = "wasm")]
+#[test]
+fn wasm_word_set_round_trip() {
+    use crate::wasm::*;
+
+    let entropy = [0x7fu8; 16];
+    let phrase = phrase_from_entropy(&entropy).unwrap();
+    assert_eq!(entropy_from_phrase(&phrase).unwrap(), entropy);
+
+    let mut word_set = WasmWordSet::new();
+    for word in phrase.split(' ') {
+        word_set.add_word(word).unwrap();
+    }
+    assert_eq!(word_set.length(), 12);
+    assert!(word_set.is_finalizable());
+    word_set.validate().unwrap();
+    assert_eq!(word_set.to_phrase().unwrap(), phrase);
+    assert_eq!(word_set.to_entropy().unwrap(), entropy);
+    assert_eq!(
+        word_set.remove_last_word(),
+        Some(index_of(phrase.rsplit(' ').next().unwrap()).unwrap())
+    );
+
+    assert_eq!(word_at(0).unwrap(), "abandon");
+    assert_eq!(words_by_prefix("zo").unwrap(), ["zone", "zoo"]);
+}
+
+#[cfg(feature = "uniffi")]
+#[test]
+fn uniffi_word_set_lifecycle() {
+    use crate::mobile::*;
+
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    validate_phrase(String::from(phrase)).unwrap();
+    assert!(matches!(
+        validate_phrase(phrase.replace("yellow", "year")),
+        Err(ErrorMnemonic::InvalidChecksum)
+    ));
+
+    let word_set = MobileWordSet::new();
+    for word in phrase.split(' ').take(11) {
+        word_set.add_word(String::from(word)).unwrap();
+    }
+    assert!(!word_set.is_finalizable());
+    assert!(word_set
+        .valid_last_words()
+        .unwrap()
+        .contains(&String::from("yellow")));
+    word_set.add_word(String::from("yellow")).unwrap();
+    word_set.validate().unwrap();
+    assert_eq!(word_set.len(), 12);
+    assert_eq!(word_set.to_phrase().unwrap(), phrase);
+    assert_eq!(
+        MobileWordSet::from_phrase(String::from(phrase))
+            .unwrap()
+            .to_phrase()
+            .unwrap(),
+        phrase
+    );
+    word_set.clear();
+    assert!(word_set.is_empty());
+
+    assert_eq!(
+        words_by_prefix(String::from("zo")).unwrap(),
+        ["zone", "zoo"]
+    );
+}
+
+#[test]
+fn dice_rolls_pack_bits() {
+    use crate::entropy::DiceRolls;
+
+    let mut dice = DiceRolls::new(MnemonicType::Words12);
+    assert!(matches!(dice.roll(0), Err(ErrorMnemonic::DiceRoll)));
+    assert!(matches!(dice.roll(7), Err(ErrorMnemonic::DiceRoll)));
+    // 01, 10, 1, 0, 11
+    for value in [2, 3, 6, 5, 4] {
+        dice.roll(value).unwrap();
+    }
+    assert_eq!(dice.bits_collected(), 8);
+    assert_eq!(dice.bits_remaining(), 120);
+    assert!(matches!(
+        dice.entropy(),
+        Err(ErrorMnemonic::EntropyIncomplete { missing_bits: 120 })
+    ));
+    while !dice.is_complete() {
+        dice.roll(1).unwrap();
+    }
+    assert_eq!(dice.rolls(), 65);
+    dice.roll(6).unwrap();
+    assert_eq!(dice.rolls(), 65);
+    let mut expected = [0u8; 16];
+    expected[0] = 0x6b;
+    assert_eq!(dice.entropy().unwrap(), expected);
+    assert_eq!(dice.to_word_set().unwrap().to_entropy().unwrap(), expected);
+}
+
+#[test]
+fn dice_rolls_fill_every_length() {
+    use crate::entropy::DiceRolls;
+
+    for word_count in [12, 15, 18, 21, 24] {
+        let mnemonic_type = MnemonicType::from_word_count(word_count).unwrap();
+        let mut dice = DiceRolls::new(mnemonic_type);
+        while !dice.is_complete() {
+            dice.roll(6).unwrap();
+        }
+        assert_eq!(dice.rolls(), mnemonic_type.entropy_bits());
+        let entropy = dice.entropy().unwrap();
+        assert_eq!(entropy.len(), mnemonic_type.entropy_bytes());
+        assert!(entropy.iter().all(|byte| *byte == 0xff));
+        assert_eq!(dice.to_word_set().unwrap().len(), word_count);
+    }
+}
+
+#[test]
+fn coin_flips_known_vector() {
+    use crate::entropy::CoinFlips;
+
+    fill_flash_mock();
+    // 0x7f7f...: tails then seven heads, sixteen times
+    let mut coins = CoinFlips::new(MnemonicType::Words12);
+    assert_eq!(coins.flips_remaining(), 128);
+    for flip in 0..128 {
+        assert!(matches!(
+            coins.entropy(),
+            Err(ErrorMnemonic::EntropyIncomplete { .. })
+        ));
+        coins.flip(flip % 8 != 0);
+    }
+    assert!(coins.is_complete());
+    coins.flip(false);
+    assert_eq!(coins.flips(), 128);
+    assert_eq!(coins.entropy().unwrap(), hex::decode(KNOWN[1][1]).unwrap());
+    assert_eq!(
+        coins
+            .to_word_set()
+            .unwrap()
+            .to_phrase(&FlashMockWordList)
+            .unwrap(),
+        KNOWN[1][0]
+    );
+}
+
+#[test]
+fn hex_entropy_round_trip() {
+    use crate::entropy::HexCase;
+
+    for [_, hex] in KNOWN {
+        let word_set = WordSet::from_hex_entropy(hex).unwrap();
+        assert_eq!(word_set.to_entropy().unwrap(), hex::decode(hex).unwrap());
+        assert_eq!(*word_set.entropy_hex(HexCase::Lower).unwrap(), *hex);
+        let upper = word_set.entropy_hex(HexCase::Upper).unwrap();
+        assert_eq!(*upper, hex.to_uppercase());
+        assert!(WordSet::from_hex_entropy(&upper).unwrap() == word_set);
+    }
+    for hex in [
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7",
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7g",
+    ] {
+        assert!(matches!(
+            WordSet::from_hex_entropy(hex),
+            Err(ErrorMnemonic::InvalidHex)
+        ));
+    }
+    assert!(matches!(
+        WordSet::from_hex_entropy(&"00".repeat(33)),
+        Err(ErrorMnemonic::EntropyTooLong { len: 33 })
+    ));
+    assert!(matches!(
+        WordSet::from_hex_entropy("00"),
+        Err(ErrorMnemonic::EntropyTooShort { len: 1 })
+    ));
+}
+
+#[test]
+fn entry_progress_during_entry() {
+    let entropy = hex::decode(KNOWN[1][1]).unwrap();
+    let mut word_set = WordSet::from_entropy(&entropy).unwrap();
+    let progress = word_set.validate_progress();
+    assert!(progress.is_valid());
+    assert_eq!(progress.reachable, MnemonicType::ALL);
+    assert_eq!(progress.words_to_next_length(), Some(0));
+
+    word_set.bits11_set[11] = Bits11::from(word_set.bits11_set[11].bits() ^ 1).unwrap();
+    assert_eq!(word_set.validate_progress().checksum_valid, Some(false));
+
+    word_set.bits11_set.push(Bits11::from(0).unwrap());
+    assert_eq!(
+        word_set.validate_progress(),
+        EntryProgress {
+            word_count: 13,
+            checksum_valid: None,
+            reachable: MnemonicType::ALL[1..].to_vec(),
+        }
+    );
+    assert_eq!(word_set.validate_progress().words_to_next_length(), Some(2));
+
+    while word_set.len() < 25 {
+        word_set.bits11_set.push(Bits11::from(0).unwrap());
+    }
+    let progress = word_set.validate_progress();
+    assert!(progress.reachable.is_empty());
+    assert_eq!(progress.words_to_next_length(), None);
+}
+
+#[test]
+fn xor_combine_known_shares() {
+    use crate::xor::xor_combine;
+
+    // 0x7f.. ^ 0x80.. = 0xff..
+    let shares = [
+        WordSet::from_hex_entropy(KNOWN[1][1]).unwrap(),
+        WordSet::from_hex_entropy(KNOWN[2][1]).unwrap(),
+    ];
+    let combined = xor_combine(&shares).unwrap();
+    assert!(combined == WordSet::from_hex_entropy(&"ff".repeat(16)).unwrap());
+    let all_three = [combined, shares[0].clone(), shares[1].clone()];
+    assert!(xor_combine(&all_three).unwrap() == WordSet::from_hex_entropy(KNOWN[0][1]).unwrap());
+
+    assert!(matches!(
+        xor_combine(&shares[..1]),
+        Err(ErrorMnemonic::XorShares)
+    ));
+    let longer = WordSet::from_hex_entropy(&"00".repeat(32)).unwrap();
+    assert!(matches!(
+        xor_combine(&[shares[0].clone(), longer]),
+        Err(ErrorMnemonic::XorShares)
+    ));
+}
+
+#[cfg(feature = "generate")]
+#[test]
+fn xor_split_round_trip() {
+    use crate::xor::{xor_combine, xor_split};
+
+    for mnemonic_type in MnemonicType::ALL {
+        let word_set = WordSet::generate(mnemonic_type, &mut CounterRng(3));
+        for count in 2..5 {
+            let shares = xor_split(&word_set, count, &mut CounterRng(100)).unwrap();
+            assert_eq!(shares.len(), count);
+            for share in shares.iter() {
+                assert_eq!(share.mnemonic_type().unwrap(), mnemonic_type);
+                share.validate().unwrap();
+                assert!(*share != word_set);
+            }
+            assert!(xor_combine(&shares).unwrap() == word_set);
+            assert!(!matches!(xor_combine(&shares[1..]), Ok(partial) if partial == word_set));
+        }
+    }
+    assert!(matches!(
+        xor_split(&WordSet::new(), 2, &mut CounterRng(0)),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+    let word_set = WordSet::generate(MnemonicType::Words12, &mut CounterRng(0));
+    assert!(matches!(
+        xor_split(&word_set, 1, &mut CounterRng(0)),
+        Err(ErrorMnemonic::XorShares)
+    ));
+}
+
+#[cfg(feature = "cardano")]
+#[test]
+fn icarus_master_key_matches_cip3() {
+    // "eight country switch draw meat scout mystery blade tip drift useless
+    // good keep usage title"
+    let word_set = WordSet::from_hex_entropy("46e62370a138a182a498b8e2885bc032379ddf38").unwrap();
+    for (passphrase, expected) in [
+        ("", "c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620"),
+        ("foo", "70531039904019351e1afb361cd1b312a4d0565d4ff9f8062d38acf4b15cce41d7b5738d9c893feea55512a3004acb0d222c35d3e3d5cde943a15a9824cbac59443cf67e589614076ba01e354b1a432e0e6db3b59e37fc56b5fb0222970a010e"),
+    ] {
+        let key = word_set.to_icarus_master_key(passphrase).unwrap();
+        assert_eq!(hex::encode(key.as_bytes()), expected);
+        assert_eq!(key.extended_secret().len() + key.chain_code().len(), 96);
+    }
+    assert!(matches!(
+        WordSet::new().to_icarus_master_key(""),
+        Err(ErrorMnemonic::WordsNumber)
+    ));
+}
+
+#[cfg(all(feature = "french", feature = "spanish", feature = "nfkd"))]
+#[test]
+fn internal_ignore_accents_lookup() {
+    use crate::regular::{InternalWordListFrench, InternalWordListSpanish};
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+    fn plain(text: &str) -> String {
+        text.nfkd().filter(|c| !is_combining_mark(*c)).collect()
+    }
+    // every word is found from its unaccented spelling
+    fn check_all<L: AsWordList>(wordlist: &L) {
+        for bits_u16 in 0..TOTAL_WORDS as u16 {
+            let word = wordlist.get_word(Bits11::from(bits_u16).unwrap()).unwrap();
+            let found =
+                resolve_word(&plain(word.as_ref()), wordlist, ResolveMode::IgnoreAccents).unwrap();
+            assert_eq!(found.bits(), bits_u16);
+        }
+    }
+
+    let spanish = InternalWordListSpanish;
+    let resolve = |input: &str, mode: ResolveMode| {
+        resolve_word(input, &spanish, mode).map(|bits11| bits11.bits())
+    };
+    assert!(resolve("abaco", ResolveMode::Nfkd).is_err());
+    assert_eq!(resolve("abaco", ResolveMode::IgnoreAccents).unwrap(), 0);
+    assert_eq!(resolve(" ABACO ", ResolveMode::IgnoreAccents).unwrap(), 0);
+    assert_eq!(
+        resolve("\u{E1}baco", ResolveMode::IgnoreAccents).unwrap(),
+        0
+    );
+    assert_eq!(
+        resolve("\u{E0}baco", ResolveMode::IgnoreAccents).unwrap(),
+        0
+    );
+
+    check_all(&spanish);
+    check_all(&InternalWordListFrench);
+
+    let entropy = hex::decode(KNOWN[0][1]).unwrap();
+    let phrase = WordSet::from_entropy(&entropy)
+        .unwrap()
+        .to_phrase(&spanish)
+        .unwrap();
+    let phrase = plain(&phrase);
+    assert!(WordSet::from_phrase_with_mode(&phrase, &spanish, ResolveMode::Strict).is_err());
+    let parsed = WordSet::from_phrase(&phrase, &spanish).unwrap();
+    assert_eq!(parsed.to_entropy().unwrap(), entropy);
+}
+
+#[cfg(feature = "english-wordlist")]
+#[test]
+fn typed_phrase_accepted_by_every_entry_point() {
+    use crate::regular::{detect_language, Language};
+
+    let phrase = KNOWN[20][0];
+    let typed = phrase
+        .split(' ')
+        .map(|word| {
+            let (first, rest) = word.split_at(1);
+            first.to_uppercase() + rest
+        })
+        .collect::<Vec<String>>()
+        .join("  ");
+    let expected = WordSet::from_phrase(phrase, &InternalWordList).unwrap();
+
+    assert_eq!(
+        WordSet::from_phrase(&typed, &InternalWordList).unwrap(),
+        expected
+    );
+    let check = check_phrase(&typed, &InternalWordList).unwrap();
+    assert!(check.unknown_positions.is_empty());
+    assert!(check.is_valid());
+
+    let mut added = WordSet::new();
+    let mut prefixed = WordSet::new();
+    for word in typed.split_whitespace() {
+        added.add_word(word, &InternalWordList).unwrap();
+        let prefix: String = word.chars().take(4).collect();
+        prefixed
+            .add_word_prefix(&format!(" {prefix} "), &InternalWordList)
+            .unwrap();
+    }
+    assert_eq!(added, expected);
+    assert_eq!(prefixed, expected);
+
+    let entropy_len = expected.to_entropy().unwrap().len();
+    let mut replaced = WordSet::from_entropy(&vec![0xff; entropy_len]).unwrap();
+    for (position, word) in typed.split_whitespace().enumerate() {
+        replaced
+            .replace_word(position, word, &InternalWordList)
+            .unwrap();
+    }
+    assert_eq!(replaced, expected);
+
+    assert!(detect_language(&typed).contains(&Language::English));
+    assert!(Language::English.contains_word("Abandon"));
+    assert_eq!(
+        Language::English.word_set_from_phrase(&typed).unwrap(),
+        expected
+    );
+}
+
+#[cfg(all(feature = "spanish", feature = "nfkd"))]
+#[test]
+fn unaccented_phrase_detected() {
+    use crate::regular::{detect_language, InternalWordListSpanish, Language};
+
+    let entropy = hex::decode(KNOWN[1][1]).unwrap();
+    let phrase = WordSet::from_entropy(&entropy)
+        .unwrap()
+        .to_phrase(&InternalWordListSpanish)
+        .unwrap();
+    let plain: String = phrase
+        .chars()
+        .filter(|c| !unicode_normalization::char::is_combining_mark(*c))
+        .collect();
+    assert!(check_phrase(&plain, &InternalWordListSpanish)
+        .unwrap()
+        .is_valid());
+    assert_eq!(detect_language(&plain), [Language::Spanish]);
+    assert_eq!(
+        Language::Spanish
+            .word_set_from_phrase(&plain)
+            .unwrap()
+            .to_entropy()
+            .unwrap(),
+        entropy
+    );
+}
+
+#[cfg(all(
+    feature = "nfkd",
+    feature = "chinese-simplified",
+    feature = "chinese-traditional",
+    feature = "czech",
+    feature = "french",
+    feature = "italian",
+    feature = "japanese",
+    feature = "korean",
+    feature = "portuguese",
+    feature = "spanish",
+))]
+#[test]
+fn internal_lists_know_their_accents() {
+    use crate::regular::*;
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+    fn check<L: AsWordList>(wordlist: &L) {
+        let accents = (0..TOTAL_WORDS as u16).any(|bits_u16| {
+            let word = wordlist.get_word(Bits11::from(bits_u16).unwrap()).unwrap();
+            word.as_ref().nfkd().any(is_combining_mark)
+        });
+        assert_eq!(wordlist.has_accents(), accents);
+    }
+    check(&InternalWordList);
+    check(&InternalWordListChineseSimplified);
+    check(&InternalWordListChineseTraditional);
+    check(&InternalWordListCzech);
+    check(&InternalWordListFrench);
+    check(&InternalWordListItalian);
+    check(&InternalWordListJapanese);
+    check(&InternalWordListKorean);
+    check(&InternalWordListPortuguese);
+    check(&InternalWordListSpanish);
+}
+
+#[cfg(feature = "nfkd")]
+#[test]
+fn raw_word_list_reads_accents_once() {
+    use core::cell::Cell;
+
+    let blob = raw_wordlist_blob(&WORDLIST_ENGLISH).unwrap();
+    let reads = Cell::new(0usize);
+    let read = |offset: usize, buffer: &mut [u8]| -> Result<(), ()> {
+        reads.set(reads.get() + 1);
+        let source = blob.get(offset..offset + buffer.len()).ok_or(())?;
+        buffer.copy_from_slice(source);
+        Ok(())
+    };
+    let raw_word_list = RawWordList::new(read).unwrap();
+    let miss = || {
+        let before = reads.get();
+        assert!(matches!(
+            resolve_word("abandom", &raw_word_list, ResolveMode::IgnoreAccents),
+            Err(ErrorMnemonic::NoWord)
+        ));
+        reads.get() - before
+    };
+    // the first miss also reads the list for accents, later ones do not
+    let first = miss();
+    assert_eq!(first - miss(), TOTAL_WORDS);
+    assert!(!raw_word_list.has_accents());
+    assert_eq!(
+        resolve_word("\u{E1}bandon", &raw_word_list, ResolveMode::IgnoreAccents)
+            .unwrap()
+            .bits(),
+        0
+    );
+}
@@ -0,0 +1,80 @@
+use sha2::{Digest, Sha256};
+
+use crate::ct::ct_eq_bytes;
+use crate::error::ErrorMnemonic;
+use crate::{AsWordList, Bits11, TOTAL_WORDS};
+
+// SHA-256 of the word list files in the bips repository: every word followed
+// by `\n`. A list kept in external flash is checked against these, so none
+// of the lists has to be built in.
+
+pub const WORDLIST_SHA256_ENGLISH: [u8; 32] = [
+    0x2f, 0x5e, 0xed, 0x53, 0xa4, 0x72, 0x7b, 0x4b, 0xf8, 0x88, 0x0d, 0x8f, 0x3f, 0x19, 0x9e, 0xfc,
+    0x90, 0xe5, 0x85, 0x03, 0x64, 0x6d, 0x9f, 0xf8, 0xef, 0xf3, 0xa2, 0xed, 0x3b, 0x24, 0xdb, 0xda,
+];
+pub const WORDLIST_SHA256_CHINESE_SIMPLIFIED: [u8; 32] = [
+    0x5c, 0x59, 0x42, 0x79, 0x2b, 0xd8, 0x34, 0x0c, 0xb8, 0xb2, 0x7c, 0xd5, 0x92, 0xf1, 0x01, 0x5e,
+    0xdf, 0x56, 0xa8, 0xc5, 0xb2, 0x62, 0x76, 0xee, 0x18, 0xa4, 0x82, 0x42, 0x8e, 0x7c, 0x57, 0x26,
+];
+pub const WORDLIST_SHA256_CHINESE_TRADITIONAL: [u8; 32] = [
+    0x41, 0x7b, 0x26, 0xb3, 0xd8, 0x50, 0x0a, 0x4a, 0xe3, 0xd5, 0x97, 0x17, 0xd7, 0x01, 0x19, 0x52,
+    0xdb, 0x6f, 0xc2, 0xfb, 0x84, 0xb8, 0x07, 0xf3, 0xf9, 0x4a, 0xc7, 0x34, 0xe8, 0x9c, 0x1b, 0x5f,
+];
+pub const WORDLIST_SHA256_CZECH: [u8; 32] = [
+    0x7e, 0x80, 0xe1, 0x61, 0xc3, 0xe9, 0x3d, 0x95, 0x54, 0xc2, 0xef, 0xb7, 0x8d, 0x4e, 0x3c, 0xeb,
+    0xf8, 0xfc, 0x72, 0x7e, 0x9c, 0x52, 0xe0, 0x3b, 0x83, 0xb9, 0x44, 0x06, 0xbd, 0xcc, 0x95, 0xfc,
+];
+pub const WORDLIST_SHA256_FRENCH: [u8; 32] = [
+    0xeb, 0xc3, 0x95, 0x9a, 0xb7, 0x80, 0x1a, 0x1d, 0xf6, 0xba, 0xc4, 0xfa, 0x7d, 0x97, 0x06, 0x52,
+    0xf1, 0xdf, 0x76, 0xb6, 0x83, 0xcd, 0x2f, 0x40, 0x03, 0xc9, 0x41, 0xc6, 0x3d, 0x51, 0x7e, 0x59,
+];
+pub const WORDLIST_SHA256_ITALIAN: [u8; 32] = [
+    0xd3, 0x92, 0xc4, 0x9f, 0xdb, 0x70, 0x0a, 0x24, 0xcd, 0x1f, 0xce, 0xb2, 0x37, 0xc1, 0xf6, 0x5d,
+    0xcc, 0x12, 0x8f, 0x6b, 0x34, 0xa8, 0xaa, 0xcb, 0x58, 0xb5, 0x93, 0x84, 0xb5, 0xc6, 0x48, 0xc2,
+];
+pub const WORDLIST_SHA256_JAPANESE: [u8; 32] = [
+    0x2e, 0xed, 0x0a, 0xef, 0x49, 0x22, 0x91, 0xe0, 0x61, 0x63, 0x3d, 0x7a, 0xd8, 0x11, 0x7f, 0x1a,
+    0x2b, 0x03, 0xeb, 0x80, 0xa2, 0x9d, 0x0e, 0x4e, 0x31, 0x17, 0xac, 0x25, 0x28, 0xd0, 0x5f, 0xfd,
+];
+pub const WORDLIST_SHA256_KOREAN: [u8; 32] = [
+    0x9e, 0x95, 0xf8, 0x6c, 0x16, 0x7d, 0xe8, 0x8f, 0x45, 0x0f, 0x0a, 0xaf, 0x89, 0xe8, 0x7f, 0x66,
+    0x24, 0xa5, 0x7f, 0x97, 0x3c, 0x67, 0xb5, 0x16, 0xe3, 0x38, 0xe8, 0xe8, 0xb8, 0x89, 0x7f, 0x60,
+];
+pub const WORDLIST_SHA256_PORTUGUESE: [u8; 32] = [
+    0x26, 0x85, 0xe9, 0xc1, 0x94, 0xc8, 0x2a, 0xe6, 0x7e, 0x10, 0xba, 0x59, 0xd9, 0xea, 0x53, 0x45,
+    0xa2, 0x3d, 0xc0, 0x93, 0xe9, 0x22, 0x76, 0xfc, 0x53, 0x61, 0xf6, 0x66, 0x7d, 0x79, 0xcd, 0x3f,
+];
+pub const WORDLIST_SHA256_SPANISH: [u8; 32] = [
+    0x46, 0x84, 0x6a, 0x5a, 0x01, 0x39, 0xd1, 0xe3, 0xcb, 0x77, 0x29, 0x3e, 0x52, 0x1c, 0x28, 0x65,
+    0xf7, 0xbc, 0xdb, 0x82, 0xc4, 0x4e, 0x8d, 0x0a, 0x06, 0xa2, 0xcd, 0x0e, 0xcb, 0xa4, 0x8c, 0x0b,
+];
+
+/// SHA-256 of `wordlist` in the layout of the bips repository files, read one
+/// word at a time.
+pub fn wordlist_sha256<L: AsWordList>(wordlist: &L) -> Result<[u8; 32], ErrorMnemonic> {
+    let mut hasher = Sha256::new();
+    for bits_u16 in 0..TOTAL_WORDS as u16 {
+        let word = wordlist.get_word(Bits11(bits_u16))?;
+        hasher.update(word.as_ref().as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(hasher.finalize().into())
+}
+
+/// Whether `wordlist` is the official English list; `WordListDigest` if any
+/// word differs, `DamagedWord` if a word can not be read.
+pub fn verify_wordlist<L: AsWordList>(wordlist: &L) -> Result<(), ErrorMnemonic> {
+    verify_wordlist_sha256(wordlist, &WORDLIST_SHA256_ENGLISH)
+}
+
+/// As [`verify_wordlist`], against one of the `WORDLIST_SHA256_*` digests.
+pub fn verify_wordlist_sha256<L: AsWordList>(
+    wordlist: &L,
+    expected: &[u8; 32],
+) -> Result<(), ErrorMnemonic> {
+    if ct_eq_bytes(&wordlist_sha256(wordlist)?, expected) == 1 {
+        Ok(())
+    } else {
+        Err(ErrorMnemonic::WordListDigest)
+    }
+}
@@ -0,0 +1,132 @@
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::error::ErrorMnemonic;
+use crate::regular::InternalWordList;
+use crate::{AsWordList, Bits11, WordSet};
+
+// JavaScript interface over the English list, for offline recovery pages.
+// Errors are thrown as `Error` with the `ErrorMnemonic` text. The crate is
+// only a `lib`, so the module is built with the crate type given on the
+// command line:
+//
+//     cargo rustc --release --lib --crate-type cdylib \
+//         --target wasm32-unknown-unknown --no-default-features --features wasm
+//     wasm-bindgen --target web --out-dir <dir> \
+//         target/wasm32-unknown-unknown/release/mnemonic_external.wasm
+
+fn js_error(error: ErrorMnemonic) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// [`WordSet`] for JavaScript; zeroized when `free()` is called on it.
+#[wasm_bindgen(js_name = WordSet)]
+pub struct WasmWordSet(WordSet);
+
+#[wasm_bindgen(js_class = WordSet)]
+impl WasmWordSet {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(WordSet::new())
+    }
+
+    #[wasm_bindgen(js_name = fromEntropy)]
+    pub fn from_entropy(entropy: &[u8]) -> Result<WasmWordSet, JsError> {
+        WordSet::from_entropy(entropy).map(Self).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = fromPhrase)]
+    pub fn from_phrase(phrase: &str) -> Result<WasmWordSet, JsError> {
+        WordSet::from_phrase(phrase, &InternalWordList)
+            .map(Self)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = addWord)]
+    pub fn add_word(&mut self, word: &str) -> Result<(), JsError> {
+        self.0.add_word(word, &InternalWordList).map_err(js_error)
+    }
+
+    /// Index of the removed word, `undefined` if the set was empty.
+    #[wasm_bindgen(js_name = removeLastWord)]
+    pub fn remove_last_word(&mut self) -> Option<u16> {
+        self.0.remove_last_word().map(Bits11::bits)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    #[wasm_bindgen(js_name = isFinalizable)]
+    pub fn is_finalizable(&self) -> bool {
+        self.0.is_finalizable()
+    }
+
+    pub fn validate(&self) -> Result<(), JsError> {
+        self.0.validate().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = toEntropy)]
+    pub fn to_entropy(&self) -> Result<Vec<u8>, JsError> {
+        self.0.to_entropy().map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = toPhrase)]
+    pub fn to_phrase(&self) -> Result<String, JsError> {
+        self.0.to_phrase(&InternalWordList).map_err(js_error)
+    }
+}
+
+/// Words starting with `prefix`, in list order.
+#[wasm_bindgen(js_name = wordsByPrefix)]
+pub fn words_by_prefix(prefix: &str) -> Result<Vec<String>, JsError> {
+    InternalWordList
+        .get_words_by_prefix(prefix)
+        .map(|elements| {
+            elements
+                .into_iter()
+                .map(|element| String::from(element.word))
+                .collect()
+        })
+        .map_err(js_error)
+}
+
+/// Word at list `index`, 0 to 2047.
+#[wasm_bindgen(js_name = wordAt)]
+pub fn word_at(index: u16) -> Result<String, JsError> {
+    Bits11::from(index)
+        .and_then(|bits11| InternalWordList.get_word(bits11))
+        .map(String::from)
+        .map_err(js_error)
+}
+
+/// List index of `word`.
+#[wasm_bindgen(js_name = indexOf)]
+pub fn index_of(word: &str) -> Result<u16, JsError> {
+    InternalWordList
+        .bits11_for_word(word)
+        .map(Bits11::bits)
+        .map_err(js_error)
+}
+
+#[wasm_bindgen(js_name = phraseFromEntropy)]
+pub fn phrase_from_entropy(entropy: &[u8]) -> Result<String, JsError> {
+    WordSet::from_entropy(entropy)
+        .and_then(|word_set| word_set.to_phrase(&InternalWordList))
+        .map_err(js_error)
+}
+
+#[wasm_bindgen(js_name = entropyFromPhrase)]
+pub fn entropy_from_phrase(phrase: &str) -> Result<Vec<u8>, JsError> {
+    WordSet::from_phrase(phrase, &InternalWordList)
+        .and_then(|word_set| word_set.to_entropy())
+        .map_err(js_error)
+}
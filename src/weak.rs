@@ -0,0 +1,90 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::entropy::HexCase;
+use crate::error::ErrorMnemonic;
+use crate::{Bits11, WordSet};
+
+// Phrases that are valid but known to everyone: test vectors, and the
+// patterns tutorials and lazy test setups produce. Funds on them are taken
+// within minutes, so wallets should warn before importing one.
+
+/// Entropy of the official BIP-39 test vectors, lowercase hex, in their
+/// published order.
+pub const BIP39_VECTOR_ENTROPY: [&str; 24] = [
+    "00000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "80808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffff",
+    "000000000000000000000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "808080808080808080808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+    "8080808080808080808080808080808080808080808080808080808080808080",
+    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "9e885d952ad362caeb4efe34a8e91bd2",
+    "6610b25967cdcca9d59875f5cb50b0ea75433311869e930b",
+    "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+    "c0ba5a8e914111210f2bd131f3d5e08d",
+    "6d9be1ee6ebd27a258115aad99b7317b9c8d28b6d76431c3",
+    "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+    "23db8160a31d3e0dca3688ed941adbf3",
+    "8197a4a47f0425faeaa69deebc05ca29c0a5b5cc76ceacc0",
+    "066dca1a2bb7e8a1db2832148ce9933eea0f3ac9548d793112d9a95c9407efad",
+    "f30f8c1da665478f49b001d94c5fc452",
+    "c10ec20dc3cd9f652c7fac2f1230f7a3c828389a14392f05",
+    "f585c11aec520db57dd353c69554b21a89b20fb0650966fa0a9d6f74fd989d8f",
+];
+
+/// Reason a valid phrase is unsafe to hold funds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Weakness {
+    /// Every word but the last is the same word.
+    RepeatedWord,
+    /// The words but the last follow each other in the list, up or down.
+    SequentialWords,
+    /// The phrase is one of the BIP-39 test vectors.
+    TestVector,
+    /// The entropy bits are all zeros or all ones.
+    UniformEntropy,
+}
+
+impl WordSet {
+    /// Weaknesses of a complete, valid set, none for a fresh random one.
+    ///
+    /// The last word is left out of the word patterns as it carries the
+    /// checksum: "abandon" eleven times then "about" counts as repeated.
+    pub fn audit(&self) -> Result<Vec<Weakness>, ErrorMnemonic> {
+        let entropy_hex = self.entropy_hex(HexCase::Lower)?;
+        let mut weaknesses = Vec::new();
+
+        let (_, leading) = self
+            .bits11_set
+            .split_last()
+            .ok_or(ErrorMnemonic::WordsNumber)?;
+        if leading.windows(2).all(|pair| pair[0] == pair[1]) {
+            weaknesses.push(Weakness::RepeatedWord);
+        }
+        let step = |pair: &[Bits11]| pair[1].bits() as i32 - pair[0].bits() as i32;
+        if leading.windows(2).all(|pair| step(pair) == 1)
+            || leading.windows(2).all(|pair| step(pair) == -1)
+        {
+            weaknesses.push(Weakness::SequentialWords);
+        }
+        if BIP39_VECTOR_ENTROPY.contains(&entropy_hex.as_str()) {
+            weaknesses.push(Weakness::TestVector);
+        }
+        if entropy_hex.bytes().all(|digit| digit == b'0')
+            || entropy_hex.bytes().all(|digit| digit == b'f')
+        {
+            weaknesses.push(Weakness::UniformEntropy);
+        }
+        Ok(weaknesses)
+    }
+}
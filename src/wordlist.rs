@@ -1,5 +1,43 @@
+#[cfg(feature = "chinese-simplified")]
+mod chinese_simplified;
+#[cfg(feature = "chinese-traditional")]
+mod chinese_traditional;
+#[cfg(feature = "czech")]
+mod czech;
+#[cfg(feature = "french")]
+mod french;
+#[cfg(feature = "italian")]
+mod italian;
+#[cfg(feature = "japanese")]
+mod japanese;
+#[cfg(feature = "korean")]
+mod korean;
+#[cfg(feature = "portuguese")]
+mod portuguese;
+#[cfg(feature = "spanish")]
+mod spanish;
+
+#[cfg(feature = "chinese-simplified")]
+pub use chinese_simplified::WORDLIST_CHINESE_SIMPLIFIED;
+#[cfg(feature = "chinese-traditional")]
+pub use chinese_traditional::WORDLIST_CHINESE_TRADITIONAL;
+#[cfg(feature = "czech")]
+pub use czech::WORDLIST_CZECH;
+#[cfg(feature = "french")]
+pub use french::WORDLIST_FRENCH;
+#[cfg(feature = "italian")]
+pub use italian::WORDLIST_ITALIAN;
+#[cfg(feature = "japanese")]
+pub use japanese::WORDLIST_JAPANESE;
+#[cfg(feature = "korean")]
+pub use korean::WORDLIST_KOREAN;
+#[cfg(feature = "portuguese")]
+pub use portuguese::WORDLIST_PORTUGUESE;
+#[cfg(feature = "spanish")]
+pub use spanish::WORDLIST_SPANISH;
+
 #[rustfmt::skip]
-pub const WORDLIST_ENGLISH: [&str; 2048] = [
+pub static WORDLIST_ENGLISH: [&str; 2048] = [
     "abandon",
     "ability",
     "able",
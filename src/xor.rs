@@ -0,0 +1,65 @@
+#[cfg(all(feature = "generate", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(all(feature = "generate", feature = "std"))]
+use std::vec::Vec;
+
+#[cfg(feature = "generate")]
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+use crate::error::ErrorMnemonic;
+use crate::{WordSet, MAX_ENTROPY_LEN};
+
+// XOR backups: the entropy is split into shares that XOR back to it, each
+// share a valid mnemonic of the same length. Every share is needed to
+// recover, and any set short of one reveals nothing. Unlike SLIP-0039 there
+// is no threshold, and shares carry no identifier or index.
+
+/// `count` shares of `word_set`, the first `count - 1` random and the last
+/// making up the difference. `XorShares` for fewer than two.
+#[cfg(feature = "generate")]
+pub fn xor_split<R: CryptoRngCore + ?Sized>(
+    word_set: &WordSet,
+    count: usize,
+    rng: &mut R,
+) -> Result<Vec<WordSet>, ErrorMnemonic> {
+    if count < 2 {
+        return Err(ErrorMnemonic::XorShares);
+    }
+    let entropy = word_set.to_entropy_secret()?;
+    let len = entropy.len();
+    let mut last = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+    last[..len].copy_from_slice(&entropy);
+    let mut shares = Vec::with_capacity(count);
+    let mut random = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+    for _ in 1..count {
+        rng.fill_bytes(&mut random[..len]);
+        for (byte, random_byte) in last.iter_mut().zip(random[..len].iter()) {
+            *byte ^= random_byte;
+        }
+        shares.push(WordSet::from_checked_entropy(&random[..len]));
+    }
+    shares.push(WordSet::from_checked_entropy(&last[..len]));
+    Ok(shares)
+}
+
+/// The set that `shares` were split from, in any order. Every share must
+/// pass its checksum; `XorShares` for fewer than two or mixed lengths.
+pub fn xor_combine(shares: &[WordSet]) -> Result<WordSet, ErrorMnemonic> {
+    let len = match shares {
+        [first, _, ..] => first.mnemonic_type()?.entropy_bytes(),
+        _ => return Err(ErrorMnemonic::XorShares),
+    };
+    let mut combined = Zeroizing::new([0u8; MAX_ENTROPY_LEN]);
+    for share in shares {
+        let entropy = share.to_entropy_secret()?;
+        if entropy.len() != len {
+            return Err(ErrorMnemonic::XorShares);
+        }
+        for (byte, share_byte) in combined.iter_mut().zip(entropy.iter()) {
+            *byte ^= share_byte;
+        }
+    }
+    Ok(WordSet::from_checked_entropy(&combined[..len]))
+}